@@ -1,5 +1,4 @@
 use lambda_http::{run, tracing, Error};
-use std::env::set_var;
 
 use std::fmt::Debug;
 
@@ -14,13 +13,25 @@ use hyper::header::CONTENT_TYPE;
 use serde::Deserialize;
 use tower_http::cors::{AllowMethods, CorsLayer};
 
+use command_domain::project::DEFAULT_RESTORE_GRACE_PERIOD_DAYS;
 use command_interface_adaptor::controllers::create_router;
+use command_interface_adaptor::gateways::iteration_index::AwsDynamoDbIterationIndex;
+use command_interface_adaptor::gateways::iteration_repository::AwsDynamoDbIterationRepository;
+use command_interface_adaptor::gateways::mailer::LoggingMailer;
+use command_interface_adaptor::gateways::project_invitation_repository::AwsDynamoDbProjectInvitationRepository;
+use command_interface_adaptor::gateways::project_name_reservation::AwsDynamoDbProjectNameReservation;
 use command_interface_adaptor::gateways::project_repository::AwsDynamoDbProjectRepository;
+use command_interface_adaptor::gateways::task_index::AwsDynamoDbTaskIndex;
+use command_interface_adaptor::gateways::task_repository::AwsDynamoDbTaskRepository;
+use command_interface_adaptor::gateways::user_email_reservation::AwsDynamoDbUserEmailReservation;
+use command_interface_adaptor::gateways::user_repository::AwsDynamoDbUserRepository;
 
 #[derive(Deserialize, Debug)]
 struct AppSettings {
     api: ApiSettings,
     persistence: PersistenceSettings,
+    #[serde(default)]
+    project: ProjectSettings,
     aws: AwsSettings,
 }
 
@@ -37,6 +48,20 @@ struct PersistenceSettings {
     snapshot_aid_index_name: String,
     shard_count: u64,
     snapshot_interval: usize,
+    reservation_table_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct ProjectSettings {
+    restore_grace_period_days: i64,
+}
+
+impl Default for ProjectSettings {
+    fn default() -> Self {
+        Self {
+            restore_grace_period_days: DEFAULT_RESTORE_GRACE_PERIOD_DAYS,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -60,7 +85,7 @@ async fn main() -> Result<(), Error> {
     let app_settings = load_app_config().unwrap();
     let aws_client = create_aws_client(&app_settings.aws).await;
     let egg = EventStoreForDynamoDB::new(
-        aws_client,
+        aws_client.clone(),
         app_settings.persistence.journal_table_name.clone(),
         app_settings.persistence.journal_aid_index_name.clone(),
         app_settings.persistence.snapshot_table_name.clone(),
@@ -68,10 +93,79 @@ async fn main() -> Result<(), Error> {
         app_settings.persistence.shard_count,
     );
     let repository = AwsDynamoDbProjectRepository::new(egg, app_settings.persistence.snapshot_interval);
+    let invitation_egg = EventStoreForDynamoDB::new(
+        aws_client.clone(),
+        app_settings.persistence.journal_table_name.clone(),
+        app_settings.persistence.journal_aid_index_name.clone(),
+        app_settings.persistence.snapshot_table_name.clone(),
+        app_settings.persistence.snapshot_aid_index_name.clone(),
+        app_settings.persistence.shard_count,
+    );
+    let invitation_repository =
+        AwsDynamoDbProjectInvitationRepository::new(invitation_egg, app_settings.persistence.snapshot_interval);
 
-    let router = create_router(repository)
-        .layer(create_cors_layer(&app_settings))
-        .layer(axum::middleware::from_fn(access_log_on_request));
+    let iteration_egg = EventStoreForDynamoDB::new(
+        aws_client.clone(),
+        app_settings.persistence.journal_table_name.clone(),
+        app_settings.persistence.journal_aid_index_name.clone(),
+        app_settings.persistence.snapshot_table_name.clone(),
+        app_settings.persistence.snapshot_aid_index_name.clone(),
+        app_settings.persistence.shard_count,
+    );
+    let iteration_repository =
+        AwsDynamoDbIterationRepository::new(iteration_egg, app_settings.persistence.snapshot_interval);
+    let task_egg = EventStoreForDynamoDB::new(
+        aws_client.clone(),
+        app_settings.persistence.journal_table_name.clone(),
+        app_settings.persistence.journal_aid_index_name.clone(),
+        app_settings.persistence.snapshot_table_name.clone(),
+        app_settings.persistence.snapshot_aid_index_name.clone(),
+        app_settings.persistence.shard_count,
+    );
+    let task_repository = AwsDynamoDbTaskRepository::new(task_egg, app_settings.persistence.snapshot_interval);
+    let user_egg = EventStoreForDynamoDB::new(
+        aws_client.clone(),
+        app_settings.persistence.journal_table_name.clone(),
+        app_settings.persistence.journal_aid_index_name.clone(),
+        app_settings.persistence.snapshot_table_name.clone(),
+        app_settings.persistence.snapshot_aid_index_name.clone(),
+        app_settings.persistence.shard_count,
+    );
+    let user_repository = AwsDynamoDbUserRepository::new(user_egg, app_settings.persistence.snapshot_interval);
+    // イテレーションとタスクの索引、メールアドレスの予約はキーの接頭辞で区別し、予約テーブルに同居させる
+    let iteration_index = AwsDynamoDbIterationIndex::new(
+        aws_client.clone(),
+        app_settings.persistence.reservation_table_name.clone(),
+    );
+    let task_index = AwsDynamoDbTaskIndex::new(
+        aws_client.clone(),
+        app_settings.persistence.reservation_table_name.clone(),
+    );
+    let user_email_reservation = AwsDynamoDbUserEmailReservation::new(
+        aws_client.clone(),
+        app_settings.persistence.reservation_table_name.clone(),
+    );
+    let project_name_reservation = AwsDynamoDbProjectNameReservation::new(
+        aws_client,
+        app_settings.persistence.reservation_table_name.clone(),
+    );
+    let restore_grace_period = chrono::Duration::days(app_settings.project.restore_grace_period_days);
+
+    let router = create_router(
+        repository,
+        invitation_repository,
+        project_name_reservation,
+        iteration_repository,
+        iteration_index,
+        task_repository,
+        task_index,
+        user_repository,
+        user_email_reservation,
+        LoggingMailer,
+        restore_grace_period,
+    )
+    .layer(create_cors_layer(&app_settings))
+    .layer(axum::middleware::from_fn(access_log_on_request));
     run(router).await
 }
 
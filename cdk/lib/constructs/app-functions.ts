@@ -13,6 +13,7 @@ export interface AppFunctionsProps {
   readonly journalGsiName: string;
   readonly snapshotTable: dynamodb.ITableV2;
   readonly snapshotGsiName: string;
+  readonly reservationTable: dynamodb.ITableV2;
 }
 
 export class AppFunctions extends Construct {
@@ -37,12 +38,14 @@ export class AppFunctions extends Construct {
           APP__PERSISTENCE__SNAPSHOT_AID_INDEX_NAME: props.snapshotGsiName,
           APP__PERSISTENCE__SHARD_COUNT: "64",
           APP__PERSISTENCE__SNAPSHOT_INTERVAL: "10",
+          APP__PERSISTENCE__RESERVATION_TABLE_NAME: props.reservationTable.tableName,
           APP__AWS__REGION_NAME: "ap-northeast-1",
         },
       });
 
       props.journalTable.grantReadWriteData(fn);
       props.snapshotTable.grantReadWriteData(fn);
+      props.reservationTable.grantReadWriteData(fn);
 
       const fnUrl = fn.addFunctionUrl({
         // TODO: 整える
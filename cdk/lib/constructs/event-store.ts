@@ -9,6 +9,7 @@ export interface EventStoreProps {
 export class EventStore extends Construct {
   readonly journalTable: dynamodb.ITableV2;
   readonly snapshotTable: dynamodb.ITableV2;
+  readonly reservationTable: dynamodb.ITableV2;
 
   constructor(scope: Construct, id: string, props: EventStoreProps) {
     super(scope, id);
@@ -50,7 +51,17 @@ export class EventStore extends Construct {
       projectionType: dynamodb.ProjectionType.ALL,
     });
 
+    //
+    // Create DynamoDB table for unique key reservations
+    //
+    const reservationTable = new dynamodb.TableV2(this, "ReservationTable", {
+      partitionKey: { name: "pkey", type: dynamodb.AttributeType.STRING },
+      billing: dynamodb.Billing.onDemand(),
+      removalPolicy: RemovalPolicy.DESTROY,
+    });
+
     this.journalTable = journalTable;
     this.snapshotTable = snapshotTable;
+    this.reservationTable = reservationTable;
   }
 }
@@ -29,6 +29,7 @@ export class EventStoreAdapterRsSampleStack extends Stack {
       journalGsiName: journalGsiName,
       snapshotTable: eventStore.snapshotTable,
       snapshotGsiName: snapshotGsiName,
+      reservationTable: eventStore.reservationTable,
     });
 
     new Cdn(this, "Cdn", {
@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 説明文の最大文字数
+const DESCRIPTION_MAX_LENGTH: usize = 1000;
+
+/// 説明
+///
+/// 前後の空白は取り除いて保持する。空の説明も許容する。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Description(String);
+
+#[derive(Error, Debug, Clone)]
+pub enum DescriptionError {
+    #[error("the description is too long")]
+    TooLong,
+}
+
+impl Description {
+    pub fn new(description: &str) -> Result<Self, DescriptionError> {
+        let trimmed = description.trim();
+        if trimmed.chars().count() > DESCRIPTION_MAX_LENGTH {
+            Err(DescriptionError::TooLong)
+        } else {
+            Ok(Self(trimmed.to_string()))
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromStr for Description {
+    type Err = DescriptionError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for Description {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_trims_whitespace() {
+        let description = Description::new("  sample project \n").unwrap();
+        assert_eq!(description.to_string(), "sample project");
+    }
+
+    #[test]
+    fn test_new_allows_empty() {
+        let description = Description::new("   ").unwrap();
+        assert!(description.is_empty());
+    }
+
+    #[test]
+    fn test_new_too_long() {
+        let value = "あ".repeat(DESCRIPTION_MAX_LENGTH + 1);
+        assert!(Description::new(&value).is_err());
+
+        let value = "あ".repeat(DESCRIPTION_MAX_LENGTH);
+        assert!(Description::new(&value).is_ok());
+    }
+}
@@ -36,6 +36,13 @@ impl Email {
 
         Ok(Self(email.to_string()))
     }
+
+    /// 一意性の判定に用いる正規化したメールアドレスを返す
+    ///
+    /// 大文字と小文字を区別しない。
+    pub fn normalized(&self) -> String {
+        self.0.to_lowercase()
+    }
 }
 
 impl FromStr for Email {
@@ -43,4 +43,10 @@ pub enum ParseError {
     InvalidULID(#[from] ULIDError),
     #[error("invalid Member Role: {0}")]
     InvalidMemberRole(String),
+    #[error("invalid Project Status: {0}")]
+    InvalidProjectStatus(String),
+    #[error("invalid Priority: {0}")]
+    InvalidPriority(String),
+    #[error("invalid Task Status: {0}")]
+    InvalidTaskStatus(String),
 }
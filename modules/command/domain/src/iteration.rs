@@ -0,0 +1,522 @@
+use chrono::{DateTime, Utc};
+use event_store_adapter_rs::types::Aggregate;
+use serde::{Deserialize, Serialize};
+
+mod iteration_error;
+mod iteration_events;
+mod iteration_id;
+mod iteration_name;
+mod iteration_service;
+mod iteration_status;
+
+use crate::description::Description;
+pub use crate::iteration::iteration_error::IterationError;
+pub use crate::iteration::iteration_events::{
+    IterationEvent, IterationEventCompletedBody, IterationEventCreatedBody, IterationEventRenamedBody,
+    IterationEventRescheduledBody, IterationEventStartedBody,
+};
+pub use crate::iteration::iteration_id::IterationId;
+pub use crate::iteration::iteration_name::{IterationName, IterationNameError};
+pub use crate::iteration::iteration_service::IterationService;
+pub use crate::iteration::iteration_status::IterationStatus;
+use crate::period::Period;
+use crate::project::{Permission, Project, ProjectId};
+use crate::user::UserId;
+
+/// プロジェクト内で定める短期間の作業サイクル
+///
+/// 操作の可否は所属するプロジェクトのメンバーの権限で判定するため、各コマンドは[Project]を受け取る。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Iteration {
+    id: IterationId,
+    project_id: ProjectId,
+    name: IterationName,
+    description: Description,
+    status: IterationStatus,
+    period: Period,
+    version: usize,
+    seq_nr_counter: usize,
+    last_updated_at: DateTime<Utc>,
+}
+
+impl PartialEq for Iteration {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Aggregate for Iteration {
+    type ID = IterationId;
+
+    fn id(&self) -> &Self::ID {
+        &self.id
+    }
+
+    fn seq_nr(&self) -> usize {
+        self.seq_nr_counter
+    }
+
+    fn version(&self) -> usize {
+        self.version
+    }
+
+    fn set_version(&mut self, version: usize) {
+        self.version = version;
+    }
+
+    fn last_updated_at(&self) -> &DateTime<Utc> {
+        &self.last_updated_at
+    }
+}
+
+impl Iteration {
+    /// イテレーションを作成する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - name: イテレーション名
+    /// - description: イテレーションの説明
+    /// - period: イテレーションの期間
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageIterations] を持たない場合はエラーを返す。
+    /// - 成功した場合は、IterationCreatedイベントを返す。
+    pub fn new(
+        project: &Project,
+        name: IterationName,
+        description: Description,
+        period: Period,
+        executor_id: UserId,
+    ) -> Result<(Self, IterationEvent), IterationError> {
+        Self::ensure_permission(project, &executor_id)?;
+
+        let id = IterationId::new();
+        let now = Utc::now();
+        let my_self = Self {
+            id: id.clone(),
+            project_id: project.id().clone(),
+            name: name.clone(),
+            description: description.clone(),
+            status: IterationStatus::Planning,
+            period,
+            version: 1,
+            seq_nr_counter: 1,
+            last_updated_at: now,
+        };
+        let event = IterationEvent::IterationCreated(IterationEventCreatedBody::new(
+            id,
+            my_self.seq_nr_counter,
+            my_self.project_id.clone(),
+            name,
+            description,
+            period,
+            executor_id,
+            now,
+        ));
+        Ok((my_self, event))
+    }
+
+    fn apply_event(&mut self, event: &IterationEvent) {
+        match event {
+            IterationEvent::IterationRenamed(body) => {
+                self.name = body.new_name.clone();
+            },
+            IterationEvent::IterationRescheduled(body) => {
+                self.period = body.new_period;
+            },
+            IterationEvent::IterationStarted(_) => {
+                self.status = IterationStatus::InProgress;
+            },
+            IterationEvent::IterationCompleted(_) => {
+                self.status = IterationStatus::Completed;
+            },
+            IterationEvent::IterationCreated(_) => return,
+        }
+        self.seq_nr_counter += 1;
+    }
+
+    pub fn replay(events: &[IterationEvent], snapshot: Iteration) -> Self {
+        log::debug!("event.size = {}", events.len());
+        events.iter().fold(snapshot, |mut result, event| {
+            log::debug!("Replaying snapshot: {:?}", result);
+            log::debug!("Replaying event: {:?}", event);
+            result.apply_event(event);
+            result
+        })
+    }
+
+    /// 所属するプロジェクトの[ProjectId]の参照を返す
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    /// [IterationName]の参照を返す
+    pub fn name(&self) -> &IterationName {
+        &self.name
+    }
+
+    /// [Description]の参照を返す
+    pub fn description(&self) -> &Description {
+        &self.description
+    }
+
+    /// [IterationStatus]を返す
+    pub fn status(&self) -> IterationStatus {
+        self.status
+    }
+
+    /// [Period]の参照を返す
+    pub fn period(&self) -> &Period {
+        &self.period
+    }
+
+    /// イテレーション名を変更する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - new_name: 新しいイテレーション名
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageIterations] を持たない場合はエラーを返す。
+    /// - 成功した場合は、IterationRenamedイベントを返す。
+    pub fn rename(
+        &mut self,
+        project: &Project,
+        new_name: IterationName,
+        executor_id: UserId,
+    ) -> Result<IterationEvent, IterationError> {
+        self.ensure_project(project)?;
+        Self::ensure_permission(project, &executor_id)?;
+
+        self.name = new_name.clone();
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(IterationEvent::IterationRenamed(
+            IterationEventRenamedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                new_name,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// イテレーションの期間を変更する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - new_period: 新しい期間
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageIterations] を持たない場合はエラーを返す。
+    /// - イテレーションが完了している場合はエラーを返す。
+    /// - 成功した場合は、IterationRescheduledイベントを返す。
+    pub fn reschedule(
+        &mut self,
+        project: &Project,
+        new_period: Period,
+        executor_id: UserId,
+    ) -> Result<IterationEvent, IterationError> {
+        self.ensure_project(project)?;
+        Self::ensure_permission(project, &executor_id)?;
+        if self.status == IterationStatus::Completed {
+            return Err(IterationError::AlreadyCompletedError(self.id.clone()));
+        }
+
+        self.period = new_period;
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(IterationEvent::IterationRescheduled(
+            IterationEventRescheduledBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                new_period,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// イテレーションを開始する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageIterations] を持たない場合はエラーを返す。
+    /// - 計画中でない場合はエラーを返す。
+    /// - 成功した場合は、IterationStartedイベントを返す。
+    pub fn start(&mut self, project: &Project, executor_id: UserId) -> Result<IterationEvent, IterationError> {
+        self.change_status(project, IterationStatus::InProgress, &executor_id)?;
+        let now = Utc::now();
+        Ok(IterationEvent::IterationStarted(
+            IterationEventStartedBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    /// イテレーションを完了する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageIterations] を持たない場合はエラーを返す。
+    /// - 進行中でない場合はエラーを返す。
+    /// - 成功した場合は、IterationCompletedイベントを返す。
+    pub fn complete(&mut self, project: &Project, executor_id: UserId) -> Result<IterationEvent, IterationError> {
+        self.change_status(project, IterationStatus::Completed, &executor_id)?;
+        let now = Utc::now();
+        Ok(IterationEvent::IterationCompleted(
+            IterationEventCompletedBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    fn change_status(
+        &mut self,
+        project: &Project,
+        next: IterationStatus,
+        executor_id: &UserId,
+    ) -> Result<(), IterationError> {
+        self.ensure_project(project)?;
+        Self::ensure_permission(project, executor_id)?;
+        if !self.status.can_transition_to(&next) {
+            return Err(IterationError::InvalidStatusTransitionError(
+                self.status,
+                next,
+            ));
+        }
+        self.status = next;
+        self.seq_nr_counter += 1;
+        Ok(())
+    }
+
+    /// 指定したプロジェクトに所属しているかどうかを検証する
+    fn ensure_project(&self, project: &Project) -> Result<(), IterationError> {
+        if project.id() != &self.project_id {
+            return Err(IterationError::MismatchedProjectError(
+                self.id.clone(),
+                project.id().clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 実行者がプロジェクトでイテレーションを管理できるかどうかを検証する
+    fn ensure_permission(project: &Project, executor_id: &UserId) -> Result<(), IterationError> {
+        if project.is_deleted() {
+            return Err(IterationError::ProjectAlreadyDeletedError(
+                project.id().clone(),
+            ));
+        }
+        if !project.members().has_permission(executor_id, &Permission::ManageIterations) {
+            return Err(IterationError::PermissionDeniedError(
+                "executor_id".to_string(),
+                Permission::ManageIterations,
+                executor_id.clone(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    use crate::project::{Members, ProjectName};
+
+    fn create_project(owner_id: &UserId) -> Project {
+        let (project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        project
+    }
+
+    fn create_period(start_day: u32, end_day: u32) -> Period {
+        Period::new(
+            NaiveDate::from_ymd_opt(2025, 4, start_day).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 4, end_day).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_create_iteration() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+
+        let result = Iteration::new(
+            &project,
+            IterationName::new("Sprint 1").unwrap(),
+            Description::default(),
+            create_period(1, 14),
+            UserId::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(IterationError::PermissionDeniedError(_, _, _))
+        ));
+
+        let (iteration, event) = Iteration::new(
+            &project,
+            IterationName::new("Sprint 1").unwrap(),
+            Description::default(),
+            create_period(1, 14),
+            owner_id,
+        )
+        .unwrap();
+        assert!(matches!(event, IterationEvent::IterationCreated(_)));
+        assert_eq!(iteration.status(), IterationStatus::Planning);
+        assert_eq!(iteration.project_id(), project.id());
+    }
+
+    #[test]
+    fn test_ensure_no_overlap() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let (iteration, _) = Iteration::new(
+            &project,
+            IterationName::new("Sprint 1").unwrap(),
+            Description::default(),
+            create_period(1, 14),
+            owner_id,
+        )
+        .unwrap();
+        let iterations = vec![iteration.clone()];
+
+        assert!(matches!(
+            IterationService::ensure_no_overlap(&iterations, None, &create_period(14, 28)),
+            Err(IterationError::OverlappingPeriodError(_, _))
+        ));
+        assert!(IterationService::ensure_no_overlap(&iterations, None, &create_period(15, 28)).is_ok());
+        // 自身の期間の変更は重なりとみなさない
+        assert!(IterationService::ensure_no_overlap(&iterations, Some(iteration.id()), &create_period(2, 15)).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_no_active_iteration() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let (mut active, _) = Iteration::new(
+            &project,
+            IterationName::new("Sprint 1").unwrap(),
+            Description::default(),
+            create_period(1, 14),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let (planned, _) = Iteration::new(
+            &project,
+            IterationName::new("Sprint 2").unwrap(),
+            Description::default(),
+            create_period(15, 28),
+            owner_id.clone(),
+        )
+        .unwrap();
+        assert!(IterationService::ensure_no_active_iteration(&[active.clone(), planned.clone()], planned.id()).is_ok());
+
+        active.start(&project, owner_id.clone()).unwrap();
+        let iterations = vec![active.clone(), planned.clone()];
+        assert!(matches!(
+            IterationService::ensure_no_active_iteration(&iterations, planned.id()),
+            Err(IterationError::AnotherIterationInProgressError(id)) if &id == active.id()
+        ));
+
+        active.complete(&project, owner_id).unwrap();
+        assert!(IterationService::ensure_no_active_iteration(&[active, planned.clone()], planned.id()).is_ok());
+    }
+
+    #[test]
+    fn test_find_carry_over_destination() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let create_iteration = |name: &str, period: Period| {
+            Iteration::new(
+                &project,
+                IterationName::new(name).unwrap(),
+                Description::default(),
+                period,
+                owner_id.clone(),
+            )
+            .unwrap()
+            .0
+        };
+        let completed = create_iteration("Sprint 2", create_period(8, 14));
+        let previous = create_iteration("Sprint 1", create_period(1, 7));
+        let next = create_iteration("Sprint 3", create_period(15, 21));
+        let after_next = create_iteration("Sprint 4", create_period(22, 28));
+
+        let iterations = vec![after_next.clone(), previous.clone(), completed.clone(), next.clone()];
+        assert_eq!(
+            IterationService::find_carry_over_destination(&iterations, &completed),
+            Some(next.id().clone())
+        );
+        // 後続の計画中のイテレーションがない場合はバックログに戻す
+        assert_eq!(
+            IterationService::find_carry_over_destination(&[previous, completed.clone()], &completed),
+            None
+        );
+    }
+
+    #[test]
+    fn test_change_status_and_replay() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let (mut iteration, _) = Iteration::new(
+            &project,
+            IterationName::new("Sprint 1").unwrap(),
+            Description::default(),
+            create_period(1, 14),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = iteration.clone();
+
+        assert!(matches!(
+            iteration.complete(&project, owner_id.clone()),
+            Err(IterationError::InvalidStatusTransitionError(
+                IterationStatus::Planning,
+                IterationStatus::Completed
+            ))
+        ));
+
+        let events = vec![
+            iteration
+                .rename(
+                    &project,
+                    IterationName::new("Sprint 1'").unwrap(),
+                    owner_id.clone(),
+                )
+                .unwrap(),
+            iteration.reschedule(&project, create_period(2, 15), owner_id.clone()).unwrap(),
+            iteration.start(&project, owner_id.clone()).unwrap(),
+            iteration.complete(&project, owner_id.clone()).unwrap(),
+        ];
+        assert_eq!(iteration.status(), IterationStatus::Completed);
+        assert!(matches!(
+            iteration.reschedule(&project, create_period(3, 16), owner_id),
+            Err(IterationError::AlreadyCompletedError(_))
+        ));
+
+        let replayed = Iteration::replay(&events, snapshot);
+        assert_eq!(replayed.status(), IterationStatus::Completed);
+        assert_eq!(replayed.name(), iteration.name());
+        assert_eq!(replayed.period(), iteration.period());
+        assert_eq!(replayed.seq_nr(), iteration.seq_nr());
+    }
+}
@@ -0,0 +1,29 @@
+use crate::iteration::{IterationId, IterationStatus};
+use crate::period::Period;
+use crate::project::{Permission, ProjectId};
+use crate::user::UserId;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum IterationError {
+    #[error("The project is deleted: {0:?}")]
+    ProjectAlreadyDeletedError(ProjectId),
+
+    #[error("The iteration does not belong to the project: {0:?}, {1:?}")]
+    MismatchedProjectError(IterationId, ProjectId),
+
+    #[error("The {0} does not have the {1} permission on the project: {2:?}")]
+    PermissionDeniedError(String, Permission, UserId),
+
+    #[error("The iteration is already completed: {0:?}")]
+    AlreadyCompletedError(IterationId),
+
+    #[error("The iteration status cannot be changed from {0} to {1}")]
+    InvalidStatusTransitionError(IterationStatus, IterationStatus),
+
+    #[error("The period {1} overlaps with the iteration: {0:?}")]
+    OverlappingPeriodError(IterationId, Period),
+
+    #[error("Another iteration is already in progress: {0:?}")]
+    AnotherIterationInProgressError(IterationId),
+}
@@ -0,0 +1,222 @@
+use chrono::{DateTime, Utc};
+use event_store_adapter_rs::types::Event;
+use serde::{Deserialize, Serialize};
+use ulid_generator_rs::ULID;
+
+use crate::description::Description;
+use crate::helper::id_generate;
+use crate::iteration::{IterationId, IterationName};
+use crate::period::Period;
+use crate::project::ProjectId;
+use crate::user::UserId;
+
+pub type IterationEventId = ULID;
+
+/// イテレーションに関するイベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum IterationEvent {
+    /// イテレーションが作成された
+    IterationCreated(IterationEventCreatedBody),
+    /// イテレーション名が変更された
+    IterationRenamed(IterationEventRenamedBody),
+    /// イテレーションの期間が変更された
+    IterationRescheduled(IterationEventRescheduledBody),
+    /// イテレーションが開始された
+    IterationStarted(IterationEventStartedBody),
+    /// イテレーションが完了した
+    IterationCompleted(IterationEventCompletedBody),
+}
+
+impl Event for IterationEvent {
+    type AggregateID = IterationId;
+    type ID = IterationEventId;
+
+    fn id(&self) -> &IterationEventId {
+        match self {
+            IterationEvent::IterationCreated(event) => &event.id,
+            IterationEvent::IterationRenamed(event) => &event.id,
+            IterationEvent::IterationRescheduled(event) => &event.id,
+            IterationEvent::IterationStarted(event) => &event.id,
+            IterationEvent::IterationCompleted(event) => &event.id,
+        }
+    }
+
+    fn seq_nr(&self) -> usize {
+        match self {
+            IterationEvent::IterationCreated(event) => event.seq_nr,
+            IterationEvent::IterationRenamed(event) => event.seq_nr,
+            IterationEvent::IterationRescheduled(event) => event.seq_nr,
+            IterationEvent::IterationStarted(event) => event.seq_nr,
+            IterationEvent::IterationCompleted(event) => event.seq_nr,
+        }
+    }
+
+    fn aggregate_id(&self) -> &IterationId {
+        match self {
+            IterationEvent::IterationCreated(event) => &event.aggregate_id,
+            IterationEvent::IterationRenamed(event) => &event.aggregate_id,
+            IterationEvent::IterationRescheduled(event) => &event.aggregate_id,
+            IterationEvent::IterationStarted(event) => &event.aggregate_id,
+            IterationEvent::IterationCompleted(event) => &event.aggregate_id,
+        }
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        match self {
+            IterationEvent::IterationCreated(event) => &event.occurred_at,
+            IterationEvent::IterationRenamed(event) => &event.occurred_at,
+            IterationEvent::IterationRescheduled(event) => &event.occurred_at,
+            IterationEvent::IterationStarted(event) => &event.occurred_at,
+            IterationEvent::IterationCompleted(event) => &event.occurred_at,
+        }
+    }
+
+    fn is_created(&self) -> bool {
+        matches!(self, IterationEvent::IterationCreated(_))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationEventCreatedBody {
+    pub id: IterationEventId,
+    pub aggregate_id: IterationId,
+    pub seq_nr: usize,
+    pub project_id: ProjectId,
+    pub name: IterationName,
+    pub description: Description,
+    pub period: Period,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl IterationEventCreatedBody {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        aggregate_id: IterationId,
+        seq_nr: usize,
+        project_id: ProjectId,
+        name: IterationName,
+        description: Description,
+        period: Period,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            project_id,
+            name,
+            description,
+            period,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationEventRenamedBody {
+    pub id: IterationEventId,
+    pub aggregate_id: IterationId,
+    pub seq_nr: usize,
+    pub new_name: IterationName,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl IterationEventRenamedBody {
+    pub fn new(
+        aggregate_id: IterationId,
+        seq_nr: usize,
+        new_name: IterationName,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_name,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationEventRescheduledBody {
+    pub id: IterationEventId,
+    pub aggregate_id: IterationId,
+    pub seq_nr: usize,
+    pub new_period: Period,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl IterationEventRescheduledBody {
+    pub fn new(
+        aggregate_id: IterationId,
+        seq_nr: usize,
+        new_period: Period,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_period,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationEventStartedBody {
+    pub id: IterationEventId,
+    pub aggregate_id: IterationId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl IterationEventStartedBody {
+    pub fn new(aggregate_id: IterationId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationEventCompletedBody {
+    pub id: IterationEventId,
+    pub aggregate_id: IterationId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl IterationEventCompletedBody {
+    pub fn new(aggregate_id: IterationId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
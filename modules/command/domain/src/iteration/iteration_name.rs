@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IterationName(String);
+
+#[derive(Error, Debug, Clone)]
+pub enum IterationNameError {
+    #[error("the iteration name is empty")]
+    Empty,
+    #[error("the iteration name is too long")]
+    TooLong,
+}
+
+impl IterationName {
+    pub fn new(name: &str) -> Result<Self, IterationNameError> {
+        if name.is_empty() {
+            Err(IterationNameError::Empty)
+        } else if name.len() > 100 {
+            Err(IterationNameError::TooLong)
+        } else {
+            Ok(Self(name.to_string()))
+        }
+    }
+}
+
+impl FromStr for IterationName {
+    type Err = IterationNameError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for IterationName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,79 @@
+use event_store_adapter_rs::types::Aggregate;
+
+use crate::iteration::{Iteration, IterationError, IterationId, IterationStatus};
+use crate::period::Period;
+
+/// 同じプロジェクトに属するイテレーション同士の整合性を検証するドメインサービス
+pub struct IterationService;
+
+impl IterationService {
+    /// 期間が他のイテレーションと重ならないことを検証する
+    ///
+    /// # 引数
+    /// - iterations: 同じプロジェクトに属するイテレーション
+    /// - target_id: 検証対象のイテレーションID。新規作成の場合は `None`
+    /// - period: 検証対象の期間
+    ///
+    /// # 戻り値
+    /// - 他のイテレーションと期間が重なる場合はエラーを返す。
+    pub fn ensure_no_overlap(
+        iterations: &[Iteration],
+        target_id: Option<&IterationId>,
+        period: &Period,
+    ) -> Result<(), IterationError> {
+        match iterations
+            .iter()
+            .filter(|iteration| Some(iteration.id()) != target_id)
+            .find(|iteration| iteration.period().overlaps(period))
+        {
+            Some(iteration) => Err(IterationError::OverlappingPeriodError(
+                iteration.id().clone(),
+                *period,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// 他に進行中のイテレーションがないことを検証する
+    ///
+    /// プロジェクトごとに進行中にできるイテレーションは1つだけ。
+    ///
+    /// # 引数
+    /// - iterations: 同じプロジェクトに属するイテレーション
+    /// - target_id: 開始しようとしているイテレーションID
+    ///
+    /// # 戻り値
+    /// - 他のイテレーションが進行中の場合はエラーを返す。
+    pub fn ensure_no_active_iteration(iterations: &[Iteration], target_id: &IterationId) -> Result<(), IterationError> {
+        match iterations
+            .iter()
+            .filter(|iteration| iteration.id() != target_id)
+            .find(|iteration| iteration.status() == IterationStatus::InProgress)
+        {
+            Some(iteration) => Err(IterationError::AnotherIterationInProgressError(
+                iteration.id().clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// 完了したイテレーションの未完了の作業を引き継ぐイテレーションを返す
+    ///
+    /// 完了したイテレーションより後に始まる計画中のイテレーションのうち、最も早く始まるものを選ぶ。
+    ///
+    /// # 引数
+    /// - iterations: 同じプロジェクトに属するイテレーション
+    /// - completed: 完了したイテレーション
+    ///
+    /// # 戻り値
+    /// - 引き継ぐイテレーションのID。該当するイテレーションがない場合はバックログに戻すため `None` を返す。
+    pub fn find_carry_over_destination(iterations: &[Iteration], completed: &Iteration) -> Option<IterationId> {
+        iterations
+            .iter()
+            .filter(|iteration| iteration.id() != completed.id())
+            .filter(|iteration| iteration.status() == IterationStatus::Planning)
+            .filter(|iteration| iteration.period().start() >= completed.period().start())
+            .min_by_key(|iteration| iteration.period().start())
+            .map(|iteration| iteration.id().clone())
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// イテレーションの状態
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IterationStatus {
+    /// 計画中
+    #[default]
+    Planning,
+    /// 進行中
+    InProgress,
+    /// 完了
+    Completed,
+}
+
+impl IterationStatus {
+    /// 指定した状態へ遷移できるかどうかを判定する
+    ///
+    /// - Planning -> InProgress
+    /// - InProgress -> Completed
+    pub fn can_transition_to(&self, next: &IterationStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Planning, Self::InProgress) | (Self::InProgress, Self::Completed)
+        )
+    }
+}
+
+impl Display for IterationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Planning => write!(f, "Planning"),
+            Self::InProgress => write!(f, "InProgress"),
+            Self::Completed => write!(f, "Completed"),
+        }
+    }
+}
@@ -1,4 +1,9 @@
+pub mod description;
 pub mod email;
 mod helper;
+pub mod iteration;
+pub mod period;
 pub mod project;
+pub mod project_invitation;
+pub mod task;
 pub mod user;
@@ -0,0 +1,214 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{Days, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 期間の終了日の扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PeriodBound {
+    /// 終了日を含む `[start, end]`
+    #[default]
+    Closed,
+    /// 終了日を含まない `[start, end)`
+    HalfOpen,
+}
+
+/// 期間
+///
+/// 開始日と終了日からなる日付のペア。終了日は開始日より前にならない。
+/// 終了日を含むかどうかは [PeriodBound] で表し、各演算は終了日を含まない形に揃えて行う。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "PeriodFields")]
+pub struct Period {
+    start: NaiveDate,
+    end: NaiveDate,
+    bound: PeriodBound,
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum PeriodError {
+    #[error("the end date {1} is before the start date {0}")]
+    EndBeforeStart(NaiveDate, NaiveDate),
+}
+
+/// デシリアライズ時にも検証を行うための中間表現
+#[derive(Deserialize)]
+struct PeriodFields {
+    start: NaiveDate,
+    end: NaiveDate,
+    #[serde(default)]
+    bound: PeriodBound,
+}
+
+impl TryFrom<PeriodFields> for Period {
+    type Error = PeriodError;
+
+    fn try_from(fields: PeriodFields) -> Result<Self, Self::Error> {
+        Self::with_bound(fields.start, fields.end, fields.bound)
+    }
+}
+
+impl Period {
+    /// 終了日を含む期間を生成する
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Result<Self, PeriodError> {
+        Self::with_bound(start, end, PeriodBound::Closed)
+    }
+
+    /// 終了日を含まない期間を生成する
+    pub fn half_open(start: NaiveDate, end: NaiveDate) -> Result<Self, PeriodError> {
+        Self::with_bound(start, end, PeriodBound::HalfOpen)
+    }
+
+    pub fn with_bound(start: NaiveDate, end: NaiveDate, bound: PeriodBound) -> Result<Self, PeriodError> {
+        if end < start {
+            Err(PeriodError::EndBeforeStart(start, end))
+        } else {
+            Ok(Self { start, end, bound })
+        }
+    }
+
+    /// 開始日を返す
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// 終了日を返す
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// [PeriodBound]を返す
+    pub fn bound(&self) -> PeriodBound {
+        self.bound
+    }
+
+    /// 期間に含まれない最初の日を返す
+    fn exclusive_end(&self) -> NaiveDate {
+        match self.bound {
+            PeriodBound::Closed => self.end + Days::new(1),
+            PeriodBound::HalfOpen => self.end,
+        }
+    }
+
+    /// 期間に含まれる日がないかどうかを判定する
+    pub fn is_empty(&self) -> bool {
+        self.start == self.exclusive_end()
+    }
+
+    /// 期間の長さを返す
+    pub fn duration(&self) -> Duration {
+        self.exclusive_end() - self.start
+    }
+
+    /// 指定した日が期間に含まれるかどうかを判定する
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        &self.start <= date && date < &self.exclusive_end()
+    }
+
+    /// 期間が指定した日より前に終わっているかどうかを判定する
+    pub fn ends_before(&self, date: &NaiveDate) -> bool {
+        &self.exclusive_end() <= date
+    }
+
+    /// 期間が重なるかどうかを判定する
+    pub fn overlaps(&self, other: &Period) -> bool {
+        self.start < other.exclusive_end() && other.start < self.exclusive_end()
+    }
+
+    /// 重なっている期間を返す
+    ///
+    /// 結果の終了日の扱いは `self` に揃える。重なりがない場合は `None` を返す。
+    pub fn intersection(&self, other: &Period) -> Option<Period> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = self.start.max(other.start);
+        let exclusive_end = self.exclusive_end().min(other.exclusive_end());
+        let end = match self.bound {
+            PeriodBound::Closed => exclusive_end - Days::new(1),
+            PeriodBound::HalfOpen => exclusive_end,
+        };
+        Some(Self { start, end, bound: self.bound })
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.bound {
+            PeriodBound::Closed => write!(f, "[{}, {}]", self.start, self.end),
+            PeriodBound::HalfOpen => write!(f, "[{}, {})", self.start, self.end),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 4, day).unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_end_before_start() {
+        assert!(Period::new(date(2), date(1)).is_err());
+        assert!(Period::half_open(date(2), date(1)).is_err());
+        assert!(Period::new(date(1), date(1)).is_ok());
+    }
+
+    #[test]
+    fn test_closed_and_half_open() {
+        let closed = Period::new(date(1), date(1)).unwrap();
+        assert_eq!(closed.duration(), Duration::days(1));
+        assert!(closed.contains(&date(1)));
+        assert!(!closed.is_empty());
+
+        let half_open = Period::half_open(date(1), date(1)).unwrap();
+        assert_eq!(half_open.duration(), Duration::zero());
+        assert!(!half_open.contains(&date(1)));
+        assert!(half_open.is_empty());
+
+        let half_open = Period::half_open(date(1), date(8)).unwrap();
+        assert_eq!(half_open.duration(), Duration::days(7));
+        assert!(half_open.contains(&date(7)));
+        assert!(!half_open.contains(&date(8)));
+        assert!(half_open.ends_before(&date(8)));
+        assert!(!Period::new(date(1), date(8)).unwrap().ends_before(&date(8)));
+    }
+
+    #[test]
+    fn test_overlaps_and_intersection() {
+        let first = Period::new(date(1), date(14)).unwrap();
+        let second = Period::new(date(14), date(28)).unwrap();
+        let third = Period::half_open(date(1), date(14)).unwrap();
+
+        assert!(first.overlaps(&second));
+        assert_eq!(
+            first.intersection(&second),
+            Some(Period::new(date(14), date(14)).unwrap())
+        );
+        // 終了日を含まない期間とは重ならない
+        assert!(!third.overlaps(&second));
+        assert_eq!(third.intersection(&second), None);
+        assert_eq!(
+            third.intersection(&first),
+            Some(Period::half_open(date(1), date(14)).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let period = Period::half_open(date(1), date(14)).unwrap();
+        let json = serde_json::to_string(&period).unwrap();
+        let deserialized: Period = serde_json::from_str(&json).unwrap();
+        assert_eq!(period, deserialized);
+
+        // 終了日の扱いが省略された場合は終了日を含む期間とする
+        let deserialized: Period = serde_json::from_str(r#"{"start":"2025-04-01","end":"2025-04-14"}"#).unwrap();
+        assert_eq!(deserialized.bound(), PeriodBound::Closed);
+
+        let result = serde_json::from_str::<Period>(r#"{"start":"2025-04-14","end":"2025-04-01"}"#);
+        assert!(result.is_err());
+    }
+}
@@ -1,41 +1,141 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use event_store_adapter_rs::types::Aggregate;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
 mod member;
 mod member_id;
 mod member_role;
 mod members;
+mod permission;
 mod project_error;
 mod project_events;
 mod project_id;
 mod project_name;
+mod project_status;
+mod tag;
+mod tag_id;
+mod tag_name;
+mod workflow;
 
+use crate::description::Description;
 pub use crate::project::member::Member;
 pub use crate::project::member_id::MemberId;
 pub use crate::project::member_role::MemberRole;
 pub use crate::project::members::Members;
+pub use crate::project::permission::{Permission, PermissionPolicy};
 pub use crate::project::project_error::ProjectError;
 pub use crate::project::project_events::{
-    ProjectEvent, ProjectEventCreatedBody, ProjectEventDeletedBody, ProjectEventMemberAddedBody,
-    ProjectEventMemberRemovedBody, ProjectEventRenamedBody,
+    ProjectEvent, ProjectEventArchivedBody, ProjectEventCompletedBody, ProjectEventCreatedBody,
+    ProjectEventDeletedBody, ProjectEventDescriptionChangedBody, ProjectEventMemberAddedBody,
+    ProjectEventMemberLeftBody, ProjectEventMemberRemovedBody, ProjectEventMemberRoleChangedBody,
+    ProjectEventOwnershipTransferredBody, ProjectEventRenamedBody, ProjectEventReopenedBody, ProjectEventRestoredBody,
+    ProjectEventStartedBody, ProjectEventTagCreatedBody, ProjectEventTagDeletedBody, ProjectEventTagRenamedBody,
+    ProjectEventWorkflowChangedBody, ProjectEventWorkflowDefinedBody,
 };
 pub use crate::project::project_id::ProjectId;
 pub use crate::project::project_name::ProjectName;
+pub use crate::project::project_status::ProjectStatus;
+pub use crate::project::tag::Tag;
+pub use crate::project::tag_id::TagId;
+pub use crate::project::tag_name::{TagName, TagNameError};
+pub use crate::project::workflow::{Workflow, WorkflowError, WorkflowTransition};
+use crate::project_invitation::{ProjectInvitation, ProjectInvitationStatus};
+use crate::task::TaskStatus;
 use crate::user::UserId;
 
+/// 削除されたプロジェクトを復元できる猶予期間の既定値 (日)
+pub const DEFAULT_RESTORE_GRACE_PERIOD_DAYS: i64 = 30;
+
+/// ワークフローが定義されていないプロジェクトで利用するワークフロー
+static DEFAULT_WORKFLOW: Lazy<Workflow> = Lazy::new(Workflow::default);
+
 // Serialize, Deserialize はドメインモデルに実装しないようにしたい
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "ProjectSnapshot")]
 pub struct Project {
     id: ProjectId,
     deleted: bool,
+    #[serde(default)]
+    deleted_at: Option<DateTime<Utc>>,
+    name: ProjectName,
+    #[serde(default)]
+    description: Description,
+    #[serde(default)]
+    status: ProjectStatus,
+    owner_id: UserId,
+    members: Members,
+    #[serde(default)]
+    workflow: Option<Workflow>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+    version: usize,
+    seq_nr_counter: usize,
+    last_updated_at: DateTime<Utc>,
+}
+
+/// 保存済みのスナップショットを [Project] に復元するための中間表現
+///
+/// オーナーが導入される前のスナップショットには owner_id が含まれないため、メンバーから導出する。
+/// また、当時の作成者は管理者として保存されているため、オーナーのメンバーのロールを [MemberRole::Owner] に昇格する。
+#[derive(Deserialize)]
+struct ProjectSnapshot {
+    id: ProjectId,
+    deleted: bool,
+    #[serde(default)]
+    deleted_at: Option<DateTime<Utc>>,
     name: ProjectName,
+    #[serde(default)]
+    description: Description,
+    #[serde(default)]
+    status: ProjectStatus,
+    #[serde(default)]
+    owner_id: Option<UserId>,
     members: Members,
+    #[serde(default)]
+    workflow: Option<Workflow>,
+    #[serde(default)]
+    tags: Vec<Tag>,
     version: usize,
     seq_nr_counter: usize,
     last_updated_at: DateTime<Utc>,
 }
 
+impl TryFrom<ProjectSnapshot> for Project {
+    type Error = String;
+
+    fn try_from(snapshot: ProjectSnapshot) -> Result<Self, Self::Error> {
+        let owner_id = match snapshot.owner_id {
+            Some(owner_id) => owner_id,
+            None => Self::derive_owner_id(&snapshot.members).ok_or_else(|| {
+                format!(
+                    "The project has no administrator to be the owner: {}",
+                    snapshot.id
+                )
+            })?,
+        };
+        let mut members = snapshot.members;
+        if !members.is_role(&owner_id, &[MemberRole::Owner]) {
+            members.change_role(&owner_id, MemberRole::Owner);
+        }
+        Ok(Self {
+            id: snapshot.id,
+            deleted: snapshot.deleted,
+            deleted_at: snapshot.deleted_at,
+            name: snapshot.name,
+            description: snapshot.description,
+            status: snapshot.status,
+            owner_id,
+            members,
+            workflow: snapshot.workflow,
+            tags: snapshot.tags,
+            version: snapshot.version,
+            seq_nr_counter: snapshot.seq_nr_counter,
+            last_updated_at: snapshot.last_updated_at,
+        })
+    }
+}
+
 impl PartialEq for Project {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -67,15 +167,33 @@ impl Aggregate for Project {
 }
 
 impl Project {
-    pub fn new(name: ProjectName, members: Members, executor_id: UserId) -> (Self, ProjectEvent) {
+    pub fn new(
+        name: ProjectName,
+        description: Description,
+        members: Members,
+        executor_id: UserId,
+    ) -> (Self, ProjectEvent) {
         let id = ProjectId::new();
-        Self::from(id, false, name, members, 0, 1, executor_id)
+        Self::from(
+            id,
+            false,
+            name,
+            description,
+            ProjectStatus::Planning,
+            members,
+            0,
+            1,
+            executor_id,
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn from(
         id: ProjectId,
         deleted: bool,
         name: ProjectName,
+        description: Description,
+        status: ProjectStatus,
         members: Members,
         seq_nr_counter: usize,
         version: usize,
@@ -85,8 +203,14 @@ impl Project {
         let mut my_self = Self {
             id: id.clone(),
             deleted,
+            deleted_at: None,
             name: name.clone(),
+            description: description.clone(),
+            status,
+            owner_id: executor_id.clone(),
             members: members.clone(),
+            workflow: None,
+            tags: Vec::new(),
             seq_nr_counter,
             version,
             last_updated_at: now,
@@ -96,6 +220,7 @@ impl Project {
             id,
             my_self.seq_nr_counter,
             name,
+            description,
             members,
             executor_id,
             now,
@@ -107,26 +232,97 @@ impl Project {
         match event {
             ProjectEvent::ProjectDeleted(body) => {
                 self.delete(body.executor_id.clone()).unwrap();
+                self.deleted_at = Some(body.occurred_at);
+            },
+            ProjectEvent::ProjectRestored(body) => {
+                // 猶予期間はイベント発生時に検証済みのため、再適用時には検証しない
+                self.restore_at(body.executor_id.clone(), None, body.occurred_at).unwrap();
             },
             ProjectEvent::ProjectMemberAdded(body) => {
-                self.add_member(
-                    body.member.breach_encapsulation_of_id().clone(),
-                    body.member.breach_encapsulation_of_user_id().clone(),
-                    body.member.breach_encapsulation_of_role().clone(),
-                    body.executor_id.clone(),
-                )
-                .unwrap();
+                // 招待の承諾による参加は実行者が権限を持たないため、権限を検証せずに追加する
+                self.push_member(body.member.clone(), body.executor_id.clone());
             },
             ProjectEvent::ProjectMemberRemoved(body) => {
                 self.remove_member(body.user_id.clone(), body.executor_id.clone()).unwrap();
             },
+            ProjectEvent::ProjectMemberLeft(body) => {
+                self.leave(body.executor_id.clone()).unwrap();
+            },
+            ProjectEvent::ProjectMemberRoleChanged(body) => {
+                self.change_member_role(
+                    body.user_id.clone(),
+                    body.new_role.clone(),
+                    body.executor_id.clone(),
+                )
+                .unwrap();
+            },
             ProjectEvent::ProjectRenamed(body) => {
                 self.rename(body.new_name.clone(), body.executor_id.clone()).unwrap();
             },
+            ProjectEvent::ProjectOwnershipTransferred(body) => {
+                self.transfer_ownership(body.new_owner_id.clone(), body.executor_id.clone())
+                    .unwrap();
+            },
+            ProjectEvent::ProjectStarted(body) => {
+                self.start(body.executor_id.clone()).unwrap();
+            },
+            ProjectEvent::ProjectCompleted(body) => {
+                self.complete(body.executor_id.clone()).unwrap();
+            },
+            ProjectEvent::ProjectArchived(body) => {
+                self.archive(body.executor_id.clone()).unwrap();
+            },
+            ProjectEvent::ProjectReopened(body) => {
+                self.reopen(body.executor_id.clone()).unwrap();
+            },
+            ProjectEvent::ProjectDescriptionChanged(body) => {
+                self.change_description(body.new_description.clone(), body.executor_id.clone())
+                    .unwrap();
+            },
+            ProjectEvent::WorkflowDefined(body) => {
+                // タスクが利用している状態はイベント発生時に検証済みのため、再適用時には検証しない
+                self.define_workflow(body.workflow.clone(), &[], body.executor_id.clone())
+                    .unwrap();
+            },
+            ProjectEvent::WorkflowChanged(body) => {
+                self.define_workflow(body.workflow.clone(), &[], body.executor_id.clone())
+                    .unwrap();
+            },
+            ProjectEvent::TagCreated(body) => {
+                self.create_tag(
+                    body.tag.id().clone(),
+                    body.tag.name().clone(),
+                    body.executor_id.clone(),
+                )
+                .unwrap();
+            },
+            ProjectEvent::TagRenamed(body) => {
+                self.rename_tag(
+                    body.tag_id.clone(),
+                    body.new_name.clone(),
+                    body.executor_id.clone(),
+                )
+                .unwrap();
+            },
+            ProjectEvent::TagDeleted(body) => {
+                self.delete_tag(body.tag_id.clone(), body.executor_id.clone()).unwrap();
+            },
             _ => {},
         }
     }
 
+    /// owner_id を持たないスナップショットのオーナーを、オーナーのロールを持つメンバー、
+    /// いなければ管理者のうちメンバーIDの順で最初のメンバーとする
+    fn derive_owner_id(members: &Members) -> Option<UserId> {
+        let members = members.to_vec();
+        [MemberRole::Owner, MemberRole::Admin].iter().find_map(|role| {
+            members
+                .iter()
+                .find(|member| member.breach_encapsulation_of_role() == role)
+                .map(|member| member.breach_encapsulation_of_user_id().clone())
+        })
+    }
+
     pub fn replay(events: &[ProjectEvent], snapshot: Project) -> Self {
         log::debug!("event.size = {}", events.len());
         events.iter().fold(snapshot, |mut result, event| {
@@ -137,16 +333,53 @@ impl Project {
         })
     }
 
+    /// プロジェクトが削除されているかどうかを返す
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
     /// [ProjectName]の参照を返す。
     pub fn name(&self) -> &ProjectName {
         &self.name
     }
 
+    /// [Description]の参照を返す
+    pub fn description(&self) -> &Description {
+        &self.description
+    }
+
+    /// [ProjectStatus]の参照を返す
+    pub fn status(&self) -> &ProjectStatus {
+        &self.status
+    }
+
+    /// オーナーのユーザIDの参照を返す
+    pub fn owner_id(&self) -> &UserId {
+        &self.owner_id
+    }
+
     /// [Members]の参照を返す
     pub fn members(&self) -> &Members {
         &self.members
     }
 
+    /// タスクの[Workflow]の参照を返す
+    ///
+    /// ワークフローが定義されていない場合は [Workflow::default] を返す。
+    pub fn workflow(&self) -> &Workflow {
+        self.workflow.as_ref().unwrap_or(&DEFAULT_WORKFLOW)
+    }
+
+    /// タグカタログに登録されている[Tag]のスライスを返す
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// タグカタログから[Tag]を検索する
+    pub fn find_tag(&self, tag_id: &TagId) -> Option<&Tag> {
+        self.tags.iter().find(|tag| tag.id() == tag_id)
+    }
+
     /// プロジェクトを削除する
     ///
     /// # 引数
@@ -154,21 +387,17 @@ impl Project {
     ///
     /// # 戻り値
     /// - プロジェクトが削除されている場合はエラーを返す。
-    /// - 実行者が管理者でない場合はエラーを返す。
+    /// - 実行者が [Permission::DeleteProject] を持たない場合はエラーを返す。
     /// - 成功した場合は、ProjectDeletedイベントを返す。
     pub fn delete(&mut self, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
         if self.deleted {
             return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
         }
-        if !self.members.is_administrator(&executor_id) {
-            return Err(ProjectError::NotAdministratorError(
-                "executor_id".to_string(),
-                executor_id,
-            ));
-        }
+        self.ensure_permission(&executor_id, Permission::DeleteProject)?;
+        let now = Utc::now();
         self.deleted = true;
+        self.deleted_at = Some(now);
         self.seq_nr_counter += 1;
-        let now = Utc::now();
         Ok(ProjectEvent::ProjectDeleted(ProjectEventDeletedBody::new(
             self.id.clone(),
             self.seq_nr_counter,
@@ -177,6 +406,51 @@ impl Project {
         )))
     }
 
+    /// 削除されたプロジェクトを復元する
+    ///
+    /// # 引数
+    /// - executor_id: 実行者のユーザID
+    /// - grace_period: 削除後に復元できる猶予期間
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されていない場合はエラーを返す。
+    /// - 実行者が [Permission::DeleteProject] を持たない場合はエラーを返す。
+    /// - 削除から猶予期間が経過している場合はエラーを返す。
+    /// - 成功した場合は、ProjectRestoredイベントを返す。
+    pub fn restore(&mut self, executor_id: UserId, grace_period: Duration) -> Result<ProjectEvent, ProjectError> {
+        self.restore_at(executor_id, Some(grace_period), Utc::now())
+    }
+
+    fn restore_at(
+        &mut self,
+        executor_id: UserId,
+        grace_period: Option<Duration>,
+        now: DateTime<Utc>,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if !self.deleted {
+            return Err(ProjectError::NotDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::DeleteProject)?;
+        if let Some(grace_period) = grace_period {
+            let deadline = self.deleted_at.map(|deleted_at| deleted_at + grace_period);
+            match deadline {
+                Some(deadline) if now <= deadline => {},
+                _ => {
+                    return Err(ProjectError::RestoreGracePeriodExpiredError(
+                        self.id.clone(),
+                        deadline.unwrap_or(now),
+                    ));
+                },
+            }
+        }
+        self.deleted = false;
+        self.deleted_at = None;
+        self.seq_nr_counter += 1;
+        Ok(ProjectEvent::ProjectRestored(
+            ProjectEventRestoredBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
     /// プロジェクトにメンバーを追加する
     ///
     /// # 引数
@@ -187,7 +461,8 @@ impl Project {
     ///
     /// # 戻り値
     /// - プロジェクトが削除されている場合はエラーを返す。
-    /// - 実行者が管理者でない場合はエラーを返す。
+    /// - 実行者が [Permission::ManageMembers] を持たない場合はエラーを返す。
+    /// - オーナーのロールを指定した場合はエラーを返す。
     /// - ユーザIDが既にメンバーに設定されている場合はエラーを返す。
     /// - 成功した場合は、ProjectMemberAddedイベントを返す。
     pub fn add_member(
@@ -200,11 +475,9 @@ impl Project {
         if self.deleted {
             return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
         }
-        if !self.members.is_administrator(&executor_id) {
-            return Err(ProjectError::NotAdministratorError(
-                "executor_id".to_string(),
-                executor_id,
-            ));
+        self.ensure_permission(&executor_id, Permission::ManageMembers)?;
+        if role == MemberRole::Owner {
+            return Err(ProjectError::OwnerRoleChangeError(user_id));
         }
         if self.members.is_member(&user_id) {
             return Err(ProjectError::AlreadyMemberError(
@@ -212,18 +485,64 @@ impl Project {
                 user_id,
             ));
         }
-        let member = Member::new(member_id, user_id, role);
-        self.members.add_member(member.clone());
-        self.seq_nr_counter += 1;
-        let now = Utc::now();
-        Ok(ProjectEvent::ProjectMemberAdded(
-            ProjectEventMemberAddedBody::new(
+        Ok(self.push_member(Member::new(member_id, user_id, role), executor_id))
+    }
+
+    /// 承諾された招待によってプロジェクトに参加する
+    ///
+    /// 招待の送信時に [Permission::ManageMembers] を検証済みのため、招待を送信したユーザの現在の権限は問わない。
+    ///
+    /// # 引数
+    /// - invitation: 承諾された招待
+    /// - member_id: メンバーID
+    /// - executor_id: 招待を承諾したユーザのID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 招待が他のプロジェクトへの招待である場合はエラーを返す。
+    /// - 招待が承諾されていない場合はエラーを返す。
+    /// - 実行者が既にメンバーの場合はエラーを返す。
+    /// - 成功した場合は、ProjectMemberAddedイベントを返す。
+    pub fn join_by_invitation(
+        &mut self,
+        invitation: &ProjectInvitation,
+        member_id: MemberId,
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        if invitation.project_id() != &self.id {
+            return Err(ProjectError::MismatchedInvitationError(
+                invitation.id().clone(),
                 self.id.clone(),
-                self.seq_nr_counter,
-                member,
+            ));
+        }
+        if invitation.status() != &ProjectInvitationStatus::Accepted {
+            return Err(ProjectError::InvitationNotAcceptedError(
+                invitation.id().clone(),
+            ));
+        }
+        if self.members.is_member(&executor_id) {
+            return Err(ProjectError::AlreadyMemberError(
+                "executor_id".to_string(),
                 executor_id,
-                now,
-            ),
+            ));
+        }
+        let member = Member::new(member_id, executor_id.clone(), invitation.role().clone());
+        Ok(self.push_member(member, executor_id))
+    }
+
+    /// 検証済みのメンバーを追加し、ProjectMemberAddedイベントを返す
+    fn push_member(&mut self, member: Member, executor_id: UserId) -> ProjectEvent {
+        self.members.add_member(member.clone());
+        self.seq_nr_counter += 1;
+        ProjectEvent::ProjectMemberAdded(ProjectEventMemberAddedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            member,
+            executor_id,
+            Utc::now(),
         ))
     }
 
@@ -235,22 +554,31 @@ impl Project {
     ///
     /// # 戻り値
     /// - プロジェクトが削除されている場合はエラーを返す。
-    /// - 実行者が管理者でない場合はエラーを返す。
+    /// - 実行者が [Permission::ManageMembers] を持たない場合はエラーを返す。
     /// - ユーザIDがメンバーに設定されていない場合はエラーを返す。
+    /// - ユーザIDが唯一の管理者の場合はエラーを返す。
+    /// - ユーザIDがオーナーの場合はエラーを返す。
     /// - 成功した場合は、ProjectMemberRemovedイベントを返す。
     pub fn remove_member(&mut self, user_id: UserId, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
         if self.deleted {
             return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
         }
-        if !self.members.is_administrator(&executor_id) {
-            return Err(ProjectError::NotAdministratorError(
-                "executor_id".to_string(),
-                executor_id,
-            ));
-        }
+        self.ensure_permission(&executor_id, Permission::ManageMembers)?;
         if !self.members.is_member(&user_id) {
             return Err(ProjectError::NotMemberError("user_id".to_string(), user_id));
         }
+        if self.members.is_last_administrator(&user_id) {
+            return Err(ProjectError::LastAdministratorError(
+                "user_id".to_string(),
+                user_id,
+            ));
+        }
+        if self.owner_id == user_id {
+            return Err(ProjectError::OwnerCannotBeRemovedError(
+                "user_id".to_string(),
+                user_id,
+            ));
+        }
 
         self.members.remove_member_by_user_id(&user_id);
         self.seq_nr_counter += 1;
@@ -266,18 +594,20 @@ impl Project {
         ))
     }
 
-    /// プロジェクト名を変更する
+    /// プロジェクトから実行者自身が脱退する
+    ///
+    /// 管理者による [Project::remove_member] とは区別され、ProjectMemberLeftイベントとして記録される。
     ///
     /// # 引数
-    /// - new_name: 新しいプロジェクト名
     /// - executor_id: 実行者のユーザID
     ///
     /// # 戻り値
     /// - プロジェクトが削除されている場合はエラーを返す。
     /// - 実行者がメンバーでない場合はエラーを返す。
-    /// - 実行者が管理者でない場合はエラーを返す。
-    /// - 成功した場合は、ProjectRenamedイベントを返す。
-    pub fn rename(&mut self, new_name: ProjectName, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+    /// - 実行者がオーナーの場合はエラーを返す。
+    /// - 実行者が管理者の場合はエラーを返す。管理者は他の管理者によって降格されてから脱退する。
+    /// - 成功した場合は、ProjectMemberLeftイベントを返す。
+    pub fn leave(&mut self, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
         if self.deleted {
             return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
         }
@@ -287,12 +617,147 @@ impl Project {
                 executor_id,
             ));
         }
-        if !self.members.is_administrator(&executor_id) {
-            return Err(ProjectError::NotAdministratorError(
+        if self.owner_id == executor_id {
+            return Err(ProjectError::OwnerCannotBeRemovedError(
                 "executor_id".to_string(),
                 executor_id,
             ));
         }
+        if self.members.is_administrator(&executor_id) {
+            return Err(ProjectError::AdministratorCannotLeaveError(
+                "executor_id".to_string(),
+                executor_id,
+            ));
+        }
+
+        self.members.remove_member_by_user_id(&executor_id);
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectMemberLeft(
+            ProjectEventMemberLeftBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    /// プロジェクトのメンバーのロールを変更する
+    ///
+    /// # 引数
+    /// - user_id: ユーザID
+    /// - new_role: 新しいメンバーの役割
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageMembers] を持たない場合はエラーを返す。
+    /// - 唯一の管理者を管理者以外に変更する場合はエラーを返す。
+    /// - オーナーのロールを変更する、またはオーナーのロールを指定した場合はエラーを返す。
+    /// - ユーザIDがメンバーに設定されていない場合はエラーを返す。
+    /// - 成功した場合は、ProjectMemberRoleChangedイベントを返す。
+    pub fn change_member_role(
+        &mut self,
+        user_id: UserId,
+        new_role: MemberRole,
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::ManageMembers)?;
+        if !new_role.is_administrative() && self.members.is_last_administrator(&user_id) {
+            return Err(ProjectError::LastAdministratorError(
+                "user_id".to_string(),
+                user_id,
+            ));
+        }
+        if new_role == MemberRole::Owner || self.owner_id == user_id {
+            return Err(ProjectError::OwnerRoleChangeError(user_id));
+        }
+        let member = self
+            .members
+            .change_role(&user_id, new_role.clone())
+            .ok_or_else(|| ProjectError::NotMemberError("user_id".to_string(), user_id.clone()))?;
+
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectMemberRoleChanged(
+            ProjectEventMemberRoleChangedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                member.breach_encapsulation_of_id().clone(),
+                user_id,
+                new_role,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// プロジェクトのオーナーを移譲する
+    ///
+    /// # 引数
+    /// - new_owner_id: 新しいオーナーのユーザID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::TransferOwnership] を持たない場合はエラーを返す。
+    /// - 新しいオーナーが既にオーナーである場合はエラーを返す。
+    /// - 新しいオーナーがメンバーでない場合はエラーを返す。
+    ///
+    /// 新しいオーナーのロールは [MemberRole::Owner] に、元のオーナーのロールは [MemberRole::Admin] に変更される。
+    /// - 成功した場合は、ProjectOwnershipTransferredイベントを返す。
+    pub fn transfer_ownership(
+        &mut self,
+        new_owner_id: UserId,
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::TransferOwnership)?;
+        if self.owner_id == new_owner_id {
+            return Err(ProjectError::AlreadyOwnerError(
+                "new_owner_id".to_string(),
+                new_owner_id,
+            ));
+        }
+        if !self.members.is_member(&new_owner_id) {
+            return Err(ProjectError::NotMemberError(
+                "new_owner_id".to_string(),
+                new_owner_id,
+            ));
+        }
+
+        self.members.change_role(&self.owner_id, MemberRole::Admin);
+        self.members.change_role(&new_owner_id, MemberRole::Owner);
+        self.owner_id = new_owner_id.clone();
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectOwnershipTransferred(
+            ProjectEventOwnershipTransferredBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                new_owner_id,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// プロジェクト名を変更する
+    ///
+    /// # 引数
+    /// - new_name: 新しいプロジェクト名
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::RenameProject] を持たない場合はエラーを返す。
+    /// - 成功した場合は、ProjectRenamedイベントを返す。
+    pub fn rename(&mut self, new_name: ProjectName, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::RenameProject)?;
 
         self.name = new_name.clone();
         self.seq_nr_counter += 1;
@@ -305,25 +770,364 @@ impl Project {
             now,
         )))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// プロジェクトの説明を変更する
+    ///
+    /// # 引数
+    /// - new_description: 新しいプロジェクトの説明
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::EditProjectDescription] を持たない場合はエラーを返す。
+    /// - 成功した場合は、ProjectDescriptionChangedイベントを返す。
+    pub fn change_description(
+        &mut self,
+        new_description: Description,
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::EditProjectDescription)?;
 
-    #[test]
-    fn test_delete_project() {
-        let executor_id = UserId::default();
-        let user_id = UserId::default();
+        self.description = new_description.clone();
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectDescriptionChanged(
+            ProjectEventDescriptionChangedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                new_description,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// タスクのワークフローを定義する
+    ///
+    /// # 引数
+    /// - workflow: 新しいワークフロー
+    /// - statuses_in_use: プロジェクトのタスクが利用している状態
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageWorkflow] を持たない場合はエラーを返す。
+    /// - タスクが利用している状態から遷移できなくなる場合はエラーを返す。
+    /// - 初めて定義した場合は WorkflowDefinedイベント、既に定義されていた場合は WorkflowChangedイベントを返す。
+    pub fn define_workflow(
+        &mut self,
+        workflow: Workflow,
+        statuses_in_use: &[TaskStatus],
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::ManageWorkflow)?;
+        workflow
+            .ensure_accommodates(statuses_in_use)
+            .map_err(ProjectError::IncompatibleWorkflowError)?;
+
+        let defined = self.workflow.replace(workflow.clone()).is_some();
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        if defined {
+            Ok(ProjectEvent::WorkflowChanged(
+                ProjectEventWorkflowChangedBody::new(
+                    self.id.clone(),
+                    self.seq_nr_counter,
+                    workflow,
+                    executor_id,
+                    now,
+                ),
+            ))
+        } else {
+            Ok(ProjectEvent::WorkflowDefined(
+                ProjectEventWorkflowDefinedBody::new(
+                    self.id.clone(),
+                    self.seq_nr_counter,
+                    workflow,
+                    executor_id,
+                    now,
+                ),
+            ))
+        }
+    }
+
+    /// タグカタログにタグを追加する
+    ///
+    /// # 引数
+    /// - tag_id: 追加するタグのID
+    /// - name: タグ名
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTags] を持たない場合はエラーを返す。
+    /// - 同じ名前のタグが既に存在する場合はエラーを返す。
+    /// - 成功した場合は、TagCreatedイベントを返す。
+    pub fn create_tag(
+        &mut self,
+        tag_id: TagId,
+        name: TagName,
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::ManageTags)?;
+        self.ensure_unique_tag_name(None, &name)?;
+
+        let tag = Tag::new(tag_id, name);
+        self.tags.push(tag.clone());
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::TagCreated(ProjectEventTagCreatedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            tag,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タグ名を変更する
+    ///
+    /// # 引数
+    /// - tag_id: 変更するタグのID
+    /// - new_name: 新しいタグ名
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTags] を持たない場合はエラーを返す。
+    /// - タグが存在しない場合はエラーを返す。
+    /// - 同じ名前の他のタグが既に存在する場合はエラーを返す。
+    /// - 成功した場合は、TagRenamedイベントを返す。
+    pub fn rename_tag(
+        &mut self,
+        tag_id: TagId,
+        new_name: TagName,
+        executor_id: UserId,
+    ) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::ManageTags)?;
+        self.ensure_unique_tag_name(Some(&tag_id), &new_name)?;
+        let tag = self
+            .tags
+            .iter_mut()
+            .find(|tag| tag.id() == &tag_id)
+            .ok_or_else(|| ProjectError::TagNotFoundError(tag_id.clone()))?;
+
+        tag.rename(new_name.clone());
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::TagRenamed(ProjectEventTagRenamedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            tag_id,
+            new_name,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タグカタログからタグを削除する
+    ///
+    /// タスクに付与されたタグは、TagDeletedイベントを受けて別途取り除く。
+    ///
+    /// # 引数
+    /// - tag_id: 削除するタグのID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTags] を持たない場合はエラーを返す。
+    /// - タグが存在しない場合はエラーを返す。
+    /// - 成功した場合は、TagDeletedイベントを返す。
+    pub fn delete_tag(&mut self, tag_id: TagId, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(&executor_id, Permission::ManageTags)?;
+        if self.find_tag(&tag_id).is_none() {
+            return Err(ProjectError::TagNotFoundError(tag_id));
+        }
+
+        self.tags.retain(|tag| tag.id() != &tag_id);
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(ProjectEvent::TagDeleted(ProjectEventTagDeletedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            tag_id,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// 他のタグと名前が重複しないことを検証する
+    fn ensure_unique_tag_name(&self, tag_id: Option<&TagId>, name: &TagName) -> Result<(), ProjectError> {
+        if self.tags.iter().any(|tag| Some(tag.id()) != tag_id && tag.name() == name) {
+            return Err(ProjectError::DuplicateTagNameError(name.clone()));
+        }
+        Ok(())
+    }
+
+    /// プロジェクトを開始する
+    ///
+    /// # 引数
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ChangeProjectStatus] を持たない場合はエラーを返す。
+    /// - 計画中のプロジェクトでない場合はエラーを返す。
+    /// - 成功した場合は、ProjectStartedイベントを返す。
+    pub fn start(&mut self, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+        self.change_status(
+            ProjectStatus::InProgress,
+            &[ProjectStatus::Planning],
+            &executor_id,
+        )?;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectStarted(ProjectEventStartedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// プロジェクトを完了する
+    ///
+    /// # 引数
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ChangeProjectStatus] を持たない場合はエラーを返す。
+    /// - 進行中のプロジェクトでない場合はエラーを返す。
+    /// - 成功した場合は、ProjectCompletedイベントを返す。
+    pub fn complete(&mut self, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+        self.change_status(
+            ProjectStatus::Completed,
+            &[ProjectStatus::InProgress],
+            &executor_id,
+        )?;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectCompleted(
+            ProjectEventCompletedBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    /// プロジェクトをアーカイブする
+    ///
+    /// # 引数
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ChangeProjectStatus] を持たない場合はエラーを返す。
+    /// - 既にアーカイブされている場合はエラーを返す。
+    /// - 成功した場合は、ProjectArchivedイベントを返す。
+    pub fn archive(&mut self, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+        self.change_status(
+            ProjectStatus::Archived,
+            &[ProjectStatus::Planning, ProjectStatus::InProgress, ProjectStatus::Completed],
+            &executor_id,
+        )?;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectArchived(
+            ProjectEventArchivedBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    /// 完了またはアーカイブされたプロジェクトを再開する
+    ///
+    /// # 引数
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ChangeProjectStatus] を持たない場合はエラーを返す。
+    /// - 完了またはアーカイブされたプロジェクトでない場合はエラーを返す。
+    /// - 成功した場合は、ProjectReopenedイベントを返す。
+    pub fn reopen(&mut self, executor_id: UserId) -> Result<ProjectEvent, ProjectError> {
+        self.change_status(
+            ProjectStatus::InProgress,
+            &[ProjectStatus::Completed, ProjectStatus::Archived],
+            &executor_id,
+        )?;
+        let now = Utc::now();
+        Ok(ProjectEvent::ProjectReopened(
+            ProjectEventReopenedBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    /// プロジェクトの状態を遷移させる
+    ///
+    /// 遷移元の状態が `from` に含まれ、かつ [ProjectStatus::can_transition_to] で許可されている場合のみ遷移する。
+    fn change_status(
+        &mut self,
+        next: ProjectStatus,
+        from: &[ProjectStatus],
+        executor_id: &UserId,
+    ) -> Result<(), ProjectError> {
+        if self.deleted {
+            return Err(ProjectError::AlreadyDeletedError(self.id.clone()));
+        }
+        self.ensure_permission(executor_id, Permission::ChangeProjectStatus)?;
+        if !from.contains(&self.status) || !self.status.can_transition_to(&next) {
+            return Err(ProjectError::InvalidStatusTransitionError(
+                self.status,
+                next,
+            ));
+        }
+
+        self.status = next;
+        self.seq_nr_counter += 1;
+        Ok(())
+    }
+
+    /// 実行者が指定した権限を持つかどうかを検証する
+    fn ensure_permission(&self, executor_id: &UserId, permission: Permission) -> Result<(), ProjectError> {
+        if !self.members.has_permission(executor_id, &permission) {
+            return Err(ProjectError::PermissionDeniedError(
+                "executor_id".to_string(),
+                permission,
+                executor_id.clone(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use event_store_adapter_rs::types::Event;
+
+    #[test]
+    fn test_delete_project() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let admin_id = UserId::default();
         let mut members = Members::new(executor_id.clone());
         members.add_member(Member::new(
             MemberId::default(),
-            executor_id.clone(),
+            admin_id.clone(),
             MemberRole::Admin,
         ));
 
         let (mut project, _) = Project::new(
             ProjectName::new("Test").unwrap(),
+            Description::default(),
             members,
             executor_id.clone(),
         );
@@ -331,10 +1135,124 @@ mod tests {
         let result = project.delete(user_id.clone());
         assert!(result.is_err());
 
+        let result = project.delete(admin_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::PermissionDeniedError(
+                _,
+                Permission::DeleteProject,
+                _
+            ))
+        ));
+
         let result = project.delete(executor_id.clone());
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_restore_project() {
+        let executor_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+
+        assert!(matches!(
+            project.restore(
+                executor_id.clone(),
+                Duration::days(DEFAULT_RESTORE_GRACE_PERIOD_DAYS)
+            ),
+            Err(ProjectError::NotDeletedError(_))
+        ));
+
+        let deleted_event = project.delete(executor_id.clone()).unwrap();
+        let deleted_at = *deleted_event.occurred_at();
+
+        // 猶予期間を過ぎると復元できない
+        let result = project.restore_at(
+            executor_id.clone(),
+            Some(Duration::days(1)),
+            deleted_at + Duration::days(2),
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectError::RestoreGracePeriodExpiredError(_, _))
+        ));
+        assert!(project.is_deleted());
+
+        let restored_event = project
+            .restore(
+                executor_id.clone(),
+                Duration::days(DEFAULT_RESTORE_GRACE_PERIOD_DAYS),
+            )
+            .unwrap();
+        assert!(matches!(restored_event, ProjectEvent::ProjectRestored(_)));
+        assert!(!project.is_deleted());
+        assert!(project.rename(ProjectName::new("Renamed").unwrap(), executor_id).is_ok());
+    }
+
+    #[test]
+    fn test_transfer_ownership() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        assert_eq!(project.owner_id(), &executor_id);
+
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                user_id.clone(),
+                MemberRole::Member,
+                executor_id.clone(),
+            )
+            .unwrap();
+
+        let snapshot = project.clone();
+
+        let result = project.transfer_ownership(UserId::default(), executor_id.clone());
+        assert!(matches!(result, Err(ProjectError::NotMemberError(_, _))));
+
+        let result = project.transfer_ownership(executor_id.clone(), user_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::PermissionDeniedError(_, _, _))
+        ));
+
+        let event = project.transfer_ownership(user_id.clone(), executor_id.clone()).unwrap();
+        assert_eq!(project.owner_id(), &user_id);
+        assert!(project.members().is_role(&user_id, &[MemberRole::Owner]));
+        assert!(project.members().is_role(&executor_id, &[MemberRole::Admin]));
+
+        let result = project.delete(executor_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::PermissionDeniedError(_, _, _))
+        ));
+
+        let result = project.remove_member(user_id.clone(), executor_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::OwnerCannotBeRemovedError(_, _))
+        ));
+
+        let replayed = Project::replay(&[event], snapshot);
+        assert_eq!(replayed.owner_id(), &user_id);
+        assert!(replayed.members().is_role(&user_id, &[MemberRole::Owner]));
+
+        assert!(project.delete(user_id.clone()).is_ok());
+    }
+
     #[test]
     fn test_add_member() {
         let executor_id = UserId::default();
@@ -349,6 +1267,7 @@ mod tests {
 
         let (mut project, _) = Project::new(
             ProjectName::new("Test").unwrap(),
+            Description::default(),
             members,
             executor_id.clone(),
         );
@@ -379,6 +1298,7 @@ mod tests {
 
         let (mut project, _) = Project::new(
             ProjectName::new("Test").unwrap(),
+            Description::default(),
             members,
             executor_id.clone(),
         );
@@ -396,6 +1316,491 @@ mod tests {
         assert!(!project.members().is_member(&user_id));
     }
 
+    #[test]
+    fn test_leave() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                user_id.clone(),
+                MemberRole::Member,
+                executor_id.clone(),
+            )
+            .unwrap();
+
+        let event = project.leave(user_id.clone()).unwrap();
+        assert!(matches!(event, ProjectEvent::ProjectMemberLeft(_)));
+        assert!(!project.members().is_member(&user_id));
+
+        // 既に脱退したメンバーは再度脱退できない
+        assert!(matches!(
+            project.leave(user_id.clone()),
+            Err(ProjectError::NotMemberError(_, _))
+        ));
+        // オーナーは脱退できない
+        assert!(matches!(
+            project.leave(executor_id.clone()),
+            Err(ProjectError::OwnerCannotBeRemovedError(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_administrator_cannot_leave() {
+        let executor_id = UserId::default();
+        let admin_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                admin_id.clone(),
+                MemberRole::Admin,
+                executor_id.clone(),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            project.leave(admin_id.clone()),
+            Err(ProjectError::AdministratorCannotLeaveError(_, _))
+        ));
+        assert!(project.members().is_member(&admin_id));
+
+        // 降格された後であれば脱退できる
+        let _ = project
+            .change_member_role(admin_id.clone(), MemberRole::Member, executor_id.clone())
+            .unwrap();
+        let _ = project.leave(admin_id.clone()).unwrap();
+        assert!(!project.members().is_member(&admin_id));
+    }
+
+    #[test]
+    fn test_last_administrator_is_protected() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+
+        let result = project.remove_member(executor_id.clone(), executor_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::LastAdministratorError(_, _))
+        ));
+
+        let result = project.change_member_role(executor_id.clone(), MemberRole::Member, executor_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::LastAdministratorError(_, _))
+        ));
+
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                user_id.clone(),
+                MemberRole::Admin,
+                executor_id.clone(),
+            )
+            .unwrap();
+        let _ = project.transfer_ownership(user_id.clone(), executor_id.clone()).unwrap();
+        let _ = project.remove_member(executor_id.clone(), executor_id.clone()).unwrap();
+        assert!(!project.members().is_member(&executor_id));
+        assert!(project.members().is_last_administrator(&user_id));
+    }
+
+    #[test]
+    fn test_owner_role_is_protected() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let other_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                user_id.clone(),
+                MemberRole::Admin,
+                executor_id.clone(),
+            )
+            .unwrap();
+
+        let result = project.change_member_role(executor_id.clone(), MemberRole::Member, executor_id.clone());
+        assert!(matches!(result, Err(ProjectError::OwnerRoleChangeError(_))));
+
+        let result = project.remove_member(executor_id.clone(), executor_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::OwnerCannotBeRemovedError(_, _))
+        ));
+
+        let result = project.add_member(
+            MemberId::default(),
+            other_id.clone(),
+            MemberRole::Owner,
+            executor_id.clone(),
+        );
+        assert!(matches!(result, Err(ProjectError::OwnerRoleChangeError(_))));
+
+        let result = project.change_member_role(user_id.clone(), MemberRole::Owner, executor_id.clone());
+        assert!(matches!(result, Err(ProjectError::OwnerRoleChangeError(_))));
+    }
+
+    #[test]
+    fn test_permissions_by_role() {
+        let executor_id = UserId::default();
+        let member_id = UserId::default();
+        let viewer_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                member_id.clone(),
+                MemberRole::Member,
+                executor_id.clone(),
+            )
+            .unwrap();
+        let _ = project
+            .add_member(
+                MemberId::default(),
+                viewer_id.clone(),
+                MemberRole::Viewer,
+                executor_id.clone(),
+            )
+            .unwrap();
+
+        let result = project.rename(ProjectName::new("Renamed").unwrap(), member_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::PermissionDeniedError(
+                _,
+                Permission::RenameProject,
+                _
+            ))
+        ));
+        let result = project.remove_member(member_id.clone(), viewer_id.clone());
+        assert!(matches!(
+            result,
+            Err(ProjectError::PermissionDeniedError(
+                _,
+                Permission::ManageMembers,
+                _
+            ))
+        ));
+        assert!(project.members().has_permission(&member_id, &Permission::ManageTasks));
+        assert!(!project.members().has_permission(&viewer_id, &Permission::ManageTasks));
+        assert!(project.members().has_permission(&viewer_id, &Permission::ViewProject));
+    }
+
+    #[test]
+    fn test_change_member_role() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let member_id = MemberId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+
+        let _ = project
+            .add_member(
+                member_id.clone(),
+                user_id.clone(),
+                MemberRole::Member,
+                executor_id.clone(),
+            )
+            .unwrap();
+        let snapshot = project.clone();
+
+        let result = project.change_member_role(user_id.clone(), MemberRole::Admin, user_id.clone());
+        assert!(result.is_err());
+
+        let event = project
+            .change_member_role(user_id.clone(), MemberRole::Admin, executor_id.clone())
+            .unwrap();
+        assert!(project.members().is_administrator(&user_id));
+        assert_eq!(
+            project
+                .members()
+                .find_by_user_id(&user_id)
+                .unwrap()
+                .breach_encapsulation_of_id(),
+            &member_id
+        );
+
+        let replayed = Project::replay(&[event], snapshot);
+        assert!(replayed.members().is_administrator(&user_id));
+    }
+
+    #[test]
+    fn test_change_description() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let mut members = Members::new(executor_id.clone());
+        members.add_member(Member::new(
+            MemberId::default(),
+            user_id.clone(),
+            MemberRole::Member,
+        ));
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::new("before").unwrap(),
+            members,
+            executor_id.clone(),
+        );
+        let snapshot = project.clone();
+
+        let new_description = Description::new("after").unwrap();
+        let result = project.change_description(new_description.clone(), user_id.clone());
+        assert!(result.is_err());
+
+        let event = project
+            .change_description(new_description.clone(), executor_id.clone())
+            .unwrap();
+        assert_eq!(project.description(), &new_description);
+
+        let replayed = Project::replay(&[event], snapshot);
+        assert_eq!(replayed.description(), &new_description);
+    }
+
+    #[test]
+    fn test_define_workflow() {
+        let executor_id = UserId::default();
+        let user_id = UserId::default();
+        let mut members = Members::new(executor_id.clone());
+        members.add_member(Member::new(
+            MemberId::default(),
+            user_id.clone(),
+            MemberRole::Member,
+        ));
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        let snapshot = project.clone();
+        assert_eq!(project.workflow(), &Workflow::default());
+
+        let workflow = Workflow::new(
+            vec![TaskStatus::Todo, TaskStatus::Done],
+            vec![WorkflowTransition::new(TaskStatus::Todo, TaskStatus::Done)],
+        )
+        .unwrap();
+        assert!(matches!(
+            project.define_workflow(workflow.clone(), &[], user_id),
+            Err(ProjectError::PermissionDeniedError(
+                _,
+                Permission::ManageWorkflow,
+                _
+            ))
+        ));
+
+        // 作業中のタスクが残っている状態を除くワークフローは定義できない
+        assert!(matches!(
+            project.define_workflow(
+                workflow.clone(),
+                &[TaskStatus::InProgress],
+                executor_id.clone()
+            ),
+            Err(ProjectError::IncompatibleWorkflowError(
+                WorkflowError::StatusInUseRemoved(TaskStatus::InProgress)
+            ))
+        ));
+
+        let defined = project
+            .define_workflow(workflow.clone(), &[TaskStatus::Todo], executor_id.clone())
+            .unwrap();
+        assert!(matches!(defined, ProjectEvent::WorkflowDefined(_)));
+        let changed = project
+            .define_workflow(
+                Workflow::default(),
+                &[TaskStatus::Todo],
+                executor_id.clone(),
+            )
+            .unwrap();
+        assert!(matches!(changed, ProjectEvent::WorkflowChanged(_)));
+
+        let replayed = Project::replay(&[defined.clone()], snapshot.clone());
+        assert_eq!(replayed.workflow(), &workflow);
+        let replayed = Project::replay(&[defined, changed], snapshot);
+        assert_eq!(replayed.workflow(), &Workflow::default());
+        assert_eq!(replayed.seq_nr(), project.seq_nr());
+    }
+
+    #[test]
+    fn test_tag_catalog() {
+        let executor_id = UserId::default();
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            Members::new(executor_id.clone()),
+            executor_id.clone(),
+        );
+        let snapshot = project.clone();
+        let bug_id = TagId::new();
+        let feature_id = TagId::new();
+
+        let mut events = vec![
+            project
+                .create_tag(
+                    bug_id.clone(),
+                    TagName::new("bug").unwrap(),
+                    executor_id.clone(),
+                )
+                .unwrap(),
+            project
+                .create_tag(
+                    feature_id.clone(),
+                    TagName::new("feature").unwrap(),
+                    executor_id.clone(),
+                )
+                .unwrap(),
+        ];
+        assert!(matches!(
+            project.create_tag(
+                TagId::new(),
+                TagName::new("bug").unwrap(),
+                executor_id.clone()
+            ),
+            Err(ProjectError::DuplicateTagNameError(_))
+        ));
+        assert!(matches!(
+            project.create_tag(
+                TagId::new(),
+                TagName::new("docs").unwrap(),
+                UserId::default()
+            ),
+            Err(ProjectError::PermissionDeniedError(
+                _,
+                Permission::ManageTags,
+                _
+            ))
+        ));
+        assert!(matches!(
+            project.rename_tag(
+                feature_id.clone(),
+                TagName::new("bug").unwrap(),
+                executor_id.clone()
+            ),
+            Err(ProjectError::DuplicateTagNameError(_))
+        ));
+
+        events.push(
+            project
+                .rename_tag(
+                    bug_id.clone(),
+                    TagName::new("defect").unwrap(),
+                    executor_id.clone(),
+                )
+                .unwrap(),
+        );
+        events.push(project.delete_tag(feature_id.clone(), executor_id.clone()).unwrap());
+        assert!(matches!(
+            project.delete_tag(feature_id, executor_id),
+            Err(ProjectError::TagNotFoundError(_))
+        ));
+        assert_eq!(project.tags().len(), 1);
+        assert_eq!(
+            project.find_tag(&bug_id).map(|tag| tag.name().to_string()),
+            Some("defect".to_string())
+        );
+
+        let replayed = Project::replay(&events, snapshot);
+        assert_eq!(replayed.tags(), project.tags());
+    }
+
+    #[test]
+    fn test_change_status() {
+        let executor_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        assert_eq!(project.status(), &ProjectStatus::Planning);
+
+        assert!(project.complete(executor_id.clone()).is_err());
+        assert!(project.reopen(executor_id.clone()).is_err());
+        assert!(project.start(UserId::default()).is_err());
+
+        let _ = project.start(executor_id.clone()).unwrap();
+        assert_eq!(project.status(), &ProjectStatus::InProgress);
+
+        let _ = project.complete(executor_id.clone()).unwrap();
+        assert_eq!(project.status(), &ProjectStatus::Completed);
+
+        let _ = project.archive(executor_id.clone()).unwrap();
+        assert_eq!(project.status(), &ProjectStatus::Archived);
+        assert!(project.archive(executor_id.clone()).is_err());
+
+        let _ = project.reopen(executor_id.clone()).unwrap();
+        assert_eq!(project.status(), &ProjectStatus::InProgress);
+    }
+
+    #[test]
+    fn test_replay_status_events() {
+        let executor_id = UserId::default();
+        let members = Members::new(executor_id.clone());
+
+        let (mut project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
+        let snapshot = project.clone();
+
+        let events = vec![
+            project.start(executor_id.clone()).unwrap(),
+            project.complete(executor_id.clone()).unwrap(),
+        ];
+
+        let replayed = Project::replay(&events, snapshot);
+        assert_eq!(replayed.status(), &ProjectStatus::Completed);
+        assert_eq!(replayed.seq_nr(), project.seq_nr());
+    }
+
     #[test]
     fn test_to_json() {
         let executor_id = UserId::default();
@@ -407,7 +1812,12 @@ mod tests {
             MemberRole::Admin,
         ));
 
-        let (mut project, _) = Project::new(project_name.clone(), members, executor_id.clone());
+        let (mut project, _) = Project::new(
+            project_name.clone(),
+            Description::default(),
+            members,
+            executor_id.clone(),
+        );
         assert_eq!(project.name, project_name);
 
         let _ = project
@@ -423,4 +1833,103 @@ mod tests {
         println!("{}", json);
         assert!(!json.is_empty());
     }
+
+    #[test]
+    fn test_deserialize_project_without_status() {
+        let executor_id = UserId::new();
+        let (project, _) = Project::new(
+            ProjectName::new("test").unwrap(),
+            Description::default(),
+            Members::new(executor_id.clone()),
+            executor_id,
+        );
+        let mut json = serde_json::to_value(&project).unwrap();
+        json.as_object_mut().unwrap().remove("status");
+
+        let deserialized: Project = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.status(), &ProjectStatus::default());
+    }
+
+    #[test]
+    fn test_deserialize_project_without_description() {
+        let executor_id = UserId::new();
+        let (project, _) = Project::new(
+            ProjectName::new("test").unwrap(),
+            Description::new("説明").unwrap(),
+            Members::new(executor_id.clone()),
+            executor_id,
+        );
+        let mut json = serde_json::to_value(&project).unwrap();
+        json.as_object_mut().unwrap().remove("description");
+
+        let deserialized: Project = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.description(), &Description::default());
+    }
+
+    /// オーナーが導入される前の形式のスナップショットを作成する
+    ///
+    /// 当時はオーナーのロールがなく、プロジェクトの作成者は管理者として保存されていた。
+    fn to_snapshot_without_owner(project: &Project) -> serde_json::Value {
+        let mut json = serde_json::to_value(project).unwrap();
+        json.as_object_mut().unwrap().remove("owner_id");
+        for member in json["members"]["members"].as_object_mut().unwrap().values_mut() {
+            if member["role"] == "Owner" {
+                member["role"] = serde_json::Value::from("Admin");
+            }
+        }
+        json
+    }
+
+    #[test]
+    fn test_deserialize_project_without_owner_id() {
+        let creator_id = UserId::new();
+        let (mut project, _) = Project::new(
+            ProjectName::new("test").unwrap(),
+            Description::default(),
+            Members::new(creator_id.clone()),
+            creator_id.clone(),
+        );
+        project
+            .add_member(
+                MemberId::new(),
+                UserId::new(),
+                MemberRole::Member,
+                creator_id.clone(),
+            )
+            .unwrap();
+
+        let deserialized: Project = serde_json::from_value(to_snapshot_without_owner(&project)).unwrap();
+        assert_eq!(deserialized.owner_id(), &creator_id);
+    }
+
+    #[test]
+    fn test_owner_of_project_without_owner_id_keeps_owner_permissions() {
+        let creator_id = UserId::new();
+        let member_id = UserId::new();
+        let (mut project, _) = Project::new(
+            ProjectName::new("test").unwrap(),
+            Description::default(),
+            Members::new(creator_id.clone()),
+            creator_id.clone(),
+        );
+        project
+            .add_member(
+                MemberId::new(),
+                member_id.clone(),
+                MemberRole::Member,
+                creator_id.clone(),
+            )
+            .unwrap();
+
+        let mut deserialized: Project = serde_json::from_value(to_snapshot_without_owner(&project)).unwrap();
+        assert!(deserialized.members().is_role(&creator_id, &[MemberRole::Owner]));
+        assert!(
+            deserialized
+                .members()
+                .has_permission(&creator_id, &Permission::TransferOwnership)
+        );
+        assert!(deserialized.members().has_permission(&creator_id, &Permission::DeleteProject));
+
+        deserialized.delete(creator_id).unwrap();
+    }
 }
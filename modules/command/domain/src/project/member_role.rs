@@ -6,17 +6,33 @@ use std::str::FromStr;
 /// [Member]のロール
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MemberRole {
+    /// オーナー
+    Owner,
     /// 管理者
     Admin,
     /// メンバー
     Member,
+    /// 閲覧者
+    Viewer,
+    /// ゲスト
+    Guest,
+}
+
+impl MemberRole {
+    /// 管理者権限を持つロールかどうかを判定する
+    pub fn is_administrative(&self) -> bool {
+        matches!(self, Self::Owner | Self::Admin)
+    }
 }
 
 impl Display for MemberRole {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Owner => write!(f, "Owner"),
             Self::Admin => write!(f, "Admin"),
             Self::Member => write!(f, "Member"),
+            Self::Viewer => write!(f, "Viewer"),
+            Self::Guest => write!(f, "Guest"),
         }
     }
 }
@@ -26,8 +42,11 @@ impl FromStr for MemberRole {
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
+            "owner" => Ok(Self::Owner),
             "admin" => Ok(Self::Admin),
             "member" => Ok(Self::Member),
+            "viewer" => Ok(Self::Viewer),
+            "guest" => Ok(Self::Guest),
             _ => Err(ParseError::InvalidMemberRole(s.to_string())),
         }
     }
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::project::{Member, MemberId, MemberRole};
+use crate::project::{Member, MemberId, MemberRole, Permission, PermissionPolicy};
 use crate::user::UserId;
 
 /// メンバー集合
@@ -13,27 +13,41 @@ pub struct Members {
 }
 
 impl Members {
-    pub fn new(administrator_id: UserId) -> Self {
+    pub fn new(owner_id: UserId) -> Self {
         let mut my_self = Self {
             members_ids_by_user_id: BTreeMap::new(),
             members: BTreeMap::new(),
         };
-        my_self.add_member(Member::new(
-            MemberId::new(),
-            administrator_id,
-            MemberRole::Admin,
-        ));
+        my_self.add_member(Member::new(MemberId::new(), owner_id, MemberRole::Owner));
         my_self
     }
 
-    /// 管理者かどうかを判定する
+    /// 管理者 (オーナーを含む) かどうかを判定する
     pub fn is_administrator(&self, user_id: &UserId) -> bool {
-        self.is_role(user_id, &[MemberRole::Admin])
+        self.is_role(user_id, &[MemberRole::Owner, MemberRole::Admin])
     }
 
     /// メンバーかどうかを判定する
     pub fn is_member(&self, user_id: &UserId) -> bool {
-        self.is_role(user_id, &[MemberRole::Member, MemberRole::Admin])
+        self.members_ids_by_user_id.contains_key(&user_id.to_string())
+    }
+
+    /// 唯一の管理者 (オーナーを含む) かどうかを判定する
+    pub fn is_last_administrator(&self, user_id: &UserId) -> bool {
+        self.is_administrator(user_id)
+            && self
+                .members
+                .values()
+                .filter(|member| member.breach_encapsulation_of_role().is_administrative())
+                .count()
+                == 1
+    }
+
+    /// 指定した権限を持つかどうかを [PermissionPolicy] に従って判定する
+    pub fn has_permission(&self, user_id: &UserId, permission: &Permission) -> bool {
+        self.find_by_user_id(user_id)
+            .map(|member| PermissionPolicy::is_permitted(member.breach_encapsulation_of_role(), permission))
+            .unwrap_or(false)
     }
 
     /// ロールを判定する
@@ -47,7 +61,15 @@ impl Members {
     }
 
     /// メンバーを追加する
-    pub fn add_member(&mut self, member: Member) {
+    ///
+    /// ロールの変更には [Members::change_role] を利用する。
+    ///
+    /// # 戻り値
+    /// - 同じユーザ ID のメンバーが既に存在する場合は追加せずに `false` を返す。
+    pub fn add_member(&mut self, member: Member) -> bool {
+        if self.is_member(member.breach_encapsulation_of_user_id()) {
+            return false;
+        }
         self.members.insert(
             member.breach_encapsulation_of_id().to_string(),
             member.clone(),
@@ -56,6 +78,18 @@ impl Members {
             member.breach_encapsulation_of_user_id().to_string(),
             member.breach_encapsulation_of_id().clone(),
         );
+        true
+    }
+
+    /// 指定したユーザ ID のメンバーのロールを変更する
+    ///
+    /// # 戻り値
+    /// - ロールを変更したメンバーを返す。メンバーが存在しない場合は `None` を返す。
+    pub fn change_role(&mut self, user_id: &UserId, role: MemberRole) -> Option<Member> {
+        let member_id = self.members_ids_by_user_id.get(&user_id.to_string())?;
+        let member = self.members.get_mut(&member_id.to_string())?;
+        *member = Member::new(member_id.clone(), user_id.clone(), role);
+        Some(member.clone())
     }
 
     /// 指定したメンバー ID のメンバーを取得する
@@ -92,3 +126,39 @@ impl Members {
         self.members.values().collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member_rejects_duplicate_user() {
+        let owner_id = UserId::new();
+        let user_id = UserId::new();
+        let mut members = Members::new(owner_id.clone());
+
+        assert!(members.add_member(Member::new(
+            MemberId::new(),
+            user_id.clone(),
+            MemberRole::Member
+        )));
+        assert!(!members.add_member(Member::new(
+            MemberId::new(),
+            user_id.clone(),
+            MemberRole::Admin
+        )));
+        assert!(!members.add_member(Member::new(
+            MemberId::new(),
+            owner_id.clone(),
+            MemberRole::Admin
+        )));
+
+        assert_eq!(members.to_vec().len(), 2);
+        assert!(members.is_role(&user_id, &[MemberRole::Member]));
+        assert!(members.is_role(&owner_id, &[MemberRole::Owner]));
+
+        let member = members.change_role(&user_id, MemberRole::Admin).unwrap();
+        assert_eq!(member.breach_encapsulation_of_role(), &MemberRole::Admin);
+        assert_eq!(members.to_vec().len(), 2);
+    }
+}
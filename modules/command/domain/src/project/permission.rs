@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::project::MemberRole;
+
+/// プロジェクトに対する操作の権限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Permission {
+    /// プロジェクトを閲覧する
+    ViewProject,
+    /// プロジェクト名を変更する
+    RenameProject,
+    /// プロジェクトの説明を変更する
+    EditProjectDescription,
+    /// プロジェクトの状態を変更する
+    ChangeProjectStatus,
+    /// メンバーを追加・削除し、ロールを変更する
+    ManageMembers,
+    /// プロジェクトのオーナーを移譲する
+    TransferOwnership,
+    /// プロジェクトを削除する
+    DeleteProject,
+    /// イテレーションを作成・編集する
+    ManageIterations,
+    /// タスクを作成・編集する
+    ManageTasks,
+    /// タスクのワークフローを定義する
+    ManageWorkflow,
+    /// タグカタログを管理する
+    ManageTags,
+    /// タスクにコメントする
+    CommentOnTasks,
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// ロールと権限の対応表
+pub struct PermissionPolicy;
+
+impl PermissionPolicy {
+    /// 指定したロールに許可されている権限の一覧を返す
+    pub fn permissions_of(role: &MemberRole) -> &'static [Permission] {
+        use Permission::*;
+        match role {
+            MemberRole::Owner => &[
+                ViewProject,
+                RenameProject,
+                EditProjectDescription,
+                ChangeProjectStatus,
+                ManageMembers,
+                TransferOwnership,
+                DeleteProject,
+                ManageIterations,
+                ManageTasks,
+                ManageWorkflow,
+                ManageTags,
+                CommentOnTasks,
+            ],
+            MemberRole::Admin => &[
+                ViewProject,
+                RenameProject,
+                EditProjectDescription,
+                ChangeProjectStatus,
+                ManageMembers,
+                ManageIterations,
+                ManageTasks,
+                ManageWorkflow,
+                ManageTags,
+                CommentOnTasks,
+            ],
+            MemberRole::Member => &[ViewProject, ManageTasks, CommentOnTasks],
+            MemberRole::Guest => &[ViewProject, CommentOnTasks],
+            MemberRole::Viewer => &[ViewProject],
+        }
+    }
+
+    /// 指定したロールが権限を持つかどうかを判定する
+    pub fn is_permitted(role: &MemberRole, permission: &Permission) -> bool {
+        Self::permissions_of(role).contains(permission)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_permitted() {
+        assert!(PermissionPolicy::is_permitted(
+            &MemberRole::Owner,
+            &Permission::DeleteProject
+        ));
+        assert!(!PermissionPolicy::is_permitted(
+            &MemberRole::Admin,
+            &Permission::DeleteProject
+        ));
+        assert!(PermissionPolicy::is_permitted(
+            &MemberRole::Admin,
+            &Permission::ManageMembers
+        ));
+        assert!(!PermissionPolicy::is_permitted(
+            &MemberRole::Member,
+            &Permission::ManageMembers
+        ));
+        assert!(PermissionPolicy::is_permitted(
+            &MemberRole::Member,
+            &Permission::ManageTasks
+        ));
+        assert!(!PermissionPolicy::is_permitted(
+            &MemberRole::Guest,
+            &Permission::ManageTasks
+        ));
+        assert!(!PermissionPolicy::is_permitted(
+            &MemberRole::Member,
+            &Permission::ManageTags
+        ));
+        assert!(PermissionPolicy::is_permitted(
+            &MemberRole::Guest,
+            &Permission::CommentOnTasks
+        ));
+        assert!(!PermissionPolicy::is_permitted(
+            &MemberRole::Viewer,
+            &Permission::CommentOnTasks
+        ));
+        assert!(PermissionPolicy::is_permitted(
+            &MemberRole::Viewer,
+            &Permission::ViewProject
+        ));
+    }
+}
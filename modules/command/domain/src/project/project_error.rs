@@ -1,4 +1,7 @@
-use crate::project::{ProjectId, ProjectName};
+use chrono::{DateTime, Utc};
+
+use crate::project::{Permission, ProjectId, ProjectName, ProjectStatus, TagId, TagName, WorkflowError};
+use crate::project_invitation::ProjectInvitationId;
 use crate::user::UserId;
 use thiserror::Error;
 
@@ -7,12 +10,36 @@ pub enum ProjectError {
     #[error("The project is deleted: {0:?}")]
     AlreadyDeletedError(ProjectId),
 
+    #[error("The project is not deleted: {0:?}")]
+    NotDeletedError(ProjectId),
+
+    #[error("The grace period for restoring the project has expired at {1}: {0:?}")]
+    RestoreGracePeriodExpiredError(ProjectId, DateTime<Utc>),
+
     #[error("The {0} is not an administrator of the project: {1:?}")]
     NotAdministratorError(String, UserId),
 
+    #[error("The {0} does not have the {1} permission on the project: {2:?}")]
+    PermissionDeniedError(String, Permission, UserId),
+
+    #[error("The owner role can only be changed by transferring ownership: {0:?}")]
+    OwnerRoleChangeError(UserId),
+
+    #[error("The {0} is already the owner of the project: {1:?}")]
+    AlreadyOwnerError(String, UserId),
+
+    #[error("The {0} is the owner of the project and cannot be removed: {1:?}")]
+    OwnerCannotBeRemovedError(String, UserId),
+
+    #[error("The {0} is an administrator of the project and cannot leave: {1:?}")]
+    AdministratorCannotLeaveError(String, UserId),
+
     #[error("The {0} is not a member of the project: {1:?}")]
     NotMemberError(String, UserId),
 
+    #[error("The {0} is the last administrator of the project and cannot be removed or demoted: {1:?}")]
+    LastAdministratorError(String, UserId),
+
     #[error("The {0} is already a member of the project: {1:?}")]
     AlreadyMemberError(String, UserId),
 
@@ -21,4 +48,22 @@ pub enum ProjectError {
 
     #[error("The project name is already exists: {0:?}, {1:?}")]
     AlreadyExistsNameError(ProjectId, ProjectName),
+
+    #[error("The project status cannot be changed from {0} to {1}")]
+    InvalidStatusTransitionError(ProjectStatus, ProjectStatus),
+
+    #[error("The tag name already exists in the project: {0}")]
+    DuplicateTagNameError(TagName),
+
+    #[error("The tag is not found in the project: {0}")]
+    TagNotFoundError(TagId),
+
+    #[error("The invitation is not for the project: {0:?}, {1:?}")]
+    MismatchedInvitationError(ProjectInvitationId, ProjectId),
+
+    #[error("The invitation is not accepted: {0:?}")]
+    InvitationNotAcceptedError(ProjectInvitationId),
+
+    #[error("The workflow cannot be applied to the tasks: {0}")]
+    IncompatibleWorkflowError(WorkflowError),
 }
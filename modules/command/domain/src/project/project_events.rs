@@ -3,11 +3,18 @@ use event_store_adapter_rs::types::Event;
 use serde::{Deserialize, Serialize};
 use ulid_generator_rs::ULID;
 
+use crate::description::Description;
 use crate::helper::id_generate;
 use crate::project::Member;
+use crate::project::MemberId;
+use crate::project::MemberRole;
 use crate::project::Members;
 use crate::project::ProjectId;
 use crate::project::ProjectName;
+use crate::project::Tag;
+use crate::project::TagId;
+use crate::project::TagName;
+use crate::project::Workflow;
 use crate::user::UserId;
 
 pub type ProjectEventId = ULID;
@@ -20,12 +27,40 @@ pub enum ProjectEvent {
     ProjectCreated(ProjectEventCreatedBody),
     /// プロジェクトが削除された
     ProjectDeleted(ProjectEventDeletedBody),
+    /// 削除されたプロジェクトが復元された
+    ProjectRestored(ProjectEventRestoredBody),
     /// プロジェクトにメンバーが追加された
     ProjectMemberAdded(ProjectEventMemberAddedBody),
     /// プロジェクトのメンバーが削除された
     ProjectMemberRemoved(ProjectEventMemberRemovedBody),
+    /// プロジェクトからメンバーが自ら脱退した
+    ProjectMemberLeft(ProjectEventMemberLeftBody),
+    /// プロジェクトのメンバーのロールが変更された
+    ProjectMemberRoleChanged(ProjectEventMemberRoleChangedBody),
     /// プロジェクト名が変更された
     ProjectRenamed(ProjectEventRenamedBody),
+    /// プロジェクトのオーナーが移譲された
+    ProjectOwnershipTransferred(ProjectEventOwnershipTransferredBody),
+    /// プロジェクトが開始された
+    ProjectStarted(ProjectEventStartedBody),
+    /// プロジェクトが完了した
+    ProjectCompleted(ProjectEventCompletedBody),
+    /// プロジェクトがアーカイブされた
+    ProjectArchived(ProjectEventArchivedBody),
+    /// プロジェクトが再開された
+    ProjectReopened(ProjectEventReopenedBody),
+    /// プロジェクトの説明が変更された
+    ProjectDescriptionChanged(ProjectEventDescriptionChangedBody),
+    /// タスクのワークフローが定義された
+    WorkflowDefined(ProjectEventWorkflowDefinedBody),
+    /// タスクのワークフローが変更された
+    WorkflowChanged(ProjectEventWorkflowChangedBody),
+    /// タグカタログにタグが追加された
+    TagCreated(ProjectEventTagCreatedBody),
+    /// タグ名が変更された
+    TagRenamed(ProjectEventTagRenamedBody),
+    /// タグカタログからタグが削除された
+    TagDeleted(ProjectEventTagDeletedBody),
 }
 
 impl Event for ProjectEvent {
@@ -36,9 +71,23 @@ impl Event for ProjectEvent {
         match self {
             ProjectEvent::ProjectCreated(event) => &event.id,
             ProjectEvent::ProjectDeleted(event) => &event.id,
+            ProjectEvent::ProjectRestored(event) => &event.id,
             ProjectEvent::ProjectMemberAdded(event) => &event.id,
             ProjectEvent::ProjectMemberRemoved(event) => &event.id,
+            ProjectEvent::ProjectMemberLeft(event) => &event.id,
+            ProjectEvent::ProjectMemberRoleChanged(event) => &event.id,
             ProjectEvent::ProjectRenamed(event) => &event.id,
+            ProjectEvent::ProjectOwnershipTransferred(event) => &event.id,
+            ProjectEvent::ProjectStarted(event) => &event.id,
+            ProjectEvent::ProjectCompleted(event) => &event.id,
+            ProjectEvent::ProjectArchived(event) => &event.id,
+            ProjectEvent::ProjectReopened(event) => &event.id,
+            ProjectEvent::ProjectDescriptionChanged(event) => &event.id,
+            ProjectEvent::WorkflowDefined(event) => &event.id,
+            ProjectEvent::WorkflowChanged(event) => &event.id,
+            ProjectEvent::TagCreated(event) => &event.id,
+            ProjectEvent::TagRenamed(event) => &event.id,
+            ProjectEvent::TagDeleted(event) => &event.id,
         }
     }
 
@@ -46,9 +95,23 @@ impl Event for ProjectEvent {
         match self {
             ProjectEvent::ProjectCreated(event) => event.seq_nr,
             ProjectEvent::ProjectDeleted(event) => event.seq_nr,
+            ProjectEvent::ProjectRestored(event) => event.seq_nr,
             ProjectEvent::ProjectMemberAdded(event) => event.seq_nr,
             ProjectEvent::ProjectMemberRemoved(event) => event.seq_nr,
+            ProjectEvent::ProjectMemberLeft(event) => event.seq_nr,
+            ProjectEvent::ProjectMemberRoleChanged(event) => event.seq_nr,
             ProjectEvent::ProjectRenamed(event) => event.seq_nr,
+            ProjectEvent::ProjectOwnershipTransferred(event) => event.seq_nr,
+            ProjectEvent::ProjectStarted(event) => event.seq_nr,
+            ProjectEvent::ProjectCompleted(event) => event.seq_nr,
+            ProjectEvent::ProjectArchived(event) => event.seq_nr,
+            ProjectEvent::ProjectReopened(event) => event.seq_nr,
+            ProjectEvent::ProjectDescriptionChanged(event) => event.seq_nr,
+            ProjectEvent::WorkflowDefined(event) => event.seq_nr,
+            ProjectEvent::WorkflowChanged(event) => event.seq_nr,
+            ProjectEvent::TagCreated(event) => event.seq_nr,
+            ProjectEvent::TagRenamed(event) => event.seq_nr,
+            ProjectEvent::TagDeleted(event) => event.seq_nr,
         }
     }
 
@@ -56,9 +119,23 @@ impl Event for ProjectEvent {
         match self {
             ProjectEvent::ProjectCreated(event) => &event.aggregate_id,
             ProjectEvent::ProjectDeleted(event) => &event.aggregate_id,
+            ProjectEvent::ProjectRestored(event) => &event.aggregate_id,
             ProjectEvent::ProjectMemberAdded(event) => &event.aggregate_id,
             ProjectEvent::ProjectMemberRemoved(event) => &event.aggregate_id,
+            ProjectEvent::ProjectMemberLeft(event) => &event.aggregate_id,
+            ProjectEvent::ProjectMemberRoleChanged(event) => &event.aggregate_id,
             ProjectEvent::ProjectRenamed(event) => &event.aggregate_id,
+            ProjectEvent::ProjectOwnershipTransferred(event) => &event.aggregate_id,
+            ProjectEvent::ProjectStarted(event) => &event.aggregate_id,
+            ProjectEvent::ProjectCompleted(event) => &event.aggregate_id,
+            ProjectEvent::ProjectArchived(event) => &event.aggregate_id,
+            ProjectEvent::ProjectReopened(event) => &event.aggregate_id,
+            ProjectEvent::ProjectDescriptionChanged(event) => &event.aggregate_id,
+            ProjectEvent::WorkflowDefined(event) => &event.aggregate_id,
+            ProjectEvent::WorkflowChanged(event) => &event.aggregate_id,
+            ProjectEvent::TagCreated(event) => &event.aggregate_id,
+            ProjectEvent::TagRenamed(event) => &event.aggregate_id,
+            ProjectEvent::TagDeleted(event) => &event.aggregate_id,
         }
     }
 
@@ -66,9 +143,23 @@ impl Event for ProjectEvent {
         match self {
             ProjectEvent::ProjectCreated(event) => &event.occurred_at,
             ProjectEvent::ProjectDeleted(event) => &event.occurred_at,
+            ProjectEvent::ProjectRestored(event) => &event.occurred_at,
             ProjectEvent::ProjectMemberAdded(event) => &event.occurred_at,
             ProjectEvent::ProjectMemberRemoved(event) => &event.occurred_at,
+            ProjectEvent::ProjectMemberLeft(event) => &event.occurred_at,
+            ProjectEvent::ProjectMemberRoleChanged(event) => &event.occurred_at,
             ProjectEvent::ProjectRenamed(event) => &event.occurred_at,
+            ProjectEvent::ProjectOwnershipTransferred(event) => &event.occurred_at,
+            ProjectEvent::ProjectStarted(event) => &event.occurred_at,
+            ProjectEvent::ProjectCompleted(event) => &event.occurred_at,
+            ProjectEvent::ProjectArchived(event) => &event.occurred_at,
+            ProjectEvent::ProjectReopened(event) => &event.occurred_at,
+            ProjectEvent::ProjectDescriptionChanged(event) => &event.occurred_at,
+            ProjectEvent::WorkflowDefined(event) => &event.occurred_at,
+            ProjectEvent::WorkflowChanged(event) => &event.occurred_at,
+            ProjectEvent::TagCreated(event) => &event.occurred_at,
+            ProjectEvent::TagRenamed(event) => &event.occurred_at,
+            ProjectEvent::TagDeleted(event) => &event.occurred_at,
         }
     }
 
@@ -83,6 +174,8 @@ pub struct ProjectEventCreatedBody {
     pub aggregate_id: ProjectId,
     pub seq_nr: usize,
     pub name: ProjectName,
+    #[serde(default)]
+    pub description: Description,
     pub members: Members,
     pub executor_id: UserId,
     pub occurred_at: DateTime<Utc>,
@@ -93,6 +186,7 @@ impl ProjectEventCreatedBody {
         aggregate_id: ProjectId,
         seq_nr: usize,
         name: ProjectName,
+        description: Description,
         members: Members,
         executor_id: UserId,
         occurred_at: DateTime<Utc>,
@@ -103,6 +197,7 @@ impl ProjectEventCreatedBody {
             aggregate_id,
             seq_nr,
             name,
+            description,
             members,
             executor_id,
             occurred_at,
@@ -132,6 +227,28 @@ impl ProjectEventDeletedBody {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventRestoredBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventRestoredBody {
+    pub fn new(aggregate_id: ProjectId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectEventMemberAddedBody {
     pub id: ProjectEventId,
@@ -192,6 +309,64 @@ impl ProjectEventMemberRemovedBody {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventMemberLeftBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventMemberLeftBody {
+    pub fn new(aggregate_id: ProjectId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventMemberRoleChangedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub member_id: MemberId,
+    pub user_id: UserId,
+    pub new_role: MemberRole,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventMemberRoleChangedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        member_id: MemberId,
+        user_id: UserId,
+        new_role: MemberRole,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            member_id,
+            user_id,
+            new_role,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectEventRenamedBody {
     pub id: ProjectEventId,
@@ -222,8 +397,310 @@ impl ProjectEventRenamedBody {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventOwnershipTransferredBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub new_owner_id: UserId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventOwnershipTransferredBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        new_owner_id: UserId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_owner_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventStartedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventStartedBody {
+    pub fn new(aggregate_id: ProjectId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventCompletedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventCompletedBody {
+    pub fn new(aggregate_id: ProjectId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventArchivedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventArchivedBody {
+    pub fn new(aggregate_id: ProjectId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventReopenedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventReopenedBody {
+    pub fn new(aggregate_id: ProjectId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventDescriptionChangedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub new_description: Description,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventDescriptionChangedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        new_description: Description,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_description,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventWorkflowDefinedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub workflow: Workflow,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventWorkflowDefinedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        workflow: Workflow,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            workflow,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventWorkflowChangedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub workflow: Workflow,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventWorkflowChangedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        workflow: Workflow,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            workflow,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventTagCreatedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub tag: Tag,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventTagCreatedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        tag: Tag,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            tag,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventTagRenamedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub tag_id: TagId,
+    pub new_name: TagName,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventTagRenamedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        tag_id: TagId,
+        new_name: TagName,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            tag_id,
+            new_name,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectEventTagDeletedBody {
+    pub id: ProjectEventId,
+    pub aggregate_id: ProjectId,
+    pub seq_nr: usize,
+    pub tag_id: TagId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectEventTagDeletedBody {
+    pub fn new(
+        aggregate_id: ProjectId,
+        seq_nr: usize,
+        tag_id: TagId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            tag_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::description::Description;
     use crate::project::project_events::{ProjectEvent, ProjectEventCreatedBody};
     use crate::project::{Members, ProjectEventDeletedBody, ProjectId, ProjectName};
     use crate::user::UserId;
@@ -237,6 +714,7 @@ mod tests {
             ProjectId::default(),
             1usize,
             ProjectName::new("test").unwrap(),
+            Description::default(),
             Members::new(executer_id.clone()),
             executer_id,
             Utc::now(),
@@ -253,6 +731,7 @@ mod tests {
             ProjectId::default(),
             1usize,
             ProjectName::new("test").unwrap(),
+            Description::default(),
             Members::new(executer_id.clone()),
             executer_id,
             Utc::now(),
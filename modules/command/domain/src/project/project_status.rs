@@ -0,0 +1,66 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::helper::ParseError;
+
+/// プロジェクトの状態
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectStatus {
+    /// 計画中
+    #[default]
+    Planning,
+    /// 進行中
+    InProgress,
+    /// 完了
+    Completed,
+    /// アーカイブ済み
+    Archived,
+}
+
+impl ProjectStatus {
+    /// 指定した状態へ遷移できるかどうかを判定する
+    ///
+    /// - Planning -> InProgress, Archived
+    /// - InProgress -> Completed, Archived
+    /// - Completed -> InProgress, Archived
+    /// - Archived -> InProgress
+    pub fn can_transition_to(&self, next: &ProjectStatus) -> bool {
+        matches!(
+            (self, next),
+            (Self::Planning, Self::InProgress)
+                | (Self::Planning, Self::Archived)
+                | (Self::InProgress, Self::Completed)
+                | (Self::InProgress, Self::Archived)
+                | (Self::Completed, Self::InProgress)
+                | (Self::Completed, Self::Archived)
+                | (Self::Archived, Self::InProgress)
+        )
+    }
+}
+
+impl Display for ProjectStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Planning => write!(f, "Planning"),
+            Self::InProgress => write!(f, "InProgress"),
+            Self::Completed => write!(f, "Completed"),
+            Self::Archived => write!(f, "Archived"),
+        }
+    }
+}
+
+impl FromStr for ProjectStatus {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "planning" => Ok(Self::Planning),
+            "inprogress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            "archived" => Ok(Self::Archived),
+            _ => Err(ParseError::InvalidProjectStatus(s.to_string())),
+        }
+    }
+}
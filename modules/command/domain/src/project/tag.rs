@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::project::tag_id::TagId;
+use crate::project::tag_name::TagName;
+
+/// タグ
+///
+/// プロジェクトのタグカタログに登録され、タスクからは [TagId] で参照される。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    id: TagId,
+    name: TagName,
+}
+
+impl Tag {
+    pub fn new(id: TagId, name: TagName) -> Self {
+        Self { id, name }
+    }
+
+    /// [TagId]の参照を返す
+    pub fn id(&self) -> &TagId {
+        &self.id
+    }
+
+    /// [TagName]の参照を返す
+    pub fn name(&self) -> &TagName {
+        &self.name
+    }
+
+    pub(crate) fn rename(&mut self, name: TagName) {
+        self.name = name;
+    }
+}
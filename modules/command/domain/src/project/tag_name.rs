@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagName(String);
+
+#[derive(Error, Debug, Clone)]
+pub enum TagNameError {
+    #[error("the tag name is empty")]
+    Empty,
+    #[error("the tag name is too long")]
+    TooLong,
+}
+
+impl TagName {
+    pub fn new(name: &str) -> Result<Self, TagNameError> {
+        if name.is_empty() {
+            Err(TagNameError::Empty)
+        } else if name.len() > 50 {
+            Err(TagNameError::TooLong)
+        } else {
+            Ok(Self(name.to_string()))
+        }
+    }
+}
+
+impl FromStr for TagName {
+    type Err = TagNameError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for TagName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::task::TaskStatus;
+
+/// ワークフロー上の状態の遷移
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowTransition {
+    pub from: TaskStatus,
+    pub to: TaskStatus,
+}
+
+impl WorkflowTransition {
+    pub fn new(from: TaskStatus, to: TaskStatus) -> Self {
+        Self { from, to }
+    }
+}
+
+/// タスクの状態遷移を定めるワークフロー
+///
+/// プロジェクトごとに利用する状態の集合と、状態間で許可する遷移を持つ。
+/// タスクの初期状態である [TaskStatus::Todo] は必ず含む。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workflow {
+    statuses: Vec<TaskStatus>,
+    transitions: Vec<WorkflowTransition>,
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum WorkflowError {
+    #[error("the workflow does not contain the initial status: {0}")]
+    MissingInitialStatus(TaskStatus),
+    #[error("the transition refers to the status not in the workflow: {0}")]
+    UnknownStatus(TaskStatus),
+    #[error("the transition to the same status is not allowed: {0}")]
+    SelfTransition(TaskStatus),
+    #[error("the status used by tasks is not in the workflow: {0}")]
+    StatusInUseRemoved(TaskStatus),
+    #[error("the status used by tasks has no transition: {0}")]
+    StatusInUseStranded(TaskStatus),
+}
+
+impl Workflow {
+    /// ワークフローを生成する
+    ///
+    /// 重複した状態や遷移は1つにまとめる。
+    pub fn new(statuses: Vec<TaskStatus>, transitions: Vec<WorkflowTransition>) -> Result<Self, WorkflowError> {
+        let mut unique_statuses = Vec::with_capacity(statuses.len());
+        for status in statuses {
+            if !unique_statuses.contains(&status) {
+                unique_statuses.push(status);
+            }
+        }
+        if !unique_statuses.contains(&TaskStatus::default()) {
+            return Err(WorkflowError::MissingInitialStatus(TaskStatus::default()));
+        }
+
+        let mut unique_transitions = Vec::with_capacity(transitions.len());
+        for transition in transitions {
+            if transition.from == transition.to {
+                return Err(WorkflowError::SelfTransition(transition.from));
+            }
+            if let Some(status) = [transition.from, transition.to]
+                .into_iter()
+                .find(|status| !unique_statuses.contains(status))
+            {
+                return Err(WorkflowError::UnknownStatus(status));
+            }
+            if !unique_transitions.contains(&transition) {
+                unique_transitions.push(transition);
+            }
+        }
+        Ok(Self {
+            statuses: unique_statuses,
+            transitions: unique_transitions,
+        })
+    }
+
+    /// ワークフローで利用する状態を返す
+    pub fn statuses(&self) -> &[TaskStatus] {
+        &self.statuses
+    }
+
+    /// 許可されている遷移を返す
+    pub fn transitions(&self) -> &[WorkflowTransition] {
+        &self.transitions
+    }
+
+    /// 指定した状態から遷移できる状態を返す
+    pub fn allowed_targets(&self, from: &TaskStatus) -> Vec<TaskStatus> {
+        self.transitions
+            .iter()
+            .filter(|transition| &transition.from == from)
+            .map(|transition| transition.to)
+            .collect()
+    }
+
+    /// タスクが利用している状態から遷移できなくならないかを検証する
+    ///
+    /// 利用している状態はワークフローに含まれていなければならず、完了以外の状態は遷移先を持たなければならない。
+    pub fn ensure_accommodates(&self, statuses_in_use: &[TaskStatus]) -> Result<(), WorkflowError> {
+        for status in statuses_in_use {
+            if !self.statuses.contains(status) {
+                return Err(WorkflowError::StatusInUseRemoved(*status));
+            }
+            if !status.is_done() && self.allowed_targets(status).is_empty() {
+                return Err(WorkflowError::StatusInUseStranded(*status));
+            }
+        }
+        Ok(())
+    }
+
+    /// 指定した遷移が許可されているかどうかを判定する
+    pub fn can_transition(&self, from: &TaskStatus, to: &TaskStatus) -> bool {
+        self.transitions
+            .iter()
+            .any(|transition| &transition.from == from && &transition.to == to)
+    }
+}
+
+impl Default for Workflow {
+    /// Todo -> InProgress -> InReview -> Done の順に進み、Done から Todo に戻せるワークフロー
+    fn default() -> Self {
+        use TaskStatus::*;
+        Self {
+            statuses: vec![Todo, InProgress, InReview, Done],
+            transitions: vec![
+                WorkflowTransition::new(Todo, InProgress),
+                WorkflowTransition::new(InProgress, InReview),
+                WorkflowTransition::new(InReview, Done),
+                WorkflowTransition::new(Done, Todo),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_workflow() {
+        use TaskStatus::*;
+        assert!(matches!(
+            Workflow::new(vec![InProgress, Done], vec![]),
+            Err(WorkflowError::MissingInitialStatus(Todo))
+        ));
+        assert!(matches!(
+            Workflow::new(
+                vec![Todo, Done],
+                vec![WorkflowTransition::new(Todo, InReview)]
+            ),
+            Err(WorkflowError::UnknownStatus(InReview))
+        ));
+        assert!(matches!(
+            Workflow::new(vec![Todo], vec![WorkflowTransition::new(Todo, Todo)]),
+            Err(WorkflowError::SelfTransition(Todo))
+        ));
+
+        let workflow = Workflow::new(
+            vec![Todo, Done, Todo],
+            vec![WorkflowTransition::new(Todo, Done), WorkflowTransition::new(Todo, Done)],
+        )
+        .unwrap();
+        assert_eq!(workflow.statuses(), &[Todo, Done]);
+        assert_eq!(workflow.allowed_targets(&Todo), vec![Done]);
+        assert!(workflow.allowed_targets(&Done).is_empty());
+    }
+
+    #[test]
+    fn test_default_workflow() {
+        use TaskStatus::*;
+        let workflow = Workflow::default();
+        assert!(workflow.can_transition(&Todo, &InProgress));
+        assert!(workflow.can_transition(&Done, &Todo));
+        assert!(!workflow.can_transition(&Todo, &Done));
+        assert_eq!(workflow.allowed_targets(&InReview), vec![Done]);
+    }
+
+    #[test]
+    fn test_ensure_accommodates() {
+        use TaskStatus::*;
+        let workflow = Workflow::new(
+            vec![Todo, InProgress, Done],
+            vec![WorkflowTransition::new(Todo, InProgress), WorkflowTransition::new(Todo, Done)],
+        )
+        .unwrap();
+        assert!(workflow.ensure_accommodates(&[Todo, Done]).is_ok());
+        assert!(matches!(
+            workflow.ensure_accommodates(&[Todo, InReview]),
+            Err(WorkflowError::StatusInUseRemoved(InReview))
+        ));
+        assert!(matches!(
+            workflow.ensure_accommodates(&[InProgress]),
+            Err(WorkflowError::StatusInUseStranded(InProgress))
+        ));
+    }
+}
@@ -0,0 +1,533 @@
+use chrono::{DateTime, Duration, Utc};
+use event_store_adapter_rs::types::Aggregate;
+use serde::{Deserialize, Serialize};
+
+mod project_invitation_error;
+mod project_invitation_events;
+mod project_invitation_id;
+mod project_invitation_status;
+
+use crate::email::Email;
+use crate::project::{MemberRole, Permission, Project, ProjectId};
+pub use crate::project_invitation::project_invitation_error::ProjectInvitationError;
+pub use crate::project_invitation::project_invitation_events::{
+    ProjectInvitationEvent, ProjectInvitationEventAcceptedBody, ProjectInvitationEventDeclinedBody,
+    ProjectInvitationEventExpiredBody, ProjectInvitationEventSentBody,
+};
+pub use crate::project_invitation::project_invitation_id::ProjectInvitationId;
+pub use crate::project_invitation::project_invitation_status::ProjectInvitationStatus;
+use crate::user::{User, UserId};
+
+/// 招待の有効期間 (日)
+const INVITATION_VALIDITY_DAYS: i64 = 7;
+
+/// メールアドレス宛てのプロジェクトへの招待
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInvitation {
+    id: ProjectInvitationId,
+    project_id: ProjectId,
+    email: Email,
+    role: MemberRole,
+    inviter_id: UserId,
+    status: ProjectInvitationStatus,
+    expires_at: DateTime<Utc>,
+    version: usize,
+    seq_nr_counter: usize,
+    last_updated_at: DateTime<Utc>,
+}
+
+impl PartialEq for ProjectInvitation {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Aggregate for ProjectInvitation {
+    type ID = ProjectInvitationId;
+
+    fn id(&self) -> &Self::ID {
+        &self.id
+    }
+
+    fn seq_nr(&self) -> usize {
+        self.seq_nr_counter
+    }
+
+    fn version(&self) -> usize {
+        self.version
+    }
+
+    fn set_version(&mut self, version: usize) {
+        self.version = version;
+    }
+
+    fn last_updated_at(&self) -> &DateTime<Utc> {
+        &self.last_updated_at
+    }
+}
+
+impl ProjectInvitation {
+    /// プロジェクトへの招待を送信する
+    ///
+    /// # 引数
+    /// - project: 招待先のプロジェクト
+    /// - email: 招待するユーザのメールアドレス
+    /// - role: 招待を承諾したときに付与するロール
+    /// - executor_id: 実行者のユーザID
+    /// - now: 現在日時
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageMembers] を持たない場合はエラーを返す。
+    /// - オーナーのロールを指定した場合はエラーを返す。
+    /// - 成功した場合は、InvitationSentイベントを返す。
+    pub fn new(
+        project: &Project,
+        email: Email,
+        role: MemberRole,
+        executor_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(Self, ProjectInvitationEvent), ProjectInvitationError> {
+        if project.is_deleted() {
+            return Err(ProjectInvitationError::ProjectAlreadyDeletedError(
+                project.id().clone(),
+            ));
+        }
+        if !project.members().has_permission(&executor_id, &Permission::ManageMembers) {
+            return Err(ProjectInvitationError::PermissionDeniedError(
+                "executor_id".to_string(),
+                Permission::ManageMembers,
+                executor_id,
+            ));
+        }
+        if role == MemberRole::Owner {
+            return Err(ProjectInvitationError::OwnerRoleInvitationError);
+        }
+
+        let id = ProjectInvitationId::new();
+        let expires_at = now + Duration::days(INVITATION_VALIDITY_DAYS);
+        let my_self = Self {
+            id: id.clone(),
+            project_id: project.id().clone(),
+            email: email.clone(),
+            role: role.clone(),
+            inviter_id: executor_id.clone(),
+            status: ProjectInvitationStatus::Pending,
+            expires_at,
+            version: 1,
+            seq_nr_counter: 1,
+            last_updated_at: now,
+        };
+        let event = ProjectInvitationEvent::InvitationSent(ProjectInvitationEventSentBody::new(
+            id,
+            my_self.seq_nr_counter,
+            my_self.project_id.clone(),
+            email,
+            role,
+            expires_at,
+            executor_id,
+            now,
+        ));
+        Ok((my_self, event))
+    }
+
+    fn apply_event(&mut self, event: &ProjectInvitationEvent) {
+        match event {
+            ProjectInvitationEvent::InvitationAccepted(body) => {
+                self.accept_by(body.executor_id.clone(), body.occurred_at).unwrap();
+            },
+            ProjectInvitationEvent::InvitationDeclined(body) => {
+                self.decline_by(body.executor_id.clone(), body.occurred_at).unwrap();
+            },
+            ProjectInvitationEvent::InvitationExpired(body) => {
+                self.expire(body.occurred_at).unwrap();
+            },
+            _ => {},
+        }
+    }
+
+    pub fn replay(events: &[ProjectInvitationEvent], snapshot: ProjectInvitation) -> Self {
+        log::debug!("event.size = {}", events.len());
+        events.iter().fold(snapshot, |mut result, event| {
+            log::debug!("Replaying snapshot: {:?}", result);
+            log::debug!("Replaying event: {:?}", event);
+            result.apply_event(event);
+            result
+        })
+    }
+
+    /// 招待先の[ProjectId]の参照を返す
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    /// 招待先の[Email]の参照を返す
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+
+    /// 承諾時に付与する[MemberRole]の参照を返す
+    pub fn role(&self) -> &MemberRole {
+        &self.role
+    }
+
+    /// 招待を送信したユーザIDの参照を返す
+    pub fn inviter_id(&self) -> &UserId {
+        &self.inviter_id
+    }
+
+    /// [ProjectInvitationStatus]の参照を返す
+    pub fn status(&self) -> &ProjectInvitationStatus {
+        &self.status
+    }
+
+    /// 招待の有効期限の参照を返す
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    /// 指定した日時の時点で有効期限が切れているかどうかを判定する
+    pub fn is_expired_at(&self, now: &DateTime<Utc>) -> bool {
+        &self.expires_at <= now
+    }
+
+    /// 招待を承諾する
+    ///
+    /// # 引数
+    /// - executor: 承諾するユーザ
+    /// - now: 現在日時
+    ///
+    /// # 戻り値
+    /// - 承諾するユーザのメールアドレスが招待されたメールアドレスと一致しない場合はエラーを返す。
+    /// - 回答待ちでない場合はエラーを返す。
+    /// - 有効期限が切れている場合はエラーを返す。
+    /// - 成功した場合は、InvitationAcceptedイベントを返す。
+    pub fn accept(
+        &mut self,
+        executor: &User,
+        now: DateTime<Utc>,
+    ) -> Result<ProjectInvitationEvent, ProjectInvitationError> {
+        self.ensure_invitee(executor)?;
+        self.accept_by(executor.id().clone(), now)
+    }
+
+    fn accept_by(
+        &mut self,
+        executor_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<ProjectInvitationEvent, ProjectInvitationError> {
+        self.ensure_answerable(&now)?;
+
+        self.status = ProjectInvitationStatus::Accepted;
+        self.seq_nr_counter += 1;
+        Ok(ProjectInvitationEvent::InvitationAccepted(
+            ProjectInvitationEventAcceptedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                self.project_id.clone(),
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// 招待を辞退する
+    ///
+    /// # 引数
+    /// - executor: 辞退するユーザ
+    /// - now: 現在日時
+    ///
+    /// # 戻り値
+    /// - 辞退するユーザのメールアドレスが招待されたメールアドレスと一致しない場合はエラーを返す。
+    /// - 回答待ちでない場合はエラーを返す。
+    /// - 有効期限が切れている場合はエラーを返す。
+    /// - 成功した場合は、InvitationDeclinedイベントを返す。
+    pub fn decline(
+        &mut self,
+        executor: &User,
+        now: DateTime<Utc>,
+    ) -> Result<ProjectInvitationEvent, ProjectInvitationError> {
+        self.ensure_invitee(executor)?;
+        self.decline_by(executor.id().clone(), now)
+    }
+
+    fn decline_by(
+        &mut self,
+        executor_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<ProjectInvitationEvent, ProjectInvitationError> {
+        self.ensure_answerable(&now)?;
+
+        self.status = ProjectInvitationStatus::Declined;
+        self.seq_nr_counter += 1;
+        Ok(ProjectInvitationEvent::InvitationDeclined(
+            ProjectInvitationEventDeclinedBody::new(self.id.clone(), self.seq_nr_counter, executor_id, now),
+        ))
+    }
+
+    /// 有効期限が切れた招待を期限切れにする
+    ///
+    /// # 引数
+    /// - now: 現在日時
+    ///
+    /// # 戻り値
+    /// - 回答待ちでない場合はエラーを返す。
+    /// - 有効期限が切れていない場合はエラーを返す。
+    /// - 成功した場合は、InvitationExpiredイベントを返す。
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Result<ProjectInvitationEvent, ProjectInvitationError> {
+        if self.status != ProjectInvitationStatus::Pending {
+            return Err(ProjectInvitationError::NotPendingError(
+                self.id.clone(),
+                self.status,
+            ));
+        }
+        if !self.is_expired_at(&now) {
+            return Err(ProjectInvitationError::NotExpiredError(self.id.clone()));
+        }
+
+        self.status = ProjectInvitationStatus::Expired;
+        self.seq_nr_counter += 1;
+        Ok(ProjectInvitationEvent::InvitationExpired(
+            ProjectInvitationEventExpiredBody::new(self.id.clone(), self.seq_nr_counter, now),
+        ))
+    }
+
+    /// 招待されたメールアドレスを持つユーザかどうかを検証する
+    ///
+    /// メールアドレスは大文字と小文字を区別せずに比較する。
+    fn ensure_invitee(&self, user: &User) -> Result<(), ProjectInvitationError> {
+        if user.email().normalized() != self.email.normalized() {
+            return Err(ProjectInvitationError::NotInviteeError(
+                self.id.clone(),
+                user.id().clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 招待に回答できる状態かどうかを検証する
+    fn ensure_answerable(&self, now: &DateTime<Utc>) -> Result<(), ProjectInvitationError> {
+        if self.status != ProjectInvitationStatus::Pending {
+            return Err(ProjectInvitationError::NotPendingError(
+                self.id.clone(),
+                self.status,
+            ));
+        }
+        if self.is_expired_at(now) {
+            return Err(ProjectInvitationError::ExpiredError(self.id.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::description::Description;
+    use crate::project::{MemberId, Members, ProjectError, ProjectName};
+    use crate::user::UserName;
+
+    fn create_project(owner_id: &UserId) -> Project {
+        let (project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        project
+    }
+
+    fn create_user(email: &str) -> User {
+        let (user, _) = User::new(
+            UserName::new("invitee").unwrap(),
+            Email::new(email).unwrap(),
+        );
+        user
+    }
+
+    #[test]
+    fn test_send_invitation() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let email = Email::new("invitee@example.com").unwrap();
+
+        let result = ProjectInvitation::new(
+            &project,
+            email.clone(),
+            MemberRole::Member,
+            UserId::default(),
+            Utc::now(),
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationError::PermissionDeniedError(_, _, _))
+        ));
+
+        let result = ProjectInvitation::new(
+            &project,
+            email.clone(),
+            MemberRole::Owner,
+            owner_id.clone(),
+            Utc::now(),
+        );
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationError::OwnerRoleInvitationError)
+        ));
+
+        let (invitation, _) = ProjectInvitation::new(
+            &project,
+            email.clone(),
+            MemberRole::Member,
+            owner_id,
+            Utc::now(),
+        )
+        .unwrap();
+        assert_eq!(invitation.status(), &ProjectInvitationStatus::Pending);
+        assert_eq!(invitation.email(), &email);
+    }
+
+    #[test]
+    fn test_accept_and_decline() {
+        let owner_id = UserId::default();
+        let user = create_user("Invitee@Example.com");
+        let project = create_project(&owner_id);
+        let now = Utc::now();
+
+        let (mut invitation, _) = ProjectInvitation::new(
+            &project,
+            Email::new("invitee@example.com").unwrap(),
+            MemberRole::Member,
+            owner_id,
+            now,
+        )
+        .unwrap();
+        let snapshot = invitation.clone();
+
+        let event = invitation.accept(&user, now).unwrap();
+        assert_eq!(invitation.status(), &ProjectInvitationStatus::Accepted);
+
+        let result = invitation.decline(&user, now);
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationError::NotPendingError(_, _))
+        ));
+
+        let replayed = ProjectInvitation::replay(&[event], snapshot);
+        assert_eq!(replayed.status(), &ProjectInvitationStatus::Accepted);
+        assert_eq!(replayed.seq_nr(), invitation.seq_nr());
+    }
+
+    #[test]
+    fn test_expire() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let now = Utc::now();
+
+        let (mut invitation, _) = ProjectInvitation::new(
+            &project,
+            Email::new("invitee@example.com").unwrap(),
+            MemberRole::Member,
+            owner_id,
+            now,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            invitation.expire(now),
+            Err(ProjectInvitationError::NotExpiredError(_))
+        ));
+
+        let later = now + Duration::days(INVITATION_VALIDITY_DAYS);
+        let result = invitation.accept(&create_user("invitee@example.com"), later);
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationError::ExpiredError(_))
+        ));
+
+        let _ = invitation.expire(later).unwrap();
+        assert_eq!(invitation.status(), &ProjectInvitationStatus::Expired);
+    }
+
+    #[test]
+    fn test_accept_and_decline_by_other_user() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let now = Utc::now();
+        let other_user = create_user("other@example.com");
+
+        let (mut invitation, _) = ProjectInvitation::new(
+            &project,
+            Email::new("invitee@example.com").unwrap(),
+            MemberRole::Member,
+            owner_id,
+            now,
+        )
+        .unwrap();
+
+        let result = invitation.accept(&other_user, now);
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationError::NotInviteeError(_, _))
+        ));
+
+        let result = invitation.decline(&other_user, now);
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationError::NotInviteeError(_, _))
+        ));
+        assert_eq!(invitation.status(), &ProjectInvitationStatus::Pending);
+    }
+
+    #[test]
+    fn test_join_by_invitation_after_inviter_is_removed() {
+        let owner_id = UserId::default();
+        let inviter_id = UserId::default();
+        let mut project = create_project(&owner_id);
+        project
+            .add_member(
+                MemberId::default(),
+                inviter_id.clone(),
+                MemberRole::Admin,
+                owner_id.clone(),
+            )
+            .unwrap();
+        let snapshot = project.clone();
+        let invitee = create_user("invitee@example.com");
+        let (mut invitation, _) = ProjectInvitation::new(
+            &project,
+            invitee.email().clone(),
+            MemberRole::Member,
+            inviter_id.clone(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        // 承諾していない招待では参加できない
+        assert!(matches!(
+            project.join_by_invitation(&invitation, MemberId::default(), invitee.id().clone()),
+            Err(ProjectError::InvitationNotAcceptedError(_))
+        ));
+
+        // 招待を送信したユーザがメンバーでなくなっても参加できる
+        let removed = project.remove_member(inviter_id, owner_id).unwrap();
+        invitation.accept(&invitee, Utc::now()).unwrap();
+        let joined = project
+            .join_by_invitation(&invitation, MemberId::default(), invitee.id().clone())
+            .unwrap();
+        assert!(project.members().is_member(invitee.id()));
+        assert!(matches!(
+            project.join_by_invitation(&invitation, MemberId::default(), invitee.id().clone()),
+            Err(ProjectError::AlreadyMemberError(_, _))
+        ));
+
+        let mut other_project = create_project(&UserId::default());
+        assert!(matches!(
+            other_project.join_by_invitation(&invitation, MemberId::default(), invitee.id().clone()),
+            Err(ProjectError::MismatchedInvitationError(_, _))
+        ));
+
+        let replayed = Project::replay(&[removed, joined], snapshot);
+        assert!(replayed.members().is_member(invitee.id()));
+    }
+}
@@ -0,0 +1,28 @@
+use crate::project::{Permission, ProjectId};
+use crate::project_invitation::{ProjectInvitationId, ProjectInvitationStatus};
+use crate::user::UserId;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProjectInvitationError {
+    #[error("The project is deleted: {0:?}")]
+    ProjectAlreadyDeletedError(ProjectId),
+
+    #[error("The {0} does not have the {1} permission on the project: {2:?}")]
+    PermissionDeniedError(String, Permission, UserId),
+
+    #[error("The owner role cannot be granted by an invitation")]
+    OwnerRoleInvitationError,
+
+    #[error("The invitation is not pending: {0:?}, {1}")]
+    NotPendingError(ProjectInvitationId, ProjectInvitationStatus),
+
+    #[error("The invitation has expired: {0:?}")]
+    ExpiredError(ProjectInvitationId),
+
+    #[error("The invitation has not expired yet: {0:?}")]
+    NotExpiredError(ProjectInvitationId),
+
+    #[error("The user is not the invitee of the invitation: {0:?}, {1:?}")]
+    NotInviteeError(ProjectInvitationId, UserId),
+}
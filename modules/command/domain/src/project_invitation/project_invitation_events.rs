@@ -0,0 +1,183 @@
+use chrono::{DateTime, Utc};
+use event_store_adapter_rs::types::Event;
+use serde::{Deserialize, Serialize};
+use ulid_generator_rs::ULID;
+
+use crate::email::Email;
+use crate::helper::id_generate;
+use crate::project::{MemberRole, ProjectId};
+use crate::project_invitation::ProjectInvitationId;
+use crate::user::UserId;
+
+pub type ProjectInvitationEventId = ULID;
+
+/// プロジェクトへの招待に関するイベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ProjectInvitationEvent {
+    /// 招待が送信された
+    InvitationSent(ProjectInvitationEventSentBody),
+    /// 招待が承諾された
+    InvitationAccepted(ProjectInvitationEventAcceptedBody),
+    /// 招待が辞退された
+    InvitationDeclined(ProjectInvitationEventDeclinedBody),
+    /// 招待が期限切れになった
+    InvitationExpired(ProjectInvitationEventExpiredBody),
+}
+
+impl Event for ProjectInvitationEvent {
+    type AggregateID = ProjectInvitationId;
+    type ID = ProjectInvitationEventId;
+
+    fn id(&self) -> &ProjectInvitationEventId {
+        match self {
+            ProjectInvitationEvent::InvitationSent(event) => &event.id,
+            ProjectInvitationEvent::InvitationAccepted(event) => &event.id,
+            ProjectInvitationEvent::InvitationDeclined(event) => &event.id,
+            ProjectInvitationEvent::InvitationExpired(event) => &event.id,
+        }
+    }
+
+    fn seq_nr(&self) -> usize {
+        match self {
+            ProjectInvitationEvent::InvitationSent(event) => event.seq_nr,
+            ProjectInvitationEvent::InvitationAccepted(event) => event.seq_nr,
+            ProjectInvitationEvent::InvitationDeclined(event) => event.seq_nr,
+            ProjectInvitationEvent::InvitationExpired(event) => event.seq_nr,
+        }
+    }
+
+    fn aggregate_id(&self) -> &ProjectInvitationId {
+        match self {
+            ProjectInvitationEvent::InvitationSent(event) => &event.aggregate_id,
+            ProjectInvitationEvent::InvitationAccepted(event) => &event.aggregate_id,
+            ProjectInvitationEvent::InvitationDeclined(event) => &event.aggregate_id,
+            ProjectInvitationEvent::InvitationExpired(event) => &event.aggregate_id,
+        }
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        match self {
+            ProjectInvitationEvent::InvitationSent(event) => &event.occurred_at,
+            ProjectInvitationEvent::InvitationAccepted(event) => &event.occurred_at,
+            ProjectInvitationEvent::InvitationDeclined(event) => &event.occurred_at,
+            ProjectInvitationEvent::InvitationExpired(event) => &event.occurred_at,
+        }
+    }
+
+    fn is_created(&self) -> bool {
+        matches!(self, ProjectInvitationEvent::InvitationSent(_))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInvitationEventSentBody {
+    pub id: ProjectInvitationEventId,
+    pub aggregate_id: ProjectInvitationId,
+    pub seq_nr: usize,
+    pub project_id: ProjectId,
+    pub email: Email,
+    pub role: MemberRole,
+    pub expires_at: DateTime<Utc>,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectInvitationEventSentBody {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        aggregate_id: ProjectInvitationId,
+        seq_nr: usize,
+        project_id: ProjectId,
+        email: Email,
+        role: MemberRole,
+        expires_at: DateTime<Utc>,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            project_id,
+            email,
+            role,
+            expires_at,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInvitationEventAcceptedBody {
+    pub id: ProjectInvitationEventId,
+    pub aggregate_id: ProjectInvitationId,
+    pub seq_nr: usize,
+    pub project_id: ProjectId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectInvitationEventAcceptedBody {
+    pub fn new(
+        aggregate_id: ProjectInvitationId,
+        seq_nr: usize,
+        project_id: ProjectId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            project_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInvitationEventDeclinedBody {
+    pub id: ProjectInvitationEventId,
+    pub aggregate_id: ProjectInvitationId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectInvitationEventDeclinedBody {
+    pub fn new(
+        aggregate_id: ProjectInvitationId,
+        seq_nr: usize,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInvitationEventExpiredBody {
+    pub id: ProjectInvitationEventId,
+    pub aggregate_id: ProjectInvitationId,
+    pub seq_nr: usize,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl ProjectInvitationEventExpiredBody {
+    pub fn new(aggregate_id: ProjectInvitationId, seq_nr: usize, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self { id, aggregate_id, seq_nr, occurred_at }
+    }
+}
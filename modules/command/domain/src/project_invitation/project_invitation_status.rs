@@ -0,0 +1,27 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// 招待の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectInvitationStatus {
+    /// 回答待ち
+    Pending,
+    /// 承諾済み
+    Accepted,
+    /// 辞退済み
+    Declined,
+    /// 期限切れ
+    Expired,
+}
+
+impl Display for ProjectInvitationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pending => write!(f, "Pending"),
+            Self::Accepted => write!(f, "Accepted"),
+            Self::Declined => write!(f, "Declined"),
+            Self::Expired => write!(f, "Expired"),
+        }
+    }
+}
@@ -0,0 +1,1766 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use event_store_adapter_rs::types::Aggregate;
+use serde::{Deserialize, Serialize};
+
+mod comment;
+mod comment_body;
+mod comment_id;
+mod priority;
+mod task_dependency_service;
+mod task_error;
+mod task_events;
+mod task_id;
+mod task_status;
+mod task_title;
+
+use crate::description::Description;
+use crate::iteration::{Iteration, IterationId, IterationStatus};
+use crate::period::Period;
+use crate::project::{MemberId, Permission, Project, ProjectId, TagId};
+pub use crate::task::comment::Comment;
+pub use crate::task::comment_body::{CommentBody, CommentBodyError};
+pub use crate::task::comment_id::CommentId;
+pub use crate::task::priority::Priority;
+pub use crate::task::task_dependency_service::TaskDependencyService;
+pub use crate::task::task_error::TaskError;
+pub use crate::task::task_events::{
+    TaskEvent, TaskEventAssignedBody, TaskEventCommentAddedBody, TaskEventCommentDeletedBody,
+    TaskEventCommentEditedBody, TaskEventCreatedBody, TaskEventDeletedBody, TaskEventDependencyAddedBody,
+    TaskEventDependencyRemovedBody, TaskEventFinishedBody, TaskEventMovedToBacklogBody, TaskEventMovedToIterationBody,
+    TaskEventPriorityChangedBody, TaskEventRenamedBody, TaskEventScheduledBody, TaskEventStartedBody,
+    TaskEventStatusChangedBody, TaskEventTaggedBody, TaskEventUnassignedBody, TaskEventUntaggedBody,
+};
+pub use crate::task::task_id::TaskId;
+pub use crate::task::task_status::TaskStatus;
+pub use crate::task::task_title::{TaskTitle, TaskTitleError};
+use crate::user::UserId;
+
+/// プロジェクトに紐付く作業単位
+///
+/// 操作の可否は所属するプロジェクトのメンバーの権限で判定するため、各コマンドは[Project]を受け取る。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    id: TaskId,
+    project_id: ProjectId,
+    title: TaskTitle,
+    description: Description,
+    priority: Priority,
+    status: TaskStatus,
+    #[serde(default)]
+    assignee_id: Option<MemberId>,
+    #[serde(default)]
+    planned_period: Option<Period>,
+    #[serde(default)]
+    actual_started_on: Option<NaiveDate>,
+    #[serde(default)]
+    actual_finished_on: Option<NaiveDate>,
+    #[serde(default)]
+    comments: Vec<Comment>,
+    #[serde(default)]
+    tag_ids: Vec<TagId>,
+    #[serde(default)]
+    blocker_ids: Vec<TaskId>,
+    #[serde(default)]
+    iteration_id: Option<IterationId>,
+    deleted: bool,
+    version: usize,
+    seq_nr_counter: usize,
+    last_updated_at: DateTime<Utc>,
+}
+
+impl PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Aggregate for Task {
+    type ID = TaskId;
+
+    fn id(&self) -> &Self::ID {
+        &self.id
+    }
+
+    fn seq_nr(&self) -> usize {
+        self.seq_nr_counter
+    }
+
+    fn version(&self) -> usize {
+        self.version
+    }
+
+    fn set_version(&mut self, version: usize) {
+        self.version = version;
+    }
+
+    fn last_updated_at(&self) -> &DateTime<Utc> {
+        &self.last_updated_at
+    }
+}
+
+impl Task {
+    /// タスクを作成する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - title: タスクのタイトル
+    /// - description: タスクの詳細
+    /// - priority: タスクの優先度
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - プロジェクトが削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 成功した場合は、TaskCreatedイベントを返す。
+    pub fn new(
+        project: &Project,
+        title: TaskTitle,
+        description: Description,
+        priority: Priority,
+        executor_id: UserId,
+    ) -> Result<(Self, TaskEvent), TaskError> {
+        Self::ensure_permission(project, &executor_id, Permission::ManageTasks)?;
+
+        let id = TaskId::new();
+        let now = Utc::now();
+        let my_self = Self {
+            id: id.clone(),
+            project_id: project.id().clone(),
+            title: title.clone(),
+            description: description.clone(),
+            priority,
+            status: TaskStatus::Todo,
+            assignee_id: None,
+            planned_period: None,
+            actual_started_on: None,
+            actual_finished_on: None,
+            comments: Vec::new(),
+            tag_ids: Vec::new(),
+            blocker_ids: Vec::new(),
+            iteration_id: None,
+            deleted: false,
+            version: 1,
+            seq_nr_counter: 1,
+            last_updated_at: now,
+        };
+        let event = TaskEvent::TaskCreated(TaskEventCreatedBody::new(
+            id,
+            my_self.seq_nr_counter,
+            my_self.project_id.clone(),
+            title,
+            description,
+            priority,
+            executor_id,
+            now,
+        ));
+        Ok((my_self, event))
+    }
+
+    fn apply_event(&mut self, event: &TaskEvent) {
+        match event {
+            TaskEvent::TaskRenamed(body) => {
+                self.title = body.new_title.clone();
+            },
+            TaskEvent::TaskPriorityChanged(body) => {
+                self.priority = body.new_priority;
+            },
+            TaskEvent::TaskStatusChanged(body) => {
+                self.set_status(body.new_status, body.occurred_at.date_naive());
+            },
+            TaskEvent::TaskStarted(body) => {
+                self.set_status(TaskStatus::InProgress, body.started_on);
+            },
+            TaskEvent::TaskFinished(body) => {
+                self.set_status(TaskStatus::Done, body.finished_on);
+            },
+            TaskEvent::TaskScheduled(body) => {
+                self.planned_period = Some(body.planned_period);
+            },
+            TaskEvent::TaskDeleted(_) => {
+                self.deleted = true;
+            },
+            TaskEvent::TaskAssigned(body) => {
+                self.assignee_id = Some(body.assignee_id.clone());
+            },
+            TaskEvent::TaskUnassigned(_) => {
+                self.assignee_id = None;
+            },
+            TaskEvent::CommentAdded(body) => {
+                self.comments.push(Comment::new(
+                    body.comment_id.clone(),
+                    body.executor_id.clone(),
+                    body.body.clone(),
+                    body.occurred_at,
+                ));
+            },
+            TaskEvent::CommentEdited(body) => {
+                if let Some(comment) = self.comments.iter_mut().find(|comment| comment.id() == &body.comment_id) {
+                    comment.edit(body.new_body.clone(), body.occurred_at);
+                }
+            },
+            TaskEvent::CommentDeleted(body) => {
+                self.comments.retain(|comment| comment.id() != &body.comment_id);
+            },
+            TaskEvent::TaskTagged(body) => {
+                self.tag_ids.push(body.tag_id.clone());
+            },
+            TaskEvent::TaskUntagged(body) => {
+                self.tag_ids.retain(|tag_id| tag_id != &body.tag_id);
+            },
+            TaskEvent::TaskDependencyAdded(body) => {
+                self.blocker_ids.push(body.blocker_id.clone());
+            },
+            TaskEvent::TaskDependencyRemoved(body) => {
+                self.blocker_ids.retain(|blocker_id| blocker_id != &body.blocker_id);
+            },
+            TaskEvent::TaskMovedToIteration(body) => {
+                self.iteration_id = Some(body.iteration_id.clone());
+            },
+            TaskEvent::TaskMovedToBacklog(_) => {
+                self.iteration_id = None;
+            },
+            TaskEvent::TaskCreated(_) => return,
+        }
+        self.seq_nr_counter += 1;
+    }
+
+    pub fn replay(events: &[TaskEvent], snapshot: Task) -> Self {
+        log::debug!("event.size = {}", events.len());
+        events.iter().fold(snapshot, |mut result, event| {
+            log::debug!("Replaying snapshot: {:?}", result);
+            log::debug!("Replaying event: {:?}", event);
+            result.apply_event(event);
+            result
+        })
+    }
+
+    /// 所属するプロジェクトの[ProjectId]の参照を返す
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    /// [TaskTitle]の参照を返す
+    pub fn title(&self) -> &TaskTitle {
+        &self.title
+    }
+
+    /// [Description]の参照を返す
+    pub fn description(&self) -> &Description {
+        &self.description
+    }
+
+    /// [Priority]を返す
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// [TaskStatus]を返す
+    pub fn status(&self) -> TaskStatus {
+        self.status
+    }
+
+    /// 担当者の[MemberId]の参照を返す
+    pub fn assignee_id(&self) -> Option<&MemberId> {
+        self.assignee_id.as_ref()
+    }
+
+    /// 投稿順の[Comment]のスライスを返す
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    /// 付与されている[TagId]のスライスを返す
+    pub fn tag_ids(&self) -> &[TagId] {
+        &self.tag_ids
+    }
+
+    /// 先行タスクの[TaskId]のスライスを返す
+    ///
+    /// 先行タスクがすべて完了するまで、このタスクは完了にできない。
+    pub fn blocker_ids(&self) -> &[TaskId] {
+        &self.blocker_ids
+    }
+
+    /// 所属するイテレーションの[IterationId]の参照を返す
+    ///
+    /// バックログにある場合は `None` を返す。
+    pub fn iteration_id(&self) -> Option<&IterationId> {
+        self.iteration_id.as_ref()
+    }
+
+    /// 削除も完了もされておらず、後続タスクの完了を妨げるかどうかを判定する
+    pub fn is_open(&self) -> bool {
+        !self.deleted && !self.status.is_done()
+    }
+
+    /// 予定期間を返す
+    pub fn planned_period(&self) -> Option<&Period> {
+        self.planned_period.as_ref()
+    }
+
+    /// 作業を開始した日を返す
+    pub fn actual_started_on(&self) -> Option<NaiveDate> {
+        self.actual_started_on
+    }
+
+    /// 作業を完了した日を返す
+    pub fn actual_finished_on(&self) -> Option<NaiveDate> {
+        self.actual_finished_on
+    }
+
+    /// 実績期間を返す
+    ///
+    /// 作業が完了していない場合は `None` を返す。
+    pub fn actual_period(&self) -> Option<Period> {
+        match (self.actual_started_on, self.actual_finished_on) {
+            (Some(start), Some(end)) => Period::new(start, end).ok(),
+            _ => None,
+        }
+    }
+
+    /// 期限を過ぎているかどうかを判定する
+    ///
+    /// 予定期間の終了日が指定した日より前で、完了していない場合に期限切れとする。
+    pub fn is_overdue(&self, today: &NaiveDate) -> bool {
+        !self.deleted
+            && !self.status.is_done()
+            && self.planned_period.as_ref().is_some_and(|period| period.ends_before(today))
+    }
+
+    /// 削除されているかどうかを返す
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// タスクのタイトルを変更する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - new_title: 新しいタイトル
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 成功した場合は、TaskRenamedイベントを返す。
+    pub fn rename(
+        &mut self,
+        project: &Project,
+        new_title: TaskTitle,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+
+        self.title = new_title.clone();
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskRenamed(TaskEventRenamedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            new_title,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タスクの優先度を変更する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - new_priority: 新しい優先度
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 成功した場合は、TaskPriorityChangedイベントを返す。
+    pub fn change_priority(
+        &mut self,
+        project: &Project,
+        new_priority: Priority,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+
+        self.priority = new_priority;
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskPriorityChanged(
+            TaskEventPriorityChangedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                new_priority,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// タスクの状態を変更する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - new_status: 新しい状態
+    /// - blockers: 先行タスク。完了にする場合のみ参照する
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 現在と同じ状態を指定した場合はエラーを返す。
+    /// - プロジェクトのワークフローで許可されていない遷移の場合はエラーを返す。
+    /// - 完了にする場合、先行タスクが見つからない、または完了していない場合はエラーを返す。
+    /// - 初めて作業中になった場合は実績の開始日を記録し、TaskStartedイベントを返す。
+    /// - 完了になった場合は実績の終了日を記録し、TaskFinishedイベントを返す。
+    /// - それ以外の場合は、TaskStatusChangedイベントを返す。
+    pub fn change_status(
+        &mut self,
+        project: &Project,
+        new_status: TaskStatus,
+        blockers: &[Task],
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        if self.status == new_status {
+            return Err(TaskError::SameStatusError(new_status));
+        }
+        if !project.workflow().can_transition(&self.status, &new_status) {
+            return Err(TaskError::IllegalStatusTransitionError(
+                self.status,
+                new_status,
+                project.workflow().allowed_targets(&self.status),
+            ));
+        }
+        if new_status.is_done() {
+            TaskDependencyService::ensure_no_open_blockers(self, blockers)?;
+        }
+
+        let now = Utc::now();
+        let today = now.date_naive();
+        let started = new_status == TaskStatus::InProgress && self.actual_started_on.is_none();
+        self.set_status(new_status, today);
+        self.seq_nr_counter += 1;
+        let event = if started {
+            TaskEvent::TaskStarted(TaskEventStartedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                today,
+                executor_id,
+                now,
+            ))
+        } else if new_status.is_done() {
+            TaskEvent::TaskFinished(TaskEventFinishedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                today,
+                executor_id,
+                now,
+            ))
+        } else {
+            TaskEvent::TaskStatusChanged(TaskEventStatusChangedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                new_status,
+                executor_id,
+                now,
+            ))
+        };
+        Ok(event)
+    }
+
+    /// 状態を変更し、実績の開始日と終了日を記録する
+    ///
+    /// 開始日は初めて作業中になった日とし、完了から戻した場合は終了日を取り消す。
+    fn set_status(&mut self, new_status: TaskStatus, date: NaiveDate) {
+        match new_status {
+            TaskStatus::InProgress => {
+                self.actual_started_on.get_or_insert(date);
+                self.actual_finished_on = None;
+            },
+            TaskStatus::Done => {
+                self.actual_started_on.get_or_insert(date);
+                self.actual_finished_on = Some(date);
+            },
+            _ => {
+                self.actual_finished_on = None;
+            },
+        }
+        self.status = new_status;
+    }
+
+    /// タスクの予定期間を設定する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - planned_period: 予定期間
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 成功した場合は、TaskScheduledイベントを返す。
+    pub fn schedule(
+        &mut self,
+        project: &Project,
+        planned_period: Period,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+
+        self.planned_period = Some(planned_period);
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskScheduled(TaskEventScheduledBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            planned_period,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タスクを削除する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが既に削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 成功した場合は、TaskDeletedイベントを返す。
+    pub fn delete(&mut self, project: &Project, executor_id: UserId) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+
+        self.deleted = true;
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskDeleted(TaskEventDeletedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タスクに担当者を割り当てる
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - assignee_id: 担当者のメンバーID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 担当者がプロジェクトのメンバーでない場合はエラーを返す。
+    /// - 成功した場合は、TaskAssignedイベントを返す。
+    pub fn assign(
+        &mut self,
+        project: &Project,
+        assignee_id: MemberId,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        if project.members().find_by_id(&assignee_id).is_none() {
+            return Err(TaskError::NotProjectMemberError(
+                "assignee_id".to_string(),
+                assignee_id,
+            ));
+        }
+
+        self.assignee_id = Some(assignee_id.clone());
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskAssigned(TaskEventAssignedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            assignee_id,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タスクの担当者を外す
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 担当者が割り当てられていない場合はエラーを返す。
+    /// - 成功した場合は、TaskUnassignedイベントを返す。
+    pub fn unassign(&mut self, project: &Project, executor_id: UserId) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        let assignee_id = self
+            .assignee_id
+            .take()
+            .ok_or_else(|| TaskError::NotAssignedError(self.id.clone()))?;
+
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskUnassigned(TaskEventUnassignedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            assignee_id,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// プロジェクトから外れたメンバーの割り当てを解除する
+    ///
+    /// メンバーの削除に伴う後始末のため、実行者の権限は検証しない。
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - executor_id: メンバーを削除した実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - 削除済み、完了済み、または担当者が現在もメンバーである場合は何もせず `None` を返す。
+    /// - プロジェクトが異なる場合はエラーを返す。
+    /// - 割り当てを解除した場合は、TaskUnassignedイベントを返す。
+    pub fn release_removed_assignee(
+        &mut self,
+        project: &Project,
+        executor_id: UserId,
+    ) -> Result<Option<TaskEvent>, TaskError> {
+        if project.id() != &self.project_id {
+            return Err(TaskError::MismatchedProjectError(
+                self.id.clone(),
+                project.id().clone(),
+            ));
+        }
+        if self.deleted || self.status.is_done() {
+            return Ok(None);
+        }
+        match &self.assignee_id {
+            Some(assignee_id) if project.members().find_by_id(assignee_id).is_none() => {
+                let assignee_id = assignee_id.clone();
+                self.assignee_id = None;
+                self.seq_nr_counter += 1;
+                let now = Utc::now();
+                Ok(Some(TaskEvent::TaskUnassigned(
+                    TaskEventUnassignedBody::new(
+                        self.id.clone(),
+                        self.seq_nr_counter,
+                        assignee_id,
+                        executor_id,
+                        now,
+                    ),
+                )))
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// タスクにコメントを追加する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - comment_id: 追加するコメントのID
+    /// - body: コメント本文
+    /// - executor_id: 実行者のユーザID。コメントの投稿者となる
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::CommentOnTasks] を持たない場合はエラーを返す。
+    /// - 成功した場合は、CommentAddedイベントを返す。
+    pub fn add_comment(
+        &mut self,
+        project: &Project,
+        comment_id: CommentId,
+        body: CommentBody,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_accessible(project, &executor_id, Permission::CommentOnTasks)?;
+
+        let now = Utc::now();
+        self.comments.push(Comment::new(
+            comment_id.clone(),
+            executor_id.clone(),
+            body.clone(),
+            now,
+        ));
+        self.seq_nr_counter += 1;
+        Ok(TaskEvent::CommentAdded(TaskEventCommentAddedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            comment_id,
+            body,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// コメントを編集する
+    ///
+    /// 編集前の本文はイベントとして残る。
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - comment_id: 編集するコメントのID
+    /// - new_body: 新しいコメント本文
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::CommentOnTasks] を持たない場合はエラーを返す。
+    /// - コメントが存在しない場合はエラーを返す。
+    /// - 実行者がコメントの投稿者でない場合はエラーを返す。
+    /// - 成功した場合は、CommentEditedイベントを返す。
+    pub fn edit_comment(
+        &mut self,
+        project: &Project,
+        comment_id: CommentId,
+        new_body: CommentBody,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_accessible(project, &executor_id, Permission::CommentOnTasks)?;
+        let comment = self
+            .comments
+            .iter_mut()
+            .find(|comment| comment.id() == &comment_id)
+            .ok_or_else(|| TaskError::CommentNotFoundError(comment_id.clone()))?;
+        if !comment.is_written_by(&executor_id) {
+            return Err(TaskError::NotCommentAuthorError(comment_id, executor_id));
+        }
+
+        let now = Utc::now();
+        comment.edit(new_body.clone(), now);
+        self.seq_nr_counter += 1;
+        Ok(TaskEvent::CommentEdited(TaskEventCommentEditedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            comment_id,
+            new_body,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// コメントを削除する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - comment_id: 削除するコメントのID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::CommentOnTasks] を持たない場合はエラーを返す。
+    /// - コメントが存在しない場合はエラーを返す。
+    /// - 実行者がコメントの投稿者でも、プロジェクトの管理者でもない場合はエラーを返す。
+    /// - 成功した場合は、CommentDeletedイベントを返す。
+    pub fn delete_comment(
+        &mut self,
+        project: &Project,
+        comment_id: CommentId,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_accessible(project, &executor_id, Permission::CommentOnTasks)?;
+        let comment = self
+            .comments
+            .iter()
+            .find(|comment| comment.id() == &comment_id)
+            .ok_or_else(|| TaskError::CommentNotFoundError(comment_id.clone()))?;
+        if !comment.is_written_by(&executor_id) && !project.members().is_administrator(&executor_id) {
+            return Err(TaskError::NotCommentAuthorError(comment_id, executor_id));
+        }
+
+        self.comments.retain(|comment| comment.id() != &comment_id);
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::CommentDeleted(TaskEventCommentDeletedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            comment_id,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タスクにタグを付与する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - tag_id: 付与するタグのID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - タグがプロジェクトのタグカタログに存在しない場合はエラーを返す。
+    /// - 既に付与されている場合はエラーを返す。
+    /// - 成功した場合は、TaskTaggedイベントを返す。
+    pub fn tag(&mut self, project: &Project, tag_id: TagId, executor_id: UserId) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        if project.find_tag(&tag_id).is_none() {
+            return Err(TaskError::UnknownTagError(tag_id));
+        }
+        if self.tag_ids.contains(&tag_id) {
+            return Err(TaskError::AlreadyTaggedError(tag_id));
+        }
+
+        self.tag_ids.push(tag_id.clone());
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskTagged(TaskEventTaggedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            tag_id,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// タスクからタグを外す
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - tag_id: 外すタグのID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - タグが付与されていない場合はエラーを返す。
+    /// - 成功した場合は、TaskUntaggedイベントを返す。
+    pub fn untag(&mut self, project: &Project, tag_id: TagId, executor_id: UserId) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        if !self.tag_ids.contains(&tag_id) {
+            return Err(TaskError::NotTaggedError(tag_id));
+        }
+        Ok(self.remove_tag(tag_id, executor_id))
+    }
+
+    /// タグカタログから削除されたタグを外す
+    ///
+    /// タグの削除に伴う後始末のため、実行者の権限は検証しない。
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - tag_id: 削除されたタグのID
+    /// - executor_id: タグを削除した実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - 削除済み、タグが付与されていない、またはタグがカタログに残っている場合は何もせず `None` を返す。
+    /// - プロジェクトが異なる場合はエラーを返す。
+    /// - タグを外した場合は、TaskUntaggedイベントを返す。
+    pub fn release_deleted_tag(
+        &mut self,
+        project: &Project,
+        tag_id: TagId,
+        executor_id: UserId,
+    ) -> Result<Option<TaskEvent>, TaskError> {
+        if project.id() != &self.project_id {
+            return Err(TaskError::MismatchedProjectError(
+                self.id.clone(),
+                project.id().clone(),
+            ));
+        }
+        if self.deleted || !self.tag_ids.contains(&tag_id) || project.find_tag(&tag_id).is_some() {
+            return Ok(None);
+        }
+        Ok(Some(self.remove_tag(tag_id, executor_id)))
+    }
+
+    fn remove_tag(&mut self, tag_id: TagId, executor_id: UserId) -> TaskEvent {
+        self.tag_ids.retain(|id| id != &tag_id);
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        TaskEvent::TaskUntagged(TaskEventUntaggedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            tag_id,
+            executor_id,
+            now,
+        ))
+    }
+
+    /// 先行タスクを追加する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - blocker: 先行タスク
+    /// - tasks: 同じプロジェクトに属するタスク。依存関係の循環の検証に用いる
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクまたは先行タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 自身を先行タスクにしようとした場合はエラーを返す。
+    /// - 既に先行タスクである場合はエラーを返す。
+    /// - 依存関係が循環する場合はエラーを返す。
+    /// - 成功した場合は、TaskDependencyAddedイベントを返す。
+    pub fn add_blocker(
+        &mut self,
+        project: &Project,
+        blocker: &Task,
+        tasks: &[Task],
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        if blocker.deleted {
+            return Err(TaskError::AlreadyDeletedError(blocker.id.clone()));
+        }
+        if blocker.project_id != self.project_id {
+            return Err(TaskError::MismatchedProjectError(
+                blocker.id.clone(),
+                project.id().clone(),
+            ));
+        }
+        if blocker.id == self.id {
+            return Err(TaskError::SelfDependencyError(self.id.clone()));
+        }
+        if self.blocker_ids.contains(&blocker.id) {
+            return Err(TaskError::DependencyAlreadyExistsError(blocker.id.clone()));
+        }
+        TaskDependencyService::ensure_no_cycle(tasks, &self.id, &blocker.id)?;
+
+        self.blocker_ids.push(blocker.id.clone());
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskDependencyAdded(
+            TaskEventDependencyAddedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                blocker.id.clone(),
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// 先行タスクを削除する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - blocker_id: 先行タスクのID
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 先行タスクでない場合はエラーを返す。
+    /// - 成功した場合は、TaskDependencyRemovedイベントを返す。
+    pub fn remove_blocker(
+        &mut self,
+        project: &Project,
+        blocker_id: TaskId,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        if !self.blocker_ids.contains(&blocker_id) {
+            return Err(TaskError::DependencyNotFoundError(blocker_id));
+        }
+
+        self.blocker_ids.retain(|id| id != &blocker_id);
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        Ok(TaskEvent::TaskDependencyRemoved(
+            TaskEventDependencyRemovedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                blocker_id,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// タスクをイテレーションに移動する
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - iteration: 移動先のイテレーション
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 移動先のイテレーションが他のプロジェクトに属する、または完了している場合はエラーを返す。
+    /// - 既に移動先のイテレーションにある場合はエラーを返す。
+    /// - 成功した場合は、TaskMovedToIterationイベントを返す。
+    pub fn move_to_iteration(
+        &mut self,
+        project: &Project,
+        iteration: &Iteration,
+        executor_id: UserId,
+    ) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        self.ensure_destination(iteration)?;
+        if self.iteration_id.as_ref() == Some(iteration.id()) {
+            return Err(TaskError::AlreadyInIterationError(iteration.id().clone()));
+        }
+        Ok(self.set_iteration(iteration.id().clone(), executor_id))
+    }
+
+    /// タスクをバックログに戻す
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - タスクが削除されている場合はエラーを返す。
+    /// - プロジェクトが異なる、または削除されている場合はエラーを返す。
+    /// - 実行者が [Permission::ManageTasks] を持たない場合はエラーを返す。
+    /// - 既にバックログにある場合はエラーを返す。
+    /// - 成功した場合は、TaskMovedToBacklogイベントを返す。
+    pub fn move_to_backlog(&mut self, project: &Project, executor_id: UserId) -> Result<TaskEvent, TaskError> {
+        self.ensure_editable(project, &executor_id)?;
+        let from_iteration_id = self
+            .iteration_id
+            .clone()
+            .ok_or_else(|| TaskError::AlreadyInBacklogError(self.id.clone()))?;
+        Ok(self.clear_iteration(from_iteration_id, executor_id))
+    }
+
+    /// 完了したイテレーションに残っている未完了のタスクを引き継ぎ先に移動する
+    ///
+    /// イテレーションの完了に伴う後始末のため、実行者の権限は検証しない。
+    ///
+    /// # 引数
+    /// - project: 所属するプロジェクト
+    /// - completed: 完了したイテレーション
+    /// - destination: 引き継ぎ先のイテレーション。`None` の場合はバックログに戻す
+    /// - executor_id: イテレーションを完了した実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - 削除済み、完了済み、またはイテレーションが完了していない、完了したイテレーションにない場合は何もせず `None` を返す。
+    /// - プロジェクトが異なる場合はエラーを返す。
+    /// - 引き継ぎ先のイテレーションが他のプロジェクトに属する、または完了している場合はエラーを返す。
+    /// - 移動した場合は、TaskMovedToIterationイベントまたはTaskMovedToBacklogイベントを返す。
+    pub fn carry_over(
+        &mut self,
+        project: &Project,
+        completed: &Iteration,
+        destination: Option<&Iteration>,
+        executor_id: UserId,
+    ) -> Result<Option<TaskEvent>, TaskError> {
+        if project.id() != &self.project_id {
+            return Err(TaskError::MismatchedProjectError(
+                self.id.clone(),
+                project.id().clone(),
+            ));
+        }
+        if self.deleted
+            || self.status.is_done()
+            || completed.status() != IterationStatus::Completed
+            || self.iteration_id.as_ref() != Some(completed.id())
+        {
+            return Ok(None);
+        }
+        match destination {
+            Some(destination) => {
+                self.ensure_destination(destination)?;
+                Ok(Some(
+                    self.set_iteration(destination.id().clone(), executor_id),
+                ))
+            },
+            None => Ok(Some(
+                self.clear_iteration(completed.id().clone(), executor_id),
+            )),
+        }
+    }
+
+    /// タスクを移動できるイテレーションかどうかを検証する
+    fn ensure_destination(&self, iteration: &Iteration) -> Result<(), TaskError> {
+        if iteration.project_id() != &self.project_id {
+            return Err(TaskError::MismatchedIterationError(
+                iteration.id().clone(),
+                self.project_id.clone(),
+            ));
+        }
+        if iteration.status() == IterationStatus::Completed {
+            return Err(TaskError::IterationAlreadyCompletedError(
+                iteration.id().clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn set_iteration(&mut self, iteration_id: IterationId, executor_id: UserId) -> TaskEvent {
+        self.iteration_id = Some(iteration_id.clone());
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        TaskEvent::TaskMovedToIteration(TaskEventMovedToIterationBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            iteration_id,
+            executor_id,
+            now,
+        ))
+    }
+
+    fn clear_iteration(&mut self, from_iteration_id: IterationId, executor_id: UserId) -> TaskEvent {
+        self.iteration_id = None;
+        self.seq_nr_counter += 1;
+        let now = Utc::now();
+        TaskEvent::TaskMovedToBacklog(TaskEventMovedToBacklogBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            from_iteration_id,
+            executor_id,
+            now,
+        ))
+    }
+
+    /// タスクを編集できるかどうかを検証する
+    fn ensure_editable(&self, project: &Project, executor_id: &UserId) -> Result<(), TaskError> {
+        self.ensure_accessible(project, executor_id, Permission::ManageTasks)
+    }
+
+    /// 削除されていないタスクに対して、実行者が指定した権限を持つかどうかを検証する
+    fn ensure_accessible(
+        &self,
+        project: &Project,
+        executor_id: &UserId,
+        permission: Permission,
+    ) -> Result<(), TaskError> {
+        if self.deleted {
+            return Err(TaskError::AlreadyDeletedError(self.id.clone()));
+        }
+        if project.id() != &self.project_id {
+            return Err(TaskError::MismatchedProjectError(
+                self.id.clone(),
+                project.id().clone(),
+            ));
+        }
+        Self::ensure_permission(project, executor_id, permission)
+    }
+
+    /// 実行者がプロジェクトで指定した権限を持つかどうかを検証する
+    fn ensure_permission(project: &Project, executor_id: &UserId, permission: Permission) -> Result<(), TaskError> {
+        if project.is_deleted() {
+            return Err(TaskError::ProjectAlreadyDeletedError(project.id().clone()));
+        }
+        if !project.members().has_permission(executor_id, &permission) {
+            return Err(TaskError::PermissionDeniedError(
+                "executor_id".to_string(),
+                permission,
+                executor_id.clone(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::Days;
+
+    use crate::project::{MemberRole, Members, ProjectName, TagName};
+
+    fn create_project(owner_id: &UserId) -> Project {
+        let (project, _) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        project
+    }
+
+    #[test]
+    fn test_create_task() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+
+        let result = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::High,
+            UserId::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(TaskError::PermissionDeniedError(_, _, _))
+        ));
+
+        let (task, event) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::High,
+            owner_id,
+        )
+        .unwrap();
+        assert!(matches!(event, TaskEvent::TaskCreated(_)));
+        assert_eq!(task.status(), TaskStatus::Todo);
+        assert_eq!(task.priority(), Priority::High);
+        assert_eq!(task.project_id(), project.id());
+    }
+
+    #[test]
+    fn test_assign_and_release_removed_assignee() {
+        let owner_id = UserId::default();
+        let member_user_id = UserId::default();
+        let member_id = MemberId::default();
+        let mut project = create_project(&owner_id);
+        project
+            .add_member(
+                member_id.clone(),
+                member_user_id.clone(),
+                MemberRole::Member,
+                owner_id.clone(),
+            )
+            .unwrap();
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = task.clone();
+
+        assert!(matches!(
+            task.unassign(&project, owner_id.clone()),
+            Err(TaskError::NotAssignedError(_))
+        ));
+        assert!(matches!(
+            task.assign(&project, MemberId::default(), owner_id.clone()),
+            Err(TaskError::NotProjectMemberError(_, _))
+        ));
+
+        let assigned = task.assign(&project, member_id.clone(), owner_id.clone()).unwrap();
+        assert_eq!(task.assignee_id(), Some(&member_id));
+        // 担当者が現在もメンバーであれば何もしない
+        assert!(task.release_removed_assignee(&project, owner_id.clone()).unwrap().is_none());
+
+        project.remove_member(member_user_id, owner_id.clone()).unwrap();
+        let unassigned = task.release_removed_assignee(&project, owner_id.clone()).unwrap();
+        assert!(matches!(unassigned, Some(TaskEvent::TaskUnassigned(_))));
+        assert_eq!(task.assignee_id(), None);
+
+        let replayed = Task::replay(&[assigned.clone()], snapshot.clone());
+        assert_eq!(replayed.assignee_id(), Some(&member_id));
+        let replayed = Task::replay(&[assigned, unassigned.unwrap()], snapshot);
+        assert_eq!(replayed.assignee_id(), None);
+        assert_eq!(replayed.seq_nr(), task.seq_nr());
+    }
+
+    #[test]
+    fn test_schedule_and_track_actual_period() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = task.clone();
+        let today = Utc::now().date_naive();
+        let yesterday = today.pred_opt().unwrap();
+        assert!(!task.is_overdue(&today));
+
+        let events = vec![
+            task.schedule(
+                &project,
+                Period::new(yesterday, yesterday).unwrap(),
+                owner_id.clone(),
+            )
+            .unwrap(),
+            task.change_status(&project, TaskStatus::InProgress, &[], owner_id.clone())
+                .unwrap(),
+            task.change_status(&project, TaskStatus::InReview, &[], owner_id.clone())
+                .unwrap(),
+        ];
+        assert!(matches!(events[1], TaskEvent::TaskStarted(_)));
+        assert!(matches!(events[2], TaskEvent::TaskStatusChanged(_)));
+        assert_eq!(task.actual_started_on(), Some(today));
+        assert_eq!(task.actual_period(), None);
+        assert!(task.is_overdue(&today));
+        assert!(!task.is_overdue(&yesterday));
+
+        let finished = task.change_status(&project, TaskStatus::Done, &[], owner_id.clone()).unwrap();
+        assert!(matches!(finished, TaskEvent::TaskFinished(_)));
+        assert_eq!(
+            task.actual_period(),
+            Some(Period::new(today, today).unwrap())
+        );
+        assert!(!task.is_overdue(&today));
+
+        let replayed = Task::replay(&[events, vec![finished]].concat(), snapshot);
+        assert_eq!(replayed.status(), TaskStatus::Done);
+        assert_eq!(replayed.planned_period(), task.planned_period());
+        assert_eq!(replayed.actual_period(), task.actual_period());
+        assert_eq!(replayed.seq_nr(), task.seq_nr());
+
+        // 完了から戻すと実績の終了日は取り消される
+        task.change_status(&project, TaskStatus::Todo, &[], owner_id).unwrap();
+        assert_eq!(task.actual_finished_on(), None);
+        assert_eq!(task.actual_started_on(), Some(today));
+    }
+
+    #[test]
+    fn test_comments() {
+        let owner_id = UserId::default();
+        let guest_user_id = UserId::default();
+        let mut project = create_project(&owner_id);
+        project
+            .add_member(
+                MemberId::default(),
+                guest_user_id.clone(),
+                MemberRole::Guest,
+                owner_id.clone(),
+            )
+            .unwrap();
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = task.clone();
+        let guest_comment_id = CommentId::new();
+        let owner_comment_id = CommentId::new();
+
+        let mut events = vec![
+            task.add_comment(
+                &project,
+                guest_comment_id.clone(),
+                CommentBody::new("First").unwrap(),
+                guest_user_id.clone(),
+            )
+            .unwrap(),
+            task.add_comment(
+                &project,
+                owner_comment_id.clone(),
+                CommentBody::new("Second").unwrap(),
+                owner_id.clone(),
+            )
+            .unwrap(),
+        ];
+        assert!(matches!(
+            task.add_comment(
+                &project,
+                CommentId::new(),
+                CommentBody::new("Outsider").unwrap(),
+                UserId::default()
+            ),
+            Err(TaskError::PermissionDeniedError(_, _, _))
+        ));
+
+        // 投稿者以外は編集できない
+        assert!(matches!(
+            task.edit_comment(
+                &project,
+                guest_comment_id.clone(),
+                CommentBody::new("Edited").unwrap(),
+                owner_id.clone()
+            ),
+            Err(TaskError::NotCommentAuthorError(_, _))
+        ));
+        events.push(
+            task.edit_comment(
+                &project,
+                guest_comment_id.clone(),
+                CommentBody::new("Edited").unwrap(),
+                guest_user_id.clone(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            task.comments()[0].body(),
+            &CommentBody::new("Edited").unwrap()
+        );
+        assert!(task.comments()[0].edited_at().is_some());
+
+        // 管理者は他人のコメントを削除できるが、ゲストはできない
+        assert!(matches!(
+            task.delete_comment(&project, owner_comment_id.clone(), guest_user_id.clone()),
+            Err(TaskError::NotCommentAuthorError(_, _))
+        ));
+        events.push(
+            task.delete_comment(&project, guest_comment_id.clone(), owner_id.clone())
+                .unwrap(),
+        );
+        assert!(matches!(
+            task.delete_comment(&project, guest_comment_id, owner_id),
+            Err(TaskError::CommentNotFoundError(_))
+        ));
+        assert_eq!(task.comments().len(), 1);
+        assert_eq!(task.comments()[0].id(), &owner_comment_id);
+
+        let replayed = Task::replay(&events, snapshot);
+        assert_eq!(replayed.comments(), task.comments());
+        assert_eq!(replayed.seq_nr(), task.seq_nr());
+    }
+
+    #[test]
+    fn test_tag_and_release_deleted_tag() {
+        let owner_id = UserId::default();
+        let mut project = create_project(&owner_id);
+        let tag_id = TagId::new();
+        project
+            .create_tag(
+                tag_id.clone(),
+                TagName::new("bug").unwrap(),
+                owner_id.clone(),
+            )
+            .unwrap();
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = task.clone();
+
+        assert!(matches!(
+            task.tag(&project, TagId::new(), owner_id.clone()),
+            Err(TaskError::UnknownTagError(_))
+        ));
+        let tagged = task.tag(&project, tag_id.clone(), owner_id.clone()).unwrap();
+        assert!(matches!(
+            task.tag(&project, tag_id.clone(), owner_id.clone()),
+            Err(TaskError::AlreadyTaggedError(_))
+        ));
+        assert_eq!(task.tag_ids(), &[tag_id.clone()]);
+        // タグがカタログに残っている場合は何もしない
+        assert!(
+            task.release_deleted_tag(&project, tag_id.clone(), owner_id.clone())
+                .unwrap()
+                .is_none()
+        );
+
+        project.delete_tag(tag_id.clone(), owner_id.clone()).unwrap();
+        let untagged = task.release_deleted_tag(&project, tag_id.clone(), owner_id.clone()).unwrap();
+        assert!(matches!(untagged, Some(TaskEvent::TaskUntagged(_))));
+        assert!(task.tag_ids().is_empty());
+        assert!(matches!(
+            task.untag(&project, tag_id, owner_id),
+            Err(TaskError::NotTaggedError(_))
+        ));
+
+        let replayed = Task::replay(&[tagged, untagged.unwrap()], snapshot);
+        assert!(replayed.tag_ids().is_empty());
+        assert_eq!(replayed.seq_nr(), task.seq_nr());
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let new_task = |title: &str| {
+            Task::new(
+                &project,
+                TaskTitle::new(title).unwrap(),
+                Description::default(),
+                Priority::default(),
+                owner_id.clone(),
+            )
+            .unwrap()
+            .0
+        };
+        let mut design = new_task("Design");
+        let mut build = new_task("Build");
+        let mut release = new_task("Release");
+        let mut docs = new_task("Docs");
+        let snapshot = build.clone();
+        let today = Utc::now().date_naive();
+
+        assert!(matches!(
+            build.add_blocker(&project, &build.clone(), &[], owner_id.clone()),
+            Err(TaskError::SelfDependencyError(_))
+        ));
+        let added = build.add_blocker(&project, &design, &[], owner_id.clone()).unwrap();
+        release.add_blocker(&project, &build, &[], owner_id.clone()).unwrap();
+        assert!(matches!(
+            build.add_blocker(&project, &design, &[], owner_id.clone()),
+            Err(TaskError::DependencyAlreadyExistsError(_))
+        ));
+
+        let tasks = vec![release.clone(), build.clone(), design.clone(), docs.clone()];
+        assert!(matches!(
+            TaskDependencyService::ensure_no_cycle(&tasks, design.id(), release.id()),
+            Err(TaskError::CyclicDependencyError(_, _))
+        ));
+        assert!(TaskDependencyService::ensure_no_cycle(&tasks, docs.id(), release.id()).is_ok());
+        // 循環する先行タスクは追加せず、タスクも変更しない
+        let design_seq_nr = design.seq_nr();
+        assert!(matches!(
+            design.add_blocker(&project, &release, &tasks, owner_id.clone()),
+            Err(TaskError::CyclicDependencyError(_, _))
+        ));
+        assert!(design.blocker_ids().is_empty());
+        assert_eq!(design.seq_nr(), design_seq_nr);
+        assert_eq!(
+            TaskDependencyService::topological_order(&tasks).unwrap(),
+            vec![design.id().clone(), docs.id().clone(), build.id().clone(), release.id().clone()]
+        );
+
+        // 予定期間の合計が最も長い連なりがクリティカルパスになる
+        docs.schedule(
+            &project,
+            Period::new(today, today + Days::new(4)).unwrap(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let tasks = vec![release.clone(), build.clone(), design.clone(), docs.clone()];
+        assert_eq!(
+            TaskDependencyService::critical_path(&tasks).unwrap(),
+            vec![docs.id().clone()]
+        );
+        build
+            .schedule(
+                &project,
+                Period::new(today, today + Days::new(6)).unwrap(),
+                owner_id.clone(),
+            )
+            .unwrap();
+        let tasks = vec![release.clone(), build.clone(), design.clone(), docs.clone()];
+        assert_eq!(
+            TaskDependencyService::critical_path(&tasks).unwrap(),
+            vec![design.id().clone(), build.id().clone(), release.id().clone()]
+        );
+
+        // 先行タスクが完了するまで完了にできない
+        build
+            .change_status(&project, TaskStatus::InProgress, &[], owner_id.clone())
+            .unwrap();
+        build
+            .change_status(&project, TaskStatus::InReview, &[], owner_id.clone())
+            .unwrap();
+        assert!(matches!(
+            build.change_status(
+                &project,
+                TaskStatus::Done,
+                &[design.clone()],
+                owner_id.clone()
+            ),
+            Err(TaskError::OpenBlockersError(_))
+        ));
+        // 見つからない先行タスクは完了の妨げとして扱う
+        assert!(matches!(
+            build.change_status(&project, TaskStatus::Done, &[], owner_id.clone()),
+            Err(TaskError::BlockerNotFoundError(blocker_id)) if &blocker_id == design.id()
+        ));
+        assert_eq!(build.status(), TaskStatus::InReview);
+        design
+            .change_status(&project, TaskStatus::InProgress, &[], owner_id.clone())
+            .unwrap();
+        design
+            .change_status(&project, TaskStatus::InReview, &[], owner_id.clone())
+            .unwrap();
+        design.change_status(&project, TaskStatus::Done, &[], owner_id.clone()).unwrap();
+        build
+            .change_status(
+                &project,
+                TaskStatus::Done,
+                &[design.clone()],
+                owner_id.clone(),
+            )
+            .unwrap();
+
+        let removed = build.remove_blocker(&project, design.id().clone(), owner_id.clone()).unwrap();
+        assert!(matches!(
+            build.remove_blocker(&project, design.id().clone(), owner_id),
+            Err(TaskError::DependencyNotFoundError(_))
+        ));
+        let replayed = Task::replay(&[added.clone()], snapshot.clone());
+        assert_eq!(replayed.blocker_ids(), &[design.id().clone()]);
+        let replayed = Task::replay(&[added, removed], snapshot);
+        assert!(replayed.blocker_ids().is_empty());
+    }
+
+    #[test]
+    fn test_update_and_replay() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = task.clone();
+
+        assert!(matches!(
+            task.change_status(&project, TaskStatus::Todo, &[], owner_id.clone()),
+            Err(TaskError::SameStatusError(TaskStatus::Todo))
+        ));
+
+        let events = vec![
+            task.rename(
+                &project,
+                TaskTitle::new("Write API docs").unwrap(),
+                owner_id.clone(),
+            )
+            .unwrap(),
+            task.change_priority(&project, Priority::Low, owner_id.clone()).unwrap(),
+            task.change_status(&project, TaskStatus::InProgress, &[], owner_id.clone())
+                .unwrap(),
+            task.delete(&project, owner_id.clone()).unwrap(),
+        ];
+        assert!(matches!(
+            task.rename(&project, TaskTitle::new("Write docs").unwrap(), owner_id),
+            Err(TaskError::AlreadyDeletedError(_))
+        ));
+
+        let replayed = Task::replay(&events, snapshot);
+        assert_eq!(replayed.title(), task.title());
+        assert_eq!(replayed.priority(), Priority::Low);
+        assert_eq!(replayed.status(), TaskStatus::InProgress);
+        assert!(replayed.is_deleted());
+        assert_eq!(replayed.seq_nr(), task.seq_nr());
+    }
+
+    fn create_iteration(project: &Project, owner_id: &UserId, start_day: u32, end_day: u32) -> Iteration {
+        let (iteration, _) = Iteration::new(
+            project,
+            crate::iteration::IterationName::new("Sprint").unwrap(),
+            Description::default(),
+            Period::new(
+                NaiveDate::from_ymd_opt(2025, 4, start_day).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 4, end_day).unwrap(),
+            )
+            .unwrap(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        iteration
+    }
+
+    #[test]
+    fn test_move_and_carry_over() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let other_project = create_project(&owner_id);
+        let mut current = create_iteration(&project, &owner_id, 1, 14);
+        let next = create_iteration(&project, &owner_id, 15, 28);
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let snapshot = task.clone();
+
+        assert!(matches!(
+            task.move_to_backlog(&project, owner_id.clone()),
+            Err(TaskError::AlreadyInBacklogError(_))
+        ));
+        assert!(matches!(
+            task.move_to_iteration(
+                &project,
+                &create_iteration(&other_project, &owner_id, 1, 14),
+                owner_id.clone()
+            ),
+            Err(TaskError::MismatchedIterationError(_, _))
+        ));
+        let moved = task.move_to_iteration(&project, &current, owner_id.clone()).unwrap();
+        assert_eq!(task.iteration_id(), Some(current.id()));
+        assert!(matches!(
+            task.move_to_iteration(&project, &current, owner_id.clone()),
+            Err(TaskError::AlreadyInIterationError(_))
+        ));
+
+        // 完了していないイテレーションからは引き継がない
+        assert!(
+            task.carry_over(&project, &current, Some(&next), owner_id.clone())
+                .unwrap()
+                .is_none()
+        );
+        current.start(&project, owner_id.clone()).unwrap();
+        current.complete(&project, owner_id.clone()).unwrap();
+        assert!(matches!(
+            task.move_to_iteration(&project, &current, owner_id.clone()),
+            Err(TaskError::IterationAlreadyCompletedError(_))
+        ));
+
+        let carried = task
+            .carry_over(&project, &current, Some(&next), owner_id.clone())
+            .unwrap()
+            .unwrap();
+        assert!(matches!(carried, TaskEvent::TaskMovedToIteration(_)));
+        assert_eq!(task.iteration_id(), Some(next.id()));
+        // 引き継ぎ済みのタスクは対象外となるため、再実行しても何もしない
+        assert!(
+            task.carry_over(&project, &current, Some(&next), owner_id.clone())
+                .unwrap()
+                .is_none()
+        );
+
+        let backlog = task.move_to_backlog(&project, owner_id.clone()).unwrap();
+        assert!(task.iteration_id().is_none());
+
+        let replayed = Task::replay(&[moved, carried, backlog], snapshot);
+        assert!(replayed.iteration_id().is_none());
+        assert_eq!(replayed.seq_nr(), task.seq_nr());
+    }
+
+    #[test]
+    fn test_carry_over_to_backlog() {
+        let owner_id = UserId::default();
+        let project = create_project(&owner_id);
+        let mut current = create_iteration(&project, &owner_id, 1, 14);
+        let mut open_task = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap()
+        .0;
+        let mut done_task = open_task.clone();
+        done_task.id = TaskId::new();
+        open_task.move_to_iteration(&project, &current, owner_id.clone()).unwrap();
+        done_task.move_to_iteration(&project, &current, owner_id.clone()).unwrap();
+        done_task
+            .change_status(&project, TaskStatus::InProgress, &[], owner_id.clone())
+            .unwrap();
+        done_task
+            .change_status(&project, TaskStatus::InReview, &[], owner_id.clone())
+            .unwrap();
+        done_task
+            .change_status(&project, TaskStatus::Done, &[], owner_id.clone())
+            .unwrap();
+        current.start(&project, owner_id.clone()).unwrap();
+        current.complete(&project, owner_id.clone()).unwrap();
+
+        let event = open_task.carry_over(&project, &current, None, owner_id.clone()).unwrap();
+        assert!(matches!(event, Some(TaskEvent::TaskMovedToBacklog(_))));
+        assert!(open_task.iteration_id().is_none());
+
+        // 完了済みのタスクは完了したイテレーションに残す
+        assert!(done_task.carry_over(&project, &current, None, owner_id).unwrap().is_none());
+        assert_eq!(done_task.iteration_id(), Some(current.id()));
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::task::{CommentBody, CommentId};
+use crate::user::UserId;
+
+/// タスクに付くコメント
+///
+/// 編集前の本文はイベントストリームに残るため、エンティティは最新の本文のみを持つ。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    id: CommentId,
+    author_id: UserId,
+    body: CommentBody,
+    created_at: DateTime<Utc>,
+    edited_at: Option<DateTime<Utc>>,
+}
+
+impl Comment {
+    pub fn new(id: CommentId, author_id: UserId, body: CommentBody, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            author_id,
+            body,
+            created_at,
+            edited_at: None,
+        }
+    }
+
+    /// [CommentId]の参照を返す
+    pub fn id(&self) -> &CommentId {
+        &self.id
+    }
+
+    /// 投稿者の[UserId]の参照を返す
+    pub fn author_id(&self) -> &UserId {
+        &self.author_id
+    }
+
+    /// [CommentBody]の参照を返す
+    pub fn body(&self) -> &CommentBody {
+        &self.body
+    }
+
+    /// 投稿日時を返す
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    /// 最後に編集した日時を返す
+    pub fn edited_at(&self) -> Option<&DateTime<Utc>> {
+        self.edited_at.as_ref()
+    }
+
+    /// 投稿者かどうかを判定する
+    pub fn is_written_by(&self, user_id: &UserId) -> bool {
+        &self.author_id == user_id
+    }
+
+    pub(crate) fn edit(&mut self, body: CommentBody, edited_at: DateTime<Utc>) {
+        self.body = body;
+        self.edited_at = Some(edited_at);
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentBody(String);
+
+#[derive(Error, Debug, Clone)]
+pub enum CommentBodyError {
+    #[error("the comment body is empty")]
+    Empty,
+    #[error("the comment body is too long")]
+    TooLong,
+}
+
+impl CommentBody {
+    pub fn new(body: &str) -> Result<Self, CommentBodyError> {
+        if body.is_empty() {
+            Err(CommentBodyError::Empty)
+        } else if body.len() > 2000 {
+            Err(CommentBodyError::TooLong)
+        } else {
+            Ok(Self(body.to_string()))
+        }
+    }
+}
+
+impl FromStr for CommentBody {
+    type Err = CommentBodyError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for CommentBody {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
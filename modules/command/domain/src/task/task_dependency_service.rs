@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use event_store_adapter_rs::types::Aggregate;
+
+use crate::task::{Task, TaskError, TaskId};
+
+/// 同じプロジェクトに属するタスク間の依存関係を扱うドメインサービス
+///
+/// 依存関係は後続タスクが先行タスクのIDを持つことで表す。
+pub struct TaskDependencyService;
+
+impl TaskDependencyService {
+    /// 先行タスクを追加しても依存関係が循環しないことを検証する
+    ///
+    /// # 引数
+    /// - tasks: 同じプロジェクトに属するタスク
+    /// - task_id: 後続タスクのID
+    /// - blocker_id: 追加する先行タスクのID
+    ///
+    /// # 戻り値
+    /// - 先行タスクが後続タスクに (間接的にでも) 依存している場合はエラーを返す。
+    pub fn ensure_no_cycle(tasks: &[Task], task_id: &TaskId, blocker_id: &TaskId) -> Result<(), TaskError> {
+        let tasks_by_id: HashMap<&TaskId, &Task> = tasks.iter().map(|task| (task.id(), task)).collect();
+        let mut visited = HashSet::new();
+        let mut stack = vec![blocker_id];
+        while let Some(current) = stack.pop() {
+            if current == task_id {
+                return Err(TaskError::CyclicDependencyError(
+                    task_id.clone(),
+                    blocker_id.clone(),
+                ));
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(task) = tasks_by_id.get(current) {
+                stack.extend(task.blocker_ids());
+            }
+        }
+        Ok(())
+    }
+
+    /// 先行タスクがすべて完了していることを検証する
+    ///
+    /// # 引数
+    /// - task: 完了にしようとしているタスク
+    /// - blockers: 先行タスク
+    ///
+    /// # 戻り値
+    /// - 見つからない先行タスクがある場合はエラーを返す。
+    /// - 完了していない先行タスクがある場合はエラーを返す。
+    pub fn ensure_no_open_blockers(task: &Task, blockers: &[Task]) -> Result<(), TaskError> {
+        let blockers_by_id: HashMap<&TaskId, &Task> = blockers.iter().map(|blocker| (blocker.id(), blocker)).collect();
+        let mut open_blocker_ids = Vec::new();
+        for blocker_id in task.blocker_ids() {
+            match blockers_by_id.get(blocker_id) {
+                None => return Err(TaskError::BlockerNotFoundError(blocker_id.clone())),
+                Some(blocker) if blocker.is_open() => open_blocker_ids.push(blocker_id.clone()),
+                Some(_) => {},
+            }
+        }
+        if open_blocker_ids.is_empty() {
+            Ok(())
+        } else {
+            Err(TaskError::OpenBlockersError(open_blocker_ids))
+        }
+    }
+
+    /// 先行タスクが必ず前に来るようにタスクを並べる
+    ///
+    /// 削除済みのタスクは含めない。依存関係のないタスク同士は引数の順序を保つ。
+    ///
+    /// # 引数
+    /// - tasks: 同じプロジェクトに属するタスク
+    ///
+    /// # 戻り値
+    /// - 依存関係が循環している場合はエラーを返す。
+    /// - 成功した場合は、並べたタスクのIDを返す。
+    pub fn topological_order(tasks: &[Task]) -> Result<Vec<TaskId>, TaskError> {
+        let tasks: Vec<&Task> = tasks.iter().filter(|task| !task.is_deleted()).collect();
+        Ok(Self::sort(&tasks)?.into_iter().map(|index| tasks[index].id().clone()).collect())
+    }
+
+    /// 未完了のタスクのクリティカルパスを求める
+    ///
+    /// 各タスクの所要日数は予定期間の日数とし、予定期間がない場合は1日とみなす。
+    /// 完了済みおよび削除済みのタスクは含めない。
+    ///
+    /// # 引数
+    /// - tasks: 同じプロジェクトに属するタスク
+    ///
+    /// # 戻り値
+    /// - 依存関係が循環している場合はエラーを返す。
+    /// - 成功した場合は、所要日数の合計が最も長い依存関係の連なりを、先行タスクから順に返す。
+    pub fn critical_path(tasks: &[Task]) -> Result<Vec<TaskId>, TaskError> {
+        let tasks: Vec<&Task> = tasks.iter().filter(|task| task.is_open()).collect();
+        let indices: HashMap<&TaskId, usize> =
+            tasks.iter().enumerate().map(|(index, task)| (task.id(), index)).collect();
+
+        let mut total_days = vec![0; tasks.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; tasks.len()];
+        for index in Self::sort(&tasks)? {
+            let task = tasks[index];
+            let longest_blocker = task
+                .blocker_ids()
+                .iter()
+                .filter_map(|blocker_id| indices.get(blocker_id).copied())
+                .fold(None, |longest: Option<usize>, blocker| match longest {
+                    Some(current) if total_days[current] >= total_days[blocker] => Some(current),
+                    _ => Some(blocker),
+                });
+            let days = task.planned_period().map_or(1, |period| period.duration().num_days());
+            total_days[index] = days + longest_blocker.map_or(0, |blocker| total_days[blocker]);
+            previous[index] = longest_blocker;
+        }
+
+        let mut path = Vec::new();
+        let mut current = (0..tasks.len()).fold(None, |longest: Option<usize>, index| match longest {
+            Some(current) if total_days[current] >= total_days[index] => Some(current),
+            _ => Some(index),
+        });
+        while let Some(index) = current {
+            path.push(tasks[index].id().clone());
+            current = previous[index];
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    /// 先行タスクが前に来るように並べたインデックスを返す
+    ///
+    /// 引数に含まれない先行タスクへの依存は無視する。
+    fn sort(tasks: &[&Task]) -> Result<Vec<usize>, TaskError> {
+        let indices: HashMap<&TaskId, usize> =
+            tasks.iter().enumerate().map(|(index, task)| (task.id(), index)).collect();
+        let mut in_degrees = vec![0; tasks.len()];
+        let mut dependents = vec![Vec::new(); tasks.len()];
+        for (index, task) in tasks.iter().enumerate() {
+            for blocker in task.blocker_ids().iter().filter_map(|blocker_id| indices.get(blocker_id)) {
+                in_degrees[index] += 1;
+                dependents[*blocker].push(index);
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..tasks.len()).filter(|index| in_degrees[*index] == 0).collect();
+        let mut order = Vec::with_capacity(tasks.len());
+        while let Some(index) = queue.pop_front() {
+            order.push(index);
+            for dependent in &dependents[index] {
+                in_degrees[*dependent] -= 1;
+                if in_degrees[*dependent] == 0 {
+                    queue.push_back(*dependent);
+                }
+            }
+        }
+
+        // 並べられなかったタスクは、同じく並べられなかった先行タスクを必ず持つ
+        let cyclic = tasks
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| in_degrees[*index] > 0)
+            .find_map(|(_, task)| {
+                task.blocker_ids()
+                    .iter()
+                    .find(|blocker_id| indices.get(blocker_id).is_some_and(|blocker| in_degrees[*blocker] > 0))
+                    .map(|blocker_id| (task, blocker_id))
+            });
+        if let Some((task, blocker_id)) = cyclic {
+            return Err(TaskError::CyclicDependencyError(
+                task.id().clone(),
+                blocker_id.clone(),
+            ));
+        }
+        Ok(order)
+    }
+}
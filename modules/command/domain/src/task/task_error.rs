@@ -0,0 +1,76 @@
+use crate::iteration::IterationId;
+use crate::project::{MemberId, Permission, ProjectId, TagId};
+use crate::task::{CommentId, TaskId, TaskStatus};
+use crate::user::UserId;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TaskError {
+    #[error("The project is deleted: {0:?}")]
+    ProjectAlreadyDeletedError(ProjectId),
+
+    #[error("The task does not belong to the project: {0:?}, {1:?}")]
+    MismatchedProjectError(TaskId, ProjectId),
+
+    #[error("The {0} does not have the {1} permission on the project: {2:?}")]
+    PermissionDeniedError(String, Permission, UserId),
+
+    #[error("The task is already deleted: {0:?}")]
+    AlreadyDeletedError(TaskId),
+
+    #[error("The task is already in the status: {0}")]
+    SameStatusError(TaskStatus),
+
+    #[error("The task status cannot be changed from {0} to {1}, allowed targets: {2:?}")]
+    IllegalStatusTransitionError(TaskStatus, TaskStatus, Vec<TaskStatus>),
+
+    #[error("The {0} is not a member of the project: {1:?}")]
+    NotProjectMemberError(String, MemberId),
+
+    #[error("The task is not assigned: {0:?}")]
+    NotAssignedError(TaskId),
+
+    #[error("The comment is not found: {0}")]
+    CommentNotFoundError(CommentId),
+
+    #[error("The user is not allowed to modify the comment: {0}, {1:?}")]
+    NotCommentAuthorError(CommentId, UserId),
+
+    #[error("The tag is not in the tag catalog of the project: {0}")]
+    UnknownTagError(TagId),
+
+    #[error("The task is already tagged with the tag: {0}")]
+    AlreadyTaggedError(TagId),
+
+    #[error("The task is not tagged with the tag: {0}")]
+    NotTaggedError(TagId),
+
+    #[error("The task cannot depend on itself: {0:?}")]
+    SelfDependencyError(TaskId),
+
+    #[error("The task already depends on the blocker: {0:?}")]
+    DependencyAlreadyExistsError(TaskId),
+
+    #[error("The task does not depend on the blocker: {0:?}")]
+    DependencyNotFoundError(TaskId),
+
+    #[error("The dependency creates a cycle: {0:?} is blocked by {1:?}")]
+    CyclicDependencyError(TaskId, TaskId),
+
+    #[error("The task has open blockers: {0:?}")]
+    OpenBlockersError(Vec<TaskId>),
+    #[error("The blocker is not found: {0:?}")]
+    BlockerNotFoundError(TaskId),
+
+    #[error("The iteration does not belong to the project: {0:?}, {1:?}")]
+    MismatchedIterationError(IterationId, ProjectId),
+
+    #[error("The iteration is already completed: {0:?}")]
+    IterationAlreadyCompletedError(IterationId),
+
+    #[error("The task is already in the iteration: {0:?}")]
+    AlreadyInIterationError(IterationId),
+
+    #[error("The task is already in the backlog: {0:?}")]
+    AlreadyInBacklogError(TaskId),
+}
@@ -0,0 +1,743 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use event_store_adapter_rs::types::Event;
+use serde::{Deserialize, Serialize};
+use ulid_generator_rs::ULID;
+
+use crate::description::Description;
+use crate::helper::id_generate;
+use crate::iteration::IterationId;
+use crate::period::Period;
+use crate::project::{MemberId, ProjectId, TagId};
+use crate::task::{CommentBody, CommentId, Priority, TaskId, TaskStatus, TaskTitle};
+use crate::user::UserId;
+
+pub type TaskEventId = ULID;
+
+/// タスクに関するイベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TaskEvent {
+    /// タスクが作成された
+    TaskCreated(TaskEventCreatedBody),
+    /// タスクのタイトルが変更された
+    TaskRenamed(TaskEventRenamedBody),
+    /// タスクの優先度が変更された
+    TaskPriorityChanged(TaskEventPriorityChangedBody),
+    /// タスクの状態が変更された
+    TaskStatusChanged(TaskEventStatusChangedBody),
+    /// タスクが削除された
+    TaskDeleted(TaskEventDeletedBody),
+    /// タスクに担当者が割り当てられた
+    TaskAssigned(TaskEventAssignedBody),
+    /// タスクの担当者が外された
+    TaskUnassigned(TaskEventUnassignedBody),
+    /// タスクの予定期間が設定された
+    TaskScheduled(TaskEventScheduledBody),
+    /// タスクの作業が開始された
+    TaskStarted(TaskEventStartedBody),
+    /// タスクの作業が完了した
+    TaskFinished(TaskEventFinishedBody),
+    /// タスクにコメントが追加された
+    CommentAdded(TaskEventCommentAddedBody),
+    /// コメントが編集された
+    CommentEdited(TaskEventCommentEditedBody),
+    /// コメントが削除された
+    CommentDeleted(TaskEventCommentDeletedBody),
+    /// タスクにタグが付与された
+    TaskTagged(TaskEventTaggedBody),
+    /// タスクからタグが外された
+    TaskUntagged(TaskEventUntaggedBody),
+    /// タスクの先行タスクが追加された
+    TaskDependencyAdded(TaskEventDependencyAddedBody),
+    /// タスクの先行タスクが削除された
+    TaskDependencyRemoved(TaskEventDependencyRemovedBody),
+    /// タスクがイテレーションに移動された
+    TaskMovedToIteration(TaskEventMovedToIterationBody),
+    /// タスクがバックログに戻された
+    TaskMovedToBacklog(TaskEventMovedToBacklogBody),
+}
+
+impl Event for TaskEvent {
+    type AggregateID = TaskId;
+    type ID = TaskEventId;
+
+    fn id(&self) -> &TaskEventId {
+        match self {
+            TaskEvent::TaskCreated(event) => &event.id,
+            TaskEvent::TaskRenamed(event) => &event.id,
+            TaskEvent::TaskPriorityChanged(event) => &event.id,
+            TaskEvent::TaskStatusChanged(event) => &event.id,
+            TaskEvent::TaskDeleted(event) => &event.id,
+            TaskEvent::TaskAssigned(event) => &event.id,
+            TaskEvent::TaskUnassigned(event) => &event.id,
+            TaskEvent::TaskScheduled(event) => &event.id,
+            TaskEvent::TaskStarted(event) => &event.id,
+            TaskEvent::TaskFinished(event) => &event.id,
+            TaskEvent::CommentAdded(event) => &event.id,
+            TaskEvent::CommentEdited(event) => &event.id,
+            TaskEvent::CommentDeleted(event) => &event.id,
+            TaskEvent::TaskTagged(event) => &event.id,
+            TaskEvent::TaskUntagged(event) => &event.id,
+            TaskEvent::TaskDependencyAdded(event) => &event.id,
+            TaskEvent::TaskDependencyRemoved(event) => &event.id,
+            TaskEvent::TaskMovedToIteration(event) => &event.id,
+            TaskEvent::TaskMovedToBacklog(event) => &event.id,
+        }
+    }
+
+    fn seq_nr(&self) -> usize {
+        match self {
+            TaskEvent::TaskCreated(event) => event.seq_nr,
+            TaskEvent::TaskRenamed(event) => event.seq_nr,
+            TaskEvent::TaskPriorityChanged(event) => event.seq_nr,
+            TaskEvent::TaskStatusChanged(event) => event.seq_nr,
+            TaskEvent::TaskDeleted(event) => event.seq_nr,
+            TaskEvent::TaskAssigned(event) => event.seq_nr,
+            TaskEvent::TaskUnassigned(event) => event.seq_nr,
+            TaskEvent::TaskScheduled(event) => event.seq_nr,
+            TaskEvent::TaskStarted(event) => event.seq_nr,
+            TaskEvent::TaskFinished(event) => event.seq_nr,
+            TaskEvent::CommentAdded(event) => event.seq_nr,
+            TaskEvent::CommentEdited(event) => event.seq_nr,
+            TaskEvent::CommentDeleted(event) => event.seq_nr,
+            TaskEvent::TaskTagged(event) => event.seq_nr,
+            TaskEvent::TaskUntagged(event) => event.seq_nr,
+            TaskEvent::TaskDependencyAdded(event) => event.seq_nr,
+            TaskEvent::TaskDependencyRemoved(event) => event.seq_nr,
+            TaskEvent::TaskMovedToIteration(event) => event.seq_nr,
+            TaskEvent::TaskMovedToBacklog(event) => event.seq_nr,
+        }
+    }
+
+    fn aggregate_id(&self) -> &TaskId {
+        match self {
+            TaskEvent::TaskCreated(event) => &event.aggregate_id,
+            TaskEvent::TaskRenamed(event) => &event.aggregate_id,
+            TaskEvent::TaskPriorityChanged(event) => &event.aggregate_id,
+            TaskEvent::TaskStatusChanged(event) => &event.aggregate_id,
+            TaskEvent::TaskDeleted(event) => &event.aggregate_id,
+            TaskEvent::TaskAssigned(event) => &event.aggregate_id,
+            TaskEvent::TaskUnassigned(event) => &event.aggregate_id,
+            TaskEvent::TaskScheduled(event) => &event.aggregate_id,
+            TaskEvent::TaskStarted(event) => &event.aggregate_id,
+            TaskEvent::TaskFinished(event) => &event.aggregate_id,
+            TaskEvent::CommentAdded(event) => &event.aggregate_id,
+            TaskEvent::CommentEdited(event) => &event.aggregate_id,
+            TaskEvent::CommentDeleted(event) => &event.aggregate_id,
+            TaskEvent::TaskTagged(event) => &event.aggregate_id,
+            TaskEvent::TaskUntagged(event) => &event.aggregate_id,
+            TaskEvent::TaskDependencyAdded(event) => &event.aggregate_id,
+            TaskEvent::TaskDependencyRemoved(event) => &event.aggregate_id,
+            TaskEvent::TaskMovedToIteration(event) => &event.aggregate_id,
+            TaskEvent::TaskMovedToBacklog(event) => &event.aggregate_id,
+        }
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        match self {
+            TaskEvent::TaskCreated(event) => &event.occurred_at,
+            TaskEvent::TaskRenamed(event) => &event.occurred_at,
+            TaskEvent::TaskPriorityChanged(event) => &event.occurred_at,
+            TaskEvent::TaskStatusChanged(event) => &event.occurred_at,
+            TaskEvent::TaskDeleted(event) => &event.occurred_at,
+            TaskEvent::TaskAssigned(event) => &event.occurred_at,
+            TaskEvent::TaskUnassigned(event) => &event.occurred_at,
+            TaskEvent::TaskScheduled(event) => &event.occurred_at,
+            TaskEvent::TaskStarted(event) => &event.occurred_at,
+            TaskEvent::TaskFinished(event) => &event.occurred_at,
+            TaskEvent::CommentAdded(event) => &event.occurred_at,
+            TaskEvent::CommentEdited(event) => &event.occurred_at,
+            TaskEvent::CommentDeleted(event) => &event.occurred_at,
+            TaskEvent::TaskTagged(event) => &event.occurred_at,
+            TaskEvent::TaskUntagged(event) => &event.occurred_at,
+            TaskEvent::TaskDependencyAdded(event) => &event.occurred_at,
+            TaskEvent::TaskDependencyRemoved(event) => &event.occurred_at,
+            TaskEvent::TaskMovedToIteration(event) => &event.occurred_at,
+            TaskEvent::TaskMovedToBacklog(event) => &event.occurred_at,
+        }
+    }
+
+    fn is_created(&self) -> bool {
+        matches!(self, TaskEvent::TaskCreated(_))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventCreatedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub project_id: ProjectId,
+    pub title: TaskTitle,
+    pub description: Description,
+    pub priority: Priority,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventCreatedBody {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        project_id: ProjectId,
+        title: TaskTitle,
+        description: Description,
+        priority: Priority,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            project_id,
+            title,
+            description,
+            priority,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventRenamedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub new_title: TaskTitle,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventRenamedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        new_title: TaskTitle,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_title,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventPriorityChangedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub new_priority: Priority,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventPriorityChangedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        new_priority: Priority,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_priority,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventStatusChangedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub new_status: TaskStatus,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventStatusChangedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        new_status: TaskStatus,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            new_status,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventDeletedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventDeletedBody {
+    pub fn new(aggregate_id: TaskId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventAssignedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub assignee_id: MemberId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventAssignedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        assignee_id: MemberId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            assignee_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventUnassignedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub assignee_id: MemberId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventUnassignedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        assignee_id: MemberId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            assignee_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventScheduledBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub planned_period: Period,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventScheduledBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        planned_period: Period,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            planned_period,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+/// 作業中への初回の遷移を表す。状態の変更を兼ねる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventStartedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub started_on: NaiveDate,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventStartedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        started_on: NaiveDate,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            started_on,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+/// 完了への遷移を表す。状態の変更を兼ねる。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventFinishedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub finished_on: NaiveDate,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventFinishedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        finished_on: NaiveDate,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            finished_on,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventCommentAddedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub comment_id: CommentId,
+    pub body: CommentBody,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventCommentAddedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        comment_id: CommentId,
+        body: CommentBody,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            comment_id,
+            body,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventCommentEditedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub comment_id: CommentId,
+    pub new_body: CommentBody,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventCommentEditedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        comment_id: CommentId,
+        new_body: CommentBody,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            comment_id,
+            new_body,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventCommentDeletedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub comment_id: CommentId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventCommentDeletedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        comment_id: CommentId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            comment_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventTaggedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub tag_id: TagId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventTaggedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        tag_id: TagId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            tag_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventUntaggedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub tag_id: TagId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventUntaggedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        tag_id: TagId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            tag_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventDependencyAddedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub blocker_id: TaskId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventDependencyAddedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        blocker_id: TaskId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            blocker_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventDependencyRemovedBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub blocker_id: TaskId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventDependencyRemovedBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        blocker_id: TaskId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            blocker_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventMovedToIterationBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub iteration_id: IterationId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventMovedToIterationBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        iteration_id: IterationId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            iteration_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskEventMovedToBacklogBody {
+    pub id: TaskEventId,
+    pub aggregate_id: TaskId,
+    pub seq_nr: usize,
+    pub from_iteration_id: IterationId,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TaskEventMovedToBacklogBody {
+    pub fn new(
+        aggregate_id: TaskId,
+        seq_nr: usize,
+        from_iteration_id: IterationId,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            from_iteration_id,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::helper::ParseError;
+
+/// タスクの状態
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TaskStatus {
+    /// 未着手
+    #[default]
+    Todo,
+    /// 作業中
+    InProgress,
+    /// レビュー中
+    InReview,
+    /// 完了
+    Done,
+}
+
+impl TaskStatus {
+    /// 完了しているかどうかを判定する
+    pub fn is_done(&self) -> bool {
+        matches!(self, Self::Done)
+    }
+}
+
+impl Display for TaskStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Todo => write!(f, "Todo"),
+            Self::InProgress => write!(f, "InProgress"),
+            Self::InReview => write!(f, "InReview"),
+            Self::Done => write!(f, "Done"),
+        }
+    }
+}
+
+impl FromStr for TaskStatus {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "todo" => Ok(Self::Todo),
+            "inprogress" => Ok(Self::InProgress),
+            "inreview" => Ok(Self::InReview),
+            "done" => Ok(Self::Done),
+            _ => Err(ParseError::InvalidTaskStatus(s.to_string())),
+        }
+    }
+}
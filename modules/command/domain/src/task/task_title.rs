@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskTitle(String);
+
+#[derive(Error, Debug, Clone)]
+pub enum TaskTitleError {
+    #[error("the task title is empty")]
+    Empty,
+    #[error("the task title is too long")]
+    TooLong,
+}
+
+impl TaskTitle {
+    pub fn new(title: &str) -> Result<Self, TaskTitleError> {
+        if title.is_empty() {
+            Err(TaskTitleError::Empty)
+        } else if title.len() > 200 {
+            Err(TaskTitleError::TooLong)
+        } else {
+            Ok(Self(title.to_string()))
+        }
+    }
+}
+
+impl FromStr for TaskTitle {
+    type Err = TaskTitleError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for TaskTitle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
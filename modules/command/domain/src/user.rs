@@ -1,18 +1,28 @@
+mod email_verification_token;
+mod pending_email_change;
 mod user_error;
 mod user_events;
 mod user_id;
 mod user_name;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use event_store_adapter_rs::types::Aggregate;
 use serde::{Deserialize, Serialize};
 
 use crate::email::Email;
+pub use crate::user::email_verification_token::EmailVerificationToken;
+pub use crate::user::pending_email_change::PendingEmailChange;
 pub use crate::user::user_error::UserError;
-use crate::user::user_events::{UserEvent, UserEventCreatedBody, UserEventDeletedBody};
+pub use crate::user::user_events::{
+    UserEvent, UserEventCreatedBody, UserEventDeletedBody, UserEventEmailChangeRequestedBody,
+    UserEventEmailChangedBody, UserEventId, UserEventRenamedBody,
+};
 pub use crate::user::user_id::UserId;
 pub use crate::user::user_name::UserName;
 
+/// メールアドレスの変更を確認するトークンの有効期間 (時間)
+pub const EMAIL_VERIFICATION_VALIDITY_HOURS: i64 = 24;
+
 /// ユーザ
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -20,6 +30,8 @@ pub struct User {
     deleted: bool,
     user_name: UserName,
     email: Email,
+    #[serde(default)]
+    pending_email_change: Option<PendingEmailChange>,
     seq_nr_counter: usize,
     version: usize,
     last_updated_at: DateTime<Utc>,
@@ -56,43 +68,416 @@ impl Aggregate for User {
 }
 
 impl User {
+    /// ユーザを作成する
+    ///
+    /// # 引数
+    /// - user_name: ユーザ名
+    /// - email: メールアドレス
+    ///
+    /// # 戻り値
+    /// - 作成したユーザと、UserCreatedイベントを返す。
     pub fn new(user_name: UserName, email: Email) -> (Self, UserEvent) {
-        let id = UserId::default();
-        Self::from(id, false, user_name, email, 0, 1)
+        let id = UserId::new();
+        let mut my_self = Self::from(id.clone(), false, user_name.clone(), email.clone(), 0, 1);
+        my_self.seq_nr_counter += 1;
+        let event = UserEvent::UserCreated(UserEventCreatedBody::new(
+            id,
+            my_self.seq_nr_counter,
+            user_name,
+            email,
+            my_self.last_updated_at,
+        ));
+        (my_self, event)
     }
 
-    pub fn delete(&mut self) -> Result<UserEvent, UserError> {
-        if self.deleted {
-            return Err(UserError::AlreadyDeletedError(self.id.clone()));
+    /// 保存された状態からユーザを復元する
+    ///
+    /// イベントは発行しない。
+    pub fn from(
+        id: UserId,
+        deleted: bool,
+        user_name: UserName,
+        email: Email,
+        seq_nr_counter: usize,
+        version: usize,
+    ) -> Self {
+        Self {
+            id,
+            deleted,
+            user_name,
+            email,
+            pending_email_change: None,
+            seq_nr_counter,
+            version,
+            last_updated_at: Utc::now(),
         }
+    }
+
+    fn apply_event(&mut self, event: &UserEvent) {
+        match event {
+            UserEvent::UserDeleted(body) => {
+                self.delete(body.executor_id.clone()).unwrap();
+            },
+            UserEvent::UserRenamed(body) => {
+                self.rename(body.user_name.clone(), body.executor_id.clone()).unwrap();
+            },
+            UserEvent::UserEmailChangeRequested(body) => {
+                self.request_email_change_with(
+                    body.email.clone(),
+                    body.token.clone(),
+                    body.expires_at,
+                    body.executor_id.clone(),
+                    body.occurred_at,
+                )
+                .unwrap();
+            },
+            UserEvent::UserEmailChanged(body) => {
+                let token = self
+                    .pending_email_change
+                    .as_ref()
+                    .map(|pending| pending.token().clone())
+                    .unwrap();
+                self.confirm_email_change(&token, body.executor_id.clone(), body.occurred_at)
+                    .unwrap();
+            },
+            UserEvent::UserCreated(_) => {},
+        }
+    }
+
+    pub fn replay(events: &[UserEvent], snapshot: User) -> Self {
+        log::debug!("event.size = {}", events.len());
+        events.iter().fold(snapshot, |mut result, event| {
+            log::debug!("Replaying snapshot: {:?}", result);
+            log::debug!("Replaying event: {:?}", event);
+            result.apply_event(event);
+            result
+        })
+    }
+
+    /// ユーザが削除されているかどうかを返す
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// [UserName]の参照を返す
+    pub fn user_name(&self) -> &UserName {
+        &self.user_name
+    }
+
+    /// [Email]の参照を返す
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+
+    /// 確認待ちの[PendingEmailChange]の参照を返す
+    pub fn pending_email_change(&self) -> Option<&PendingEmailChange> {
+        self.pending_email_change.as_ref()
+    }
+
+    /// ユーザを削除する
+    ///
+    /// # 引数
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - ユーザが削除されている場合はエラーを返す。
+    /// - 実行者がユーザ自身でない場合はエラーを返す。
+    /// - 成功した場合は、UserDeletedイベントを返す。
+    pub fn delete(&mut self, executor_id: UserId) -> Result<UserEvent, UserError> {
+        self.ensure_changeable(&executor_id)?;
         self.deleted = true;
         self.seq_nr_counter += 1;
         Ok(UserEvent::UserDeleted(UserEventDeletedBody::new(
             self.id.clone(),
+            self.seq_nr_counter,
+            executor_id,
             Utc::now(),
         )))
     }
 
-    pub fn from(
-        id: UserId,
-        deleted: bool,
-        user_name: UserName,
+    /// ユーザ名を変更する
+    ///
+    /// # 引数
+    /// - user_name: 新しいユーザ名
+    /// - executor_id: 実行者のユーザID
+    ///
+    /// # 戻り値
+    /// - ユーザが削除されている場合はエラーを返す。
+    /// - 実行者がユーザ自身でない場合はエラーを返す。
+    /// - 成功した場合は、UserRenamedイベントを返す。
+    pub fn rename(&mut self, user_name: UserName, executor_id: UserId) -> Result<UserEvent, UserError> {
+        self.ensure_changeable(&executor_id)?;
+        self.user_name = user_name.clone();
+        self.seq_nr_counter += 1;
+        Ok(UserEvent::UserRenamed(UserEventRenamedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            user_name,
+            executor_id,
+            Utc::now(),
+        )))
+    }
+
+    /// メールアドレスの変更を要求する
+    ///
+    /// 確認用のトークンを発行する。確認されるまでは現在のメールアドレスが有効なままとなる。
+    /// 確認待ちの変更がある場合は、新しい要求で置き換える。
+    ///
+    /// # 引数
+    /// - email: 新しいメールアドレス
+    /// - executor_id: 実行者のユーザID
+    /// - now: 現在日時
+    ///
+    /// # 戻り値
+    /// - ユーザが削除されている場合はエラーを返す。
+    /// - 実行者がユーザ自身でない場合はエラーを返す。
+    /// - 現在のメールアドレスと同じ場合はエラーを返す。
+    /// - 成功した場合は、UserEmailChangeRequestedイベントを返す。
+    pub fn request_email_change(
+        &mut self,
         email: Email,
-        seq_nr_counter: usize,
-        version: usize,
-    ) -> (Self, UserEvent) {
+        executor_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<UserEvent, UserError> {
+        let expires_at = now + Duration::hours(EMAIL_VERIFICATION_VALIDITY_HOURS);
+        self.request_email_change_with(
+            email,
+            EmailVerificationToken::new(),
+            expires_at,
+            executor_id,
+            now,
+        )
+    }
+
+    fn request_email_change_with(
+        &mut self,
+        email: Email,
+        token: EmailVerificationToken,
+        expires_at: DateTime<Utc>,
+        executor_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<UserEvent, UserError> {
+        self.ensure_changeable(&executor_id)?;
+        if self.email == email {
+            return Err(UserError::SameEmailError(self.id.clone(), email));
+        }
+        self.pending_email_change = Some(PendingEmailChange::new(
+            email.clone(),
+            token.clone(),
+            expires_at,
+        ));
+        self.seq_nr_counter += 1;
+        Ok(UserEvent::UserEmailChangeRequested(
+            UserEventEmailChangeRequestedBody::new(
+                self.id.clone(),
+                self.seq_nr_counter,
+                email,
+                token,
+                expires_at,
+                executor_id,
+                now,
+            ),
+        ))
+    }
+
+    /// 確認用のトークンを検証し、メールアドレスを変更する
+    ///
+    /// # 引数
+    /// - token: 変更の要求時に発行したトークン
+    /// - executor_id: 実行者のユーザID
+    /// - now: 現在日時
+    ///
+    /// # 戻り値
+    /// - ユーザが削除されている場合はエラーを返す。
+    /// - 実行者がユーザ自身でない場合はエラーを返す。
+    /// - 確認待ちの変更がない場合はエラーを返す。
+    /// - トークンが一致しない場合、または有効期限が切れている場合はエラーを返す。
+    /// - 成功した場合は、UserEmailChangedイベントを返す。
+    pub fn confirm_email_change(
+        &mut self,
+        token: &EmailVerificationToken,
+        executor_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<UserEvent, UserError> {
+        self.ensure_changeable(&executor_id)?;
+        let pending = self
+            .pending_email_change
+            .as_ref()
+            .ok_or_else(|| UserError::NoPendingEmailChangeError(self.id.clone()))?;
+        if pending.token() != token {
+            return Err(UserError::InvalidVerificationTokenError(self.id.clone()));
+        }
+        if pending.is_expired_at(&now) {
+            return Err(UserError::VerificationTokenExpiredError(
+                self.id.clone(),
+                *pending.expires_at(),
+            ));
+        }
+        let email = pending.email().clone();
+        self.email = email.clone();
+        self.pending_email_change = None;
+        self.seq_nr_counter += 1;
+        Ok(UserEvent::UserEmailChanged(UserEventEmailChangedBody::new(
+            self.id.clone(),
+            self.seq_nr_counter,
+            email,
+            executor_id,
+            now,
+        )))
+    }
+
+    /// 削除されておらず、実行者がユーザ自身であることを検証する
+    fn ensure_changeable(&self, executor_id: &UserId) -> Result<(), UserError> {
+        if self.deleted {
+            return Err(UserError::AlreadyDeletedError(self.id.clone()));
+        }
+        if executor_id != &self.id {
+            return Err(UserError::NotSelfError(
+                self.id.clone(),
+                executor_id.clone(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use event_store_adapter_rs::types::Event;
+
+    fn create_user() -> (User, UserEvent) {
+        User::new(
+            UserName::new("Alice").unwrap(),
+            Email::new("alice@example.com").unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_new_user() {
+        let (user, event) = create_user();
+        assert!(event.is_created());
+        assert_eq!(event.aggregate_id(), user.id());
+        assert_eq!(event.seq_nr(), user.seq_nr());
+        assert_eq!(user.seq_nr(), 1);
+
+        // 復元ではイベントを発行せず、シーケンス番号も進めない
+        let restored = User::from(
+            user.id().clone(),
+            false,
+            user.user_name().clone(),
+            user.email().clone(),
+            user.seq_nr(),
+            user.version(),
+        );
+        assert_eq!(restored.seq_nr(), user.seq_nr());
+    }
+
+    #[test]
+    fn test_delete_and_replay() {
+        let (mut user, _) = create_user();
+        let snapshot = user.clone();
+
+        assert!(matches!(
+            user.delete(UserId::new()),
+            Err(UserError::NotSelfError(_, _))
+        ));
+        let event = user.delete(user.id().clone()).unwrap();
+        assert_eq!(event.seq_nr(), 2);
+        assert!(matches!(
+            user.delete(user.id().clone()),
+            Err(UserError::AlreadyDeletedError(_))
+        ));
+
+        let replayed = User::replay(&[event], snapshot);
+        assert!(replayed.is_deleted());
+        assert_eq!(replayed.seq_nr(), user.seq_nr());
+    }
+
+    #[test]
+    fn test_rename() {
+        let (mut user, _) = create_user();
+        let snapshot = user.clone();
+
+        let new_name = UserName::new("Bob").unwrap();
+        assert!(matches!(
+            user.rename(new_name.clone(), UserId::new()),
+            Err(UserError::NotSelfError(_, _))
+        ));
+        let event = user.rename(new_name.clone(), user.id().clone()).unwrap();
+        assert_eq!(user.user_name(), &new_name);
+
+        let replayed = User::replay(&[event], snapshot);
+        assert_eq!(replayed.user_name(), &new_name);
+        assert_eq!(replayed.seq_nr(), user.seq_nr());
+    }
+
+    #[test]
+    fn test_email_change() {
+        let (mut user, _) = create_user();
+        let snapshot = user.clone();
+        let user_id = user.id().clone();
+        let old_email = user.email().clone();
+        let new_email = Email::new("alice@example.org").unwrap();
         let now = Utc::now();
-        (
-            Self {
-                id: id.clone(),
-                deleted,
-                user_name: user_name.clone(),
-                email: email.clone(),
-                seq_nr_counter,
-                version,
-                last_updated_at: now,
-            },
-            UserEvent::UserCreated(UserEventCreatedBody::new(id, user_name, email, now)),
+
+        assert!(matches!(
+            user.request_email_change(old_email.clone(), user_id.clone(), now),
+            Err(UserError::SameEmailError(_, _))
+        ));
+        assert!(matches!(
+            user.confirm_email_change(&EmailVerificationToken::new(), user_id.clone(), now),
+            Err(UserError::NoPendingEmailChangeError(_))
+        ));
+
+        let requested = user.request_email_change(new_email.clone(), user_id.clone(), now).unwrap();
+        // 確認されるまでは変更前のメールアドレスが有効
+        assert_eq!(user.email(), &old_email);
+        let token = user.pending_email_change().unwrap().token().clone();
+
+        assert!(matches!(
+            user.confirm_email_change(&EmailVerificationToken::new(), user_id.clone(), now),
+            Err(UserError::InvalidVerificationTokenError(_))
+        ));
+        let changed = user.confirm_email_change(&token, user_id.clone(), now).unwrap();
+        assert_eq!(user.email(), &new_email);
+        assert!(user.pending_email_change().is_none());
+
+        let replayed = User::replay(&[requested, changed], snapshot);
+        assert_eq!(replayed.email(), &new_email);
+        assert!(replayed.pending_email_change().is_none());
+        assert_eq!(replayed.seq_nr(), user.seq_nr());
+    }
+
+    #[test]
+    fn test_email_change_token_expires() {
+        let (mut user, _) = create_user();
+        let user_id = user.id().clone();
+        let now = Utc::now();
+
+        user.request_email_change(
+            Email::new("alice@example.org").unwrap(),
+            user_id.clone(),
+            now,
         )
+        .unwrap();
+        let token = user.pending_email_change().unwrap().token().clone();
+
+        let expired_at = now + Duration::hours(EMAIL_VERIFICATION_VALIDITY_HOURS);
+        assert!(matches!(
+            user.confirm_email_change(&token, user_id, expired_at),
+            Err(UserError::VerificationTokenExpiredError(_, _))
+        ));
+        assert_eq!(user.email().to_string(), "alice@example.com");
+    }
+
+    #[test]
+    fn test_event_to_json() {
+        let (_, event) = create_user();
+        let json = serde_json::to_string(&event).unwrap();
+        let deserialized: UserEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.id(), event.id());
+        assert_eq!(deserialized.seq_nr(), event.seq_nr());
     }
 }
@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::helper::{ParseError, id_generate};
+use serde::{Deserialize, Serialize};
+use ulid_generator_rs::ULID;
+
+/// メールアドレスの変更を確認するためのトークン
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EmailVerificationToken {
+    value: ULID,
+}
+
+impl EmailVerificationToken {
+    pub fn new() -> Self {
+        let value = id_generate();
+        Self { value }
+    }
+}
+
+impl Default for EmailVerificationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for EmailVerificationToken {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match ULID::from_str(s) {
+            Ok(value) => Ok(Self { value }),
+            Err(err) => Err(ParseError::InvalidULID(err)),
+        }
+    }
+}
+
+impl Display for EmailVerificationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::email::Email;
+use crate::user::EmailVerificationToken;
+
+/// 確認待ちのメールアドレスの変更
+///
+/// 確認されるまでは変更前のメールアドレスが有効なままとなる。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingEmailChange {
+    email: Email,
+    token: EmailVerificationToken,
+    expires_at: DateTime<Utc>,
+}
+
+impl PendingEmailChange {
+    pub fn new(email: Email, token: EmailVerificationToken, expires_at: DateTime<Utc>) -> Self {
+        Self { email, token, expires_at }
+    }
+
+    /// 変更後の[Email]の参照を返す
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+
+    /// [EmailVerificationToken]の参照を返す
+    pub fn token(&self) -> &EmailVerificationToken {
+        &self.token
+    }
+
+    /// トークンの有効期限の参照を返す
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    /// 指定した日時の時点で有効期限が切れているかどうかを判定する
+    pub fn is_expired_at(&self, now: &DateTime<Utc>) -> bool {
+        &self.expires_at <= now
+    }
+}
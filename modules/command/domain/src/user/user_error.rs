@@ -1,8 +1,21 @@
-use crate::user::UserId;
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
+use crate::email::Email;
+use crate::user::UserId;
+
 #[derive(Debug, Clone, Error)]
 pub enum UserError {
     #[error("The user is deleted: {0:?}")]
     AlreadyDeletedError(UserId),
+    #[error("The user {0:?} can only be changed by the user themselves: {1:?}")]
+    NotSelfError(UserId, UserId),
+    #[error("The email is already set to the user: {0:?}, {1}")]
+    SameEmailError(UserId, Email),
+    #[error("No email change is requested: {0:?}")]
+    NoPendingEmailChangeError(UserId),
+    #[error("The verification token is invalid: {0:?}")]
+    InvalidVerificationTokenError(UserId),
+    #[error("The verification token expired at {1}: {0:?}")]
+    VerificationTokenExpiredError(UserId, DateTime<Utc>),
 }
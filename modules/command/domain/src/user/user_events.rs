@@ -1,10 +1,17 @@
 use crate::email::Email;
+use crate::helper::id_generate;
+use crate::user::email_verification_token::EmailVerificationToken;
 use crate::user::user_id::UserId;
 use crate::user::user_name::UserName;
 
 use chrono::{DateTime, Utc};
+use event_store_adapter_rs::types::Event;
 use serde::{Deserialize, Serialize};
+use ulid_generator_rs::ULID;
 
+pub type UserEventId = ULID;
+
+/// ユーザに関するイベント
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum UserEvent {
@@ -12,11 +19,68 @@ pub enum UserEvent {
     UserCreated(UserEventCreatedBody),
     /// ユーザアカウントが削除された
     UserDeleted(UserEventDeletedBody),
+    /// ユーザ名が変更された
+    UserRenamed(UserEventRenamedBody),
+    /// メールアドレスの変更が要求された
+    UserEmailChangeRequested(UserEventEmailChangeRequestedBody),
+    /// メールアドレスの変更が確認された
+    UserEmailChanged(UserEventEmailChangedBody),
+}
+
+impl Event for UserEvent {
+    type AggregateID = UserId;
+    type ID = UserEventId;
+
+    fn id(&self) -> &UserEventId {
+        match self {
+            UserEvent::UserCreated(event) => &event.id,
+            UserEvent::UserDeleted(event) => &event.id,
+            UserEvent::UserRenamed(event) => &event.id,
+            UserEvent::UserEmailChangeRequested(event) => &event.id,
+            UserEvent::UserEmailChanged(event) => &event.id,
+        }
+    }
+
+    fn seq_nr(&self) -> usize {
+        match self {
+            UserEvent::UserCreated(event) => event.seq_nr,
+            UserEvent::UserDeleted(event) => event.seq_nr,
+            UserEvent::UserRenamed(event) => event.seq_nr,
+            UserEvent::UserEmailChangeRequested(event) => event.seq_nr,
+            UserEvent::UserEmailChanged(event) => event.seq_nr,
+        }
+    }
+
+    fn aggregate_id(&self) -> &UserId {
+        match self {
+            UserEvent::UserCreated(event) => &event.aggregate_id,
+            UserEvent::UserDeleted(event) => &event.aggregate_id,
+            UserEvent::UserRenamed(event) => &event.aggregate_id,
+            UserEvent::UserEmailChangeRequested(event) => &event.aggregate_id,
+            UserEvent::UserEmailChanged(event) => &event.aggregate_id,
+        }
+    }
+
+    fn occurred_at(&self) -> &DateTime<Utc> {
+        match self {
+            UserEvent::UserCreated(event) => &event.occurred_at,
+            UserEvent::UserDeleted(event) => &event.occurred_at,
+            UserEvent::UserRenamed(event) => &event.occurred_at,
+            UserEvent::UserEmailChangeRequested(event) => &event.occurred_at,
+            UserEvent::UserEmailChanged(event) => &event.occurred_at,
+        }
+    }
+
+    fn is_created(&self) -> bool {
+        matches!(self, UserEvent::UserCreated(_))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserEventCreatedBody {
+    pub id: UserEventId,
     pub aggregate_id: UserId,
+    pub seq_nr: usize,
     pub user_name: UserName,
     pub email: Email,
     pub occurred_at: DateTime<Utc>,
@@ -25,12 +89,16 @@ pub struct UserEventCreatedBody {
 impl UserEventCreatedBody {
     pub fn new(
         aggregate_id: UserId,
+        seq_nr: usize,
         user_name: UserName,
         email: Email,
         occurred_at: DateTime<Utc>,
     ) -> UserEventCreatedBody {
+        let id = id_generate();
         Self {
+            id,
             aggregate_id,
+            seq_nr,
             user_name,
             email,
             occurred_at,
@@ -40,12 +108,118 @@ impl UserEventCreatedBody {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserEventDeletedBody {
+    pub id: UserEventId,
     pub aggregate_id: UserId,
+    pub seq_nr: usize,
+    pub executor_id: UserId,
     pub occurred_at: DateTime<Utc>,
 }
 
 impl UserEventDeletedBody {
-    pub fn new(aggregate_id: UserId, occurred_at: DateTime<Utc>) -> UserEventDeletedBody {
-        Self { aggregate_id, occurred_at }
+    pub fn new(aggregate_id: UserId, seq_nr: usize, executor_id: UserId, occurred_at: DateTime<Utc>) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEventRenamedBody {
+    pub id: UserEventId,
+    pub aggregate_id: UserId,
+    pub seq_nr: usize,
+    pub user_name: UserName,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl UserEventRenamedBody {
+    pub fn new(
+        aggregate_id: UserId,
+        seq_nr: usize,
+        user_name: UserName,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            user_name,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEventEmailChangeRequestedBody {
+    pub id: UserEventId,
+    pub aggregate_id: UserId,
+    pub seq_nr: usize,
+    pub email: Email,
+    pub token: EmailVerificationToken,
+    pub expires_at: DateTime<Utc>,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl UserEventEmailChangeRequestedBody {
+    pub fn new(
+        aggregate_id: UserId,
+        seq_nr: usize,
+        email: Email,
+        token: EmailVerificationToken,
+        expires_at: DateTime<Utc>,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            email,
+            token,
+            expires_at,
+            executor_id,
+            occurred_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEventEmailChangedBody {
+    pub id: UserEventId,
+    pub aggregate_id: UserId,
+    pub seq_nr: usize,
+    pub email: Email,
+    pub executor_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl UserEventEmailChangedBody {
+    pub fn new(
+        aggregate_id: UserId,
+        seq_nr: usize,
+        email: Email,
+        executor_id: UserId,
+        occurred_at: DateTime<Utc>,
+    ) -> Self {
+        let id = id_generate();
+        Self {
+            id,
+            aggregate_id,
+            seq_nr,
+            email,
+            executor_id,
+            occurred_at,
+        }
     }
 }
@@ -0,0 +1,39 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use command_domain::iteration::IterationId;
+use command_domain::project::ProjectId;
+
+#[derive(Debug, Error)]
+pub enum IterationIndexError {
+    #[error("Failed to add the iteration to the index: {0:?}, {1:?}")]
+    AddError(ProjectId, IterationId, anyhow::Error),
+    #[error("Failed to find the iterations by the project id: {0:?}")]
+    FindByProjectIdError(ProjectId, anyhow::Error),
+}
+
+/// プロジェクトに属するイテレーションを引くための索引。
+#[async_trait::async_trait]
+pub trait IterationIndex: Debug + Clone + Sync + Send + 'static {
+    /// イテレーションを索引に追加する。
+    ///
+    /// 既に追加されている場合は成功として扱う。
+    ///
+    /// # 引数
+    /// - `project_id` - イテレーションが所属するプロジェクトのID
+    /// - `iteration_id` - 追加するイテレーションのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn add(&mut self, project_id: &ProjectId, iteration_id: &IterationId) -> Result<(), IterationIndexError>;
+
+    /// プロジェクトに属するイテレーションのIDを取得する。
+    ///
+    /// # 引数
+    /// - `project_id` - プロジェクトのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はイテレーションのIDの一覧, 失敗した場合はErrを返す。
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<IterationId>, IterationIndexError>;
+}
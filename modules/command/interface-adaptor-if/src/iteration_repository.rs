@@ -0,0 +1,37 @@
+use std::fmt::Debug;
+
+use event_store_adapter_rs::types::{EventStoreReadError, EventStoreWriteError};
+use thiserror::Error;
+
+use command_domain::iteration::*;
+
+#[derive(Debug, Error)]
+pub enum IterationRepositoryError {
+    #[error("Failed to store the iteration: {0:?}")]
+    StoreError(Box<Iteration>, EventStoreWriteError),
+    #[error("Failed to find the iteration by id: {0:?}")]
+    FindByIdError(IterationId, EventStoreReadError),
+}
+
+/// イテレーションのリポジトリ。
+#[async_trait::async_trait]
+pub trait IterationRepository: Debug + Clone + Sync + Send + 'static {
+    /// イテレーションのイベント及びスナップを保存する。
+    ///
+    /// # 引数
+    /// - `event` - イテレーションのイベント
+    /// - `snapshot` - イテレーションのスナップショット
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn store(&mut self, event: &IterationEvent, snapshot: &Iteration) -> Result<(), IterationRepositoryError>;
+
+    /// 指定したイテレーションIDに該当するイテレーションを取得する。
+    ///
+    /// # 引数
+    /// - `id` - イテレーションID
+    ///
+    /// # 戻り値
+    /// - 取得できた場合はOk(Iteration), 取得できなかった場合はErrを返す。
+    async fn find_by_id(&self, id: &IterationId) -> Result<Option<Iteration>, IterationRepositoryError>;
+}
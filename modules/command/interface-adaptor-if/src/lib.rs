@@ -1,2 +1,20 @@
+pub mod iteration_index;
+pub mod iteration_repository;
+pub mod mailer;
+pub mod project_invitation_repository;
+pub mod project_name_reservation;
 pub mod project_repository;
+pub mod task_index;
+pub mod task_repository;
+pub mod user_email_reservation;
+pub mod user_repository;
+pub use iteration_index::*;
+pub use iteration_repository::*;
+pub use mailer::*;
+pub use project_invitation_repository::*;
+pub use project_name_reservation::*;
 pub use project_repository::*;
+pub use task_index::*;
+pub use task_repository::*;
+pub use user_email_reservation::*;
+pub use user_repository::*;
@@ -0,0 +1,33 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use command_domain::email::Email;
+use command_domain::user::EmailVerificationToken;
+
+#[derive(Debug, Error)]
+pub enum MailerError {
+    #[error("Failed to send the mail to {0}")]
+    SendError(Email, anyhow::Error),
+}
+
+/// 通知メールを送信するポート。
+#[async_trait::async_trait]
+pub trait Mailer: Debug + Clone + Sync + Send + 'static {
+    /// メールアドレスの変更を確認するためのトークンを送信する。
+    ///
+    /// # 引数
+    /// - `email` - 送信先の (変更後の) メールアドレス
+    /// - `token` - 確認用のトークン
+    /// - `expires_at` - トークンの有効期限
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn send_email_verification(
+        &mut self,
+        email: &Email,
+        token: &EmailVerificationToken,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<(), MailerError>;
+}
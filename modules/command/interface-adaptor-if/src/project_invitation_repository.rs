@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+
+use event_store_adapter_rs::types::{EventStoreReadError, EventStoreWriteError};
+use thiserror::Error;
+
+use command_domain::project_invitation::*;
+
+#[derive(Debug, Error)]
+pub enum ProjectInvitationRepositoryError {
+    #[error("Failed to store the project invitation: {0:?}")]
+    StoreError(Box<ProjectInvitation>, EventStoreWriteError),
+    #[error("Failed to find the project invitation by id: {0:?}")]
+    FindByIdError(ProjectInvitationId, EventStoreReadError),
+}
+
+/// プロジェクトへの招待のリポジトリ。
+#[async_trait::async_trait]
+pub trait ProjectInvitationRepository: Debug + Clone + Sync + Send + 'static {
+    /// 招待のイベント及びスナップを保存する。
+    ///
+    /// # 引数
+    /// - `event` - 招待のイベント
+    /// - `snapshot` - 招待のスナップショット
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn store(
+        &mut self,
+        event: &ProjectInvitationEvent,
+        snapshot: &ProjectInvitation,
+    ) -> Result<(), ProjectInvitationRepositoryError>;
+
+    /// 指定した招待IDに該当する招待を取得する。
+    ///
+    /// # 引数
+    /// - `id` - 招待ID
+    ///
+    /// # 戻り値
+    /// - 取得できた場合はOk(ProjectInvitation), 取得できなかった場合はErrを返す。
+    async fn find_by_id(
+        &self,
+        id: &ProjectInvitationId,
+    ) -> Result<Option<ProjectInvitation>, ProjectInvitationRepositoryError>;
+}
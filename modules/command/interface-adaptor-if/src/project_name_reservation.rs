@@ -0,0 +1,43 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use command_domain::project::*;
+
+#[derive(Debug, Error)]
+pub enum ProjectNameReservationError {
+    #[error("The project name is already reserved by another project: {0:?}, {1:?}")]
+    AlreadyReservedError(ProjectName, ProjectId),
+    #[error("Failed to reserve the project name: {0:?}")]
+    ReserveError(ProjectName, anyhow::Error),
+    #[error("Failed to release the project name: {0:?}")]
+    ReleaseError(ProjectName, anyhow::Error),
+}
+
+/// プロジェクト名の一意性を保証するための予約。
+#[async_trait::async_trait]
+pub trait ProjectNameReservation: Debug + Clone + Sync + Send + 'static {
+    /// プロジェクト名を予約する。
+    ///
+    /// 同じプロジェクトが既に予約している場合は成功として扱う。
+    ///
+    /// # 引数
+    /// - `name` - 予約するプロジェクト名
+    /// - `project_id` - 予約するプロジェクトのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 他のプロジェクトが予約済みの場合や失敗した場合はErrを返す。
+    async fn reserve(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError>;
+
+    /// プロジェクト名の予約を解放する。
+    ///
+    /// 指定したプロジェクトが予約していない場合は何もしない。
+    ///
+    /// # 引数
+    /// - `name` - 解放するプロジェクト名
+    /// - `project_id` - 予約しているプロジェクトのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn release(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError>;
+}
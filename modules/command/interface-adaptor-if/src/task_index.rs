@@ -0,0 +1,39 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use command_domain::project::ProjectId;
+use command_domain::task::TaskId;
+
+#[derive(Debug, Error)]
+pub enum TaskIndexError {
+    #[error("Failed to add the task to the index: {0:?}, {1:?}")]
+    AddError(ProjectId, TaskId, anyhow::Error),
+    #[error("Failed to find the tasks by the project id: {0:?}")]
+    FindByProjectIdError(ProjectId, anyhow::Error),
+}
+
+/// プロジェクトに属するタスクを引くための索引。
+#[async_trait::async_trait]
+pub trait TaskIndex: Debug + Clone + Sync + Send + 'static {
+    /// タスクを索引に追加する。
+    ///
+    /// 既に追加されている場合は成功として扱う。
+    ///
+    /// # 引数
+    /// - `project_id` - タスクが所属するプロジェクトのID
+    /// - `task_id` - 追加するタスクのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn add(&mut self, project_id: &ProjectId, task_id: &TaskId) -> Result<(), TaskIndexError>;
+
+    /// プロジェクトに属するタスクのIDを取得する。
+    ///
+    /// # 引数
+    /// - `project_id` - プロジェクトのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はタスクのIDの一覧, 失敗した場合はErrを返す。
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<TaskId>, TaskIndexError>;
+}
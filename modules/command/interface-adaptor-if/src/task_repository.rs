@@ -0,0 +1,37 @@
+use std::fmt::Debug;
+
+use event_store_adapter_rs::types::{EventStoreReadError, EventStoreWriteError};
+use thiserror::Error;
+
+use command_domain::task::*;
+
+#[derive(Debug, Error)]
+pub enum TaskRepositoryError {
+    #[error("Failed to store the task: {0:?}")]
+    StoreError(Box<Task>, EventStoreWriteError),
+    #[error("Failed to find the task by id: {0:?}")]
+    FindByIdError(TaskId, EventStoreReadError),
+}
+
+/// タスクのリポジトリ。
+#[async_trait::async_trait]
+pub trait TaskRepository: Debug + Clone + Sync + Send + 'static {
+    /// タスクのイベント及びスナップを保存する。
+    ///
+    /// # 引数
+    /// - `event` - タスクのイベント
+    /// - `snapshot` - タスクのスナップショット
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn store(&mut self, event: &TaskEvent, snapshot: &Task) -> Result<(), TaskRepositoryError>;
+
+    /// 指定したタスクIDに該当するタスクを取得する。
+    ///
+    /// # 引数
+    /// - `id` - タスクID
+    ///
+    /// # 戻り値
+    /// - 取得できた場合はOk(Task), 取得できなかった場合はErrを返す。
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<Task>, TaskRepositoryError>;
+}
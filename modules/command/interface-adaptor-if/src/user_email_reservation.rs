@@ -0,0 +1,57 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use command_domain::email::Email;
+use command_domain::user::UserId;
+
+#[derive(Debug, Error)]
+pub enum UserEmailReservationError {
+    #[error("The email is already reserved by another user: {0}, {1:?}")]
+    AlreadyReservedError(Email, UserId),
+    #[error("Failed to reserve the email: {0}")]
+    ReserveError(Email, anyhow::Error),
+    #[error("Failed to release the email: {0}")]
+    ReleaseError(Email, anyhow::Error),
+    #[error("Failed to find the email reservation: {0}")]
+    FindError(Email, anyhow::Error),
+}
+
+/// ユーザのメールアドレスの一意性を保証するための予約。
+///
+/// メールアドレスは正規化した値で予約する。
+#[async_trait::async_trait]
+pub trait UserEmailReservation: Debug + Clone + Sync + Send + 'static {
+    /// メールアドレスを予約する。
+    ///
+    /// 同じユーザが既に予約している場合は成功として扱う。
+    ///
+    /// # 引数
+    /// - `email` - 予約するメールアドレス
+    /// - `user_id` - 予約するユーザのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 他のユーザが予約済みの場合や失敗した場合はErrを返す。
+    async fn reserve(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError>;
+
+    /// メールアドレスの予約を解放する。
+    ///
+    /// 指定したユーザが予約していない場合は何もしない。
+    ///
+    /// # 引数
+    /// - `email` - 解放するメールアドレス
+    /// - `user_id` - 予約しているユーザのID
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn release(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError>;
+
+    /// メールアドレスを予約しているユーザを取得する。
+    ///
+    /// # 引数
+    /// - `email` - 取得するメールアドレス
+    ///
+    /// # 戻り値
+    /// - 予約されている場合は予約しているユーザのID, 予約されていない場合はNone, 失敗した場合はErrを返す。
+    async fn find_reserved_by(&self, email: &Email) -> Result<Option<UserId>, UserEmailReservationError>;
+}
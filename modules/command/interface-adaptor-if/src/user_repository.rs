@@ -0,0 +1,37 @@
+use std::fmt::Debug;
+
+use event_store_adapter_rs::types::{EventStoreReadError, EventStoreWriteError};
+use thiserror::Error;
+
+use command_domain::user::*;
+
+#[derive(Debug, Error)]
+pub enum UserRepositoryError {
+    #[error("Failed to store the user: {0:?}")]
+    StoreError(Box<User>, EventStoreWriteError),
+    #[error("Failed to find the user by id: {0:?}")]
+    FindByIdError(UserId, EventStoreReadError),
+}
+
+/// ユーザのリポジトリ。
+#[async_trait::async_trait]
+pub trait UserRepository: Debug + Clone + Sync + Send + 'static {
+    /// ユーザのイベント及びスナップを保存する。
+    ///
+    /// # 引数
+    /// - `event` - ユーザのイベント
+    /// - `snapshot` - ユーザのスナップショット
+    ///
+    /// # 戻り値
+    /// - 成功した場合はOk, 失敗した場合はErrを返す。
+    async fn store(&mut self, event: &UserEvent, snapshot: &User) -> Result<(), UserRepositoryError>;
+
+    /// 指定したユーザIDに該当するユーザを取得する。
+    ///
+    /// # 引数
+    /// - `id` - ユーザID
+    ///
+    /// # 戻り値
+    /// - 取得できた場合はOk(User), 取得できなかった場合はErrを返す。
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, UserRepositoryError>;
+}
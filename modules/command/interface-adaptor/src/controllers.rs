@@ -4,10 +4,20 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::{Extension, Router, response};
+use chrono::Duration;
 
+use crate::gateways::iteration_index::AwsDynamoDbIterationIndex;
+use crate::gateways::iteration_repository::AwsDynamoDbIterationRepository;
+use crate::gateways::mailer::LoggingMailer;
+use crate::gateways::project_invitation_repository::AwsDynamoDbProjectInvitationRepository;
+use crate::gateways::project_name_reservation::AwsDynamoDbProjectNameReservation;
 use crate::gateways::project_repository::AwsDynamoDbProjectRepository;
+use crate::gateways::task_index::AwsDynamoDbTaskIndex;
+use crate::gateways::task_repository::AwsDynamoDbTaskRepository;
+use crate::gateways::user_email_reservation::AwsDynamoDbUserEmailReservation;
+use crate::gateways::user_repository::AwsDynamoDbUserRepository;
 
-use crate::graphql::{ApiSchema, ES, create_schema};
+use crate::graphql::{ApiSchema, ES, InvitationES, IterationES, TaskES, UserES, create_schema};
 
 pub(crate) mod extractor;
 
@@ -55,8 +65,33 @@ async fn graphql() -> impl IntoResponse {
     response::Html(GraphiQLSource::build().endpoint(EndpointPaths::GraphQL.as_str()).finish())
 }
 
-pub fn create_router(repository: AwsDynamoDbProjectRepository<ES>) -> Router {
-    let schema = create_schema(repository);
+#[allow(clippy::too_many_arguments)]
+pub fn create_router(
+    repository: AwsDynamoDbProjectRepository<ES>,
+    invitation_repository: AwsDynamoDbProjectInvitationRepository<InvitationES>,
+    project_name_reservation: AwsDynamoDbProjectNameReservation,
+    iteration_repository: AwsDynamoDbIterationRepository<IterationES>,
+    iteration_index: AwsDynamoDbIterationIndex,
+    task_repository: AwsDynamoDbTaskRepository<TaskES>,
+    task_index: AwsDynamoDbTaskIndex,
+    user_repository: AwsDynamoDbUserRepository<UserES>,
+    user_email_reservation: AwsDynamoDbUserEmailReservation,
+    mailer: LoggingMailer,
+    restore_grace_period: Duration,
+) -> Router {
+    let schema = create_schema(
+        repository,
+        invitation_repository,
+        project_name_reservation,
+        iteration_repository,
+        iteration_index,
+        task_repository,
+        task_index,
+        user_repository,
+        user_email_reservation,
+        mailer,
+        restore_grace_period,
+    );
     Router::new()
         .route(EndpointPaths::Root.as_str(), get(hello_write_api))
         .route(EndpointPaths::HealthAlive.as_str(), get(alive))
@@ -1 +1,33 @@
+use std::collections::VecDeque;
+
+use event_store_adapter_rs::types::{Aggregate, Event};
+
+pub mod iteration_index;
+pub mod iteration_repository;
+pub mod mailer;
+pub mod project_invitation_repository;
+pub mod project_name_reservation;
 pub mod project_repository;
+pub mod task_index;
+pub mod task_repository;
+pub mod user_email_reservation;
+pub mod user_repository;
+
+/// モックリポジトリで、スナップショットより後のイベントだけを適用して集約を復元する
+pub(crate) fn replay_after_snapshot<E: Event + Clone, A: Aggregate>(
+    events: Option<&VecDeque<E>>,
+    snapshot: Option<A>,
+    replay: fn(&[E], A) -> A,
+) -> Option<A> {
+    let snapshot = snapshot?;
+    let events = events
+        .map(|events| {
+            events
+                .iter()
+                .filter(|event| event.seq_nr() > snapshot.seq_nr())
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    Some(replay(&events, snapshot))
+}
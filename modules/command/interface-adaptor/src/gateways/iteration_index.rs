@@ -0,0 +1,143 @@
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::Client;
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use command_domain::iteration::IterationId;
+use command_domain::project::ProjectId;
+use command_interface_adaptor_if::{IterationIndex, IterationIndexError};
+
+const KEY_PREFIX: &str = "ProjectIterations";
+
+fn index_key(project_id: &ProjectId) -> String {
+    format!("{}#{}", KEY_PREFIX, project_id)
+}
+
+#[derive(Debug, Clone)]
+pub struct InMemoryIterationIndex {
+    iterations: HashMap<String, BTreeSet<String>>,
+}
+
+impl InMemoryIterationIndex {
+    pub fn new() -> Self {
+        Self { iterations: HashMap::new() }
+    }
+}
+
+impl Default for InMemoryIterationIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl IterationIndex for InMemoryIterationIndex {
+    async fn add(&mut self, project_id: &ProjectId, iteration_id: &IterationId) -> Result<(), IterationIndexError> {
+        self.iterations
+            .entry(index_key(project_id))
+            .or_default()
+            .insert(iteration_id.to_string());
+        Ok(())
+    }
+
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<IterationId>, IterationIndexError> {
+        self.iterations
+            .get(&index_key(project_id))
+            .into_iter()
+            .flatten()
+            .map(|value| {
+                IterationId::from_str(value)
+                    .map_err(|error| IterationIndexError::FindByProjectIdError(project_id.clone(), error.into()))
+            })
+            .collect()
+    }
+}
+
+/// プロジェクトごとに1つのアイテムへイテレーションIDの集合を保持する索引。
+///
+/// テーブルはパーティションキー `pkey` のみを持つ。
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbIterationIndex {
+    client: Client,
+    table_name: String,
+}
+
+impl AwsDynamoDbIterationIndex {
+    pub fn new(client: Client, table_name: String) -> Self {
+        Self { client, table_name }
+    }
+}
+
+#[async_trait::async_trait]
+impl IterationIndex for AwsDynamoDbIterationIndex {
+    async fn add(&mut self, project_id: &ProjectId, iteration_id: &IterationId) -> Result<(), IterationIndexError> {
+        self.client
+            .update_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(index_key(project_id)))
+            .update_expression("ADD iteration_ids :iteration_ids")
+            .expression_attribute_values(
+                ":iteration_ids",
+                AttributeValue::Ss(vec![iteration_id.to_string()]),
+            )
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|error| {
+                IterationIndexError::AddError(
+                    project_id.clone(),
+                    iteration_id.clone(),
+                    error.into_service_error().into(),
+                )
+            })
+    }
+
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<IterationId>, IterationIndexError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(index_key(project_id)))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|error| {
+                IterationIndexError::FindByProjectIdError(project_id.clone(), error.into_service_error().into())
+            })?;
+        output
+            .item()
+            .and_then(|item| item.get("iteration_ids"))
+            .and_then(|value| value.as_ss().ok())
+            .into_iter()
+            .flatten()
+            .map(|value| {
+                IterationId::from_str(value)
+                    .map_err(|error| IterationIndexError::FindByProjectIdError(project_id.clone(), error.into()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_index() {
+        let mut index = InMemoryIterationIndex::new();
+        let project_id = ProjectId::new();
+        let iteration_id = IterationId::new();
+
+        assert!(index.find_by_project_id(&project_id).await.unwrap().is_empty());
+
+        index.add(&project_id, &iteration_id).await.unwrap();
+        // 同じイテレーションの再追加は重複しない
+        index.add(&project_id, &iteration_id).await.unwrap();
+        assert_eq!(
+            index.find_by_project_id(&project_id).await.unwrap(),
+            vec![iteration_id]
+        );
+        assert!(index.find_by_project_id(&ProjectId::new()).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+use event_store_adapter_rs::types::{Aggregate, Event, EventStore};
+use std::collections::{HashMap, VecDeque};
+
+use crate::gateways::replay_after_snapshot;
+use command_domain::iteration::IterationEvent;
+use command_domain::iteration::{Iteration, IterationId};
+use command_interface_adaptor_if::{IterationRepository, IterationRepositoryError};
+
+#[derive(Debug, Clone)]
+pub struct MockIterationRepository {
+    events: HashMap<IterationId, VecDeque<IterationEvent>>,
+    snapshot: HashMap<IterationId, Option<Iteration>>,
+}
+
+impl MockIterationRepository {
+    pub fn new() -> Self {
+        Self {
+            events: HashMap::new(),
+            snapshot: HashMap::new(),
+        }
+    }
+}
+
+impl Default for MockIterationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl IterationRepository for MockIterationRepository {
+    async fn store(&mut self, event: &IterationEvent, snapshot: &Iteration) -> Result<(), IterationRepositoryError> {
+        self.events
+            .entry(event.aggregate_id().clone())
+            .or_default()
+            .push_back(event.clone());
+
+        *self
+            .snapshot
+            .entry(event.aggregate_id().clone())
+            .or_insert(Some(snapshot.clone())) = Some(snapshot.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &IterationId) -> Result<Option<Iteration>, IterationRepositoryError> {
+        Ok(replay_after_snapshot(
+            self.events.get(id),
+            self.snapshot.get(id).cloned().flatten(),
+            Iteration::replay,
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbIterationRepository<ES: EventStore<AID = IterationId, AG = Iteration, EV = IterationEvent>> {
+    event_store: ES,
+    snapshot_interval: usize,
+}
+
+unsafe impl<ES: EventStore<AID = IterationId, AG = Iteration, EV = IterationEvent>> Sync
+    for AwsDynamoDbIterationRepository<ES>
+{
+}
+
+unsafe impl<ES: EventStore<AID = IterationId, AG = Iteration, EV = IterationEvent>> Send
+    for AwsDynamoDbIterationRepository<ES>
+{
+}
+
+impl<ES: EventStore<AID = IterationId, AG = Iteration, EV = IterationEvent>> AwsDynamoDbIterationRepository<ES> {
+    pub fn new(event_store: ES, snapshot_interval: usize) -> Self {
+        Self { event_store, snapshot_interval }
+    }
+
+    /// スナップショットを永続化するかどうかを判定する。
+    ///
+    /// # 引数
+    /// - `snapshot_interval` - スナップショットを永続化する間隔
+    /// - `created` - イテレーションが作成されたかどうか
+    /// - `iteration` - イテレーション
+    ///
+    /// # 戻り値
+    /// スナップショットを永続化する場合は `Some` 、そうでない場合は `None` 。
+    fn resolve_snapshot(snapshot_interval: usize, created: bool, iteration: &Iteration) -> Option<&Iteration> {
+        if created || iteration.seq_nr() % snapshot_interval == 0 {
+            Some(iteration)
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ES: EventStore<AID = IterationId, AG = Iteration, EV = IterationEvent>> IterationRepository
+    for AwsDynamoDbIterationRepository<ES>
+{
+    async fn store(&mut self, event: &IterationEvent, snapshot: &Iteration) -> Result<(), IterationRepositoryError> {
+        let result = match Self::resolve_snapshot(self.snapshot_interval, event.is_created(), snapshot) {
+            Some(snapshot) => self.event_store.persist_event_and_snapshot(event, snapshot).await,
+            None => self.event_store.persist_event(event, snapshot.version()).await,
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(IterationRepositoryError::StoreError(
+                Box::new(snapshot.clone()),
+                error,
+            )),
+        }
+    }
+
+    async fn find_by_id(&self, id: &IterationId) -> Result<Option<Iteration>, IterationRepositoryError> {
+        let snapshot_opt = self.event_store.get_latest_snapshot_by_id(id).await;
+        match snapshot_opt {
+            Ok(None) => Ok(None),
+            Ok(Some(snapshot)) => {
+                let events = self.event_store.get_events_by_id_since_seq_nr(id, snapshot.seq_nr()).await;
+                match events {
+                    Ok(events) => {
+                        let result = Iteration::replay(&events, snapshot.clone());
+                        Ok(Some(result))
+                    },
+                    Err(error) => Err(IterationRepositoryError::FindByIdError(id.clone(), error)),
+                }
+            },
+            Err(error) => Err(IterationRepositoryError::FindByIdError(id.clone(), error)),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+
+use command_domain::email::Email;
+use command_domain::user::EmailVerificationToken;
+use command_interface_adaptor_if::{Mailer, MailerError};
+
+/// 送信したメールアドレスの確認メール
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentEmailVerification {
+    pub email: Email,
+    pub token: EmailVerificationToken,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 送信したメールをメモリ上に記録するメーラー
+///
+/// 複製したメーラー同士は記録を共有するため、コマンドプロセッサに渡した後も送信内容を確認できる。
+#[derive(Debug, Clone)]
+pub struct InMemoryMailer {
+    sent: Arc<Mutex<Vec<SentEmailVerification>>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self { sent: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// 送信したメールを送信順に返す
+    pub fn sent_email_verifications(&self) -> Vec<SentEmailVerification> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+impl Default for InMemoryMailer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send_email_verification(
+        &mut self,
+        email: &Email,
+        token: &EmailVerificationToken,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<(), MailerError> {
+        self.sent.lock().unwrap().push(SentEmailVerification {
+            email: email.clone(),
+            token: token.clone(),
+            expires_at: *expires_at,
+        });
+        Ok(())
+    }
+}
+
+/// 送信する代わりにログへ出力するメーラー
+///
+/// 配信サービスと連携するまでの仮実装。
+#[derive(Debug, Clone, Default)]
+pub struct LoggingMailer;
+
+#[async_trait::async_trait]
+impl Mailer for LoggingMailer {
+    async fn send_email_verification(
+        &mut self,
+        email: &Email,
+        token: &EmailVerificationToken,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<(), MailerError> {
+        log::info!(
+            "Email verification: email = {}, token = {}, expires_at = {}",
+            email,
+            token,
+            expires_at
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_mailer_shares_sent_mails() {
+        let mailer = InMemoryMailer::new();
+        let mut cloned = mailer.clone();
+        let email = Email::new("alice@example.com").unwrap();
+        let token = EmailVerificationToken::new();
+        let expires_at = Utc::now();
+
+        cloned.send_email_verification(&email, &token, &expires_at).await.unwrap();
+
+        assert_eq!(
+            mailer.sent_email_verifications(),
+            vec![SentEmailVerification { email, token, expires_at }]
+        );
+    }
+}
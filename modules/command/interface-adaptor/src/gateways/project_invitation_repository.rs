@@ -0,0 +1,156 @@
+use event_store_adapter_rs::types::{Aggregate, Event, EventStore};
+use std::collections::{HashMap, VecDeque};
+
+use crate::gateways::replay_after_snapshot;
+use command_domain::project_invitation::ProjectInvitationEvent;
+use command_domain::project_invitation::{ProjectInvitation, ProjectInvitationId};
+use command_interface_adaptor_if::{ProjectInvitationRepository, ProjectInvitationRepositoryError};
+
+#[derive(Debug, Clone)]
+pub struct MockProjectInvitationRepository {
+    events: HashMap<ProjectInvitationId, VecDeque<ProjectInvitationEvent>>,
+    snapshot: HashMap<ProjectInvitationId, Option<ProjectInvitation>>,
+}
+
+impl MockProjectInvitationRepository {
+    pub fn new() -> Self {
+        Self {
+            events: HashMap::new(),
+            snapshot: HashMap::new(),
+        }
+    }
+}
+
+impl Default for MockProjectInvitationRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectInvitationRepository for MockProjectInvitationRepository {
+    async fn store(
+        &mut self,
+        event: &ProjectInvitationEvent,
+        snapshot: &ProjectInvitation,
+    ) -> Result<(), ProjectInvitationRepositoryError> {
+        self.events
+            .entry(event.aggregate_id().clone())
+            .or_default()
+            .push_back(event.clone());
+
+        *self
+            .snapshot
+            .entry(event.aggregate_id().clone())
+            .or_insert(Some(snapshot.clone())) = Some(snapshot.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &ProjectInvitationId,
+    ) -> Result<Option<ProjectInvitation>, ProjectInvitationRepositoryError> {
+        Ok(replay_after_snapshot(
+            self.events.get(id),
+            self.snapshot.get(id).cloned().flatten(),
+            ProjectInvitation::replay,
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbProjectInvitationRepository<
+    ES: EventStore<AID = ProjectInvitationId, AG = ProjectInvitation, EV = ProjectInvitationEvent>,
+> {
+    event_store: ES,
+    snapshot_interval: usize,
+}
+
+unsafe impl<ES: EventStore<AID = ProjectInvitationId, AG = ProjectInvitation, EV = ProjectInvitationEvent>> Sync
+    for AwsDynamoDbProjectInvitationRepository<ES>
+{
+}
+
+unsafe impl<ES: EventStore<AID = ProjectInvitationId, AG = ProjectInvitation, EV = ProjectInvitationEvent>> Send
+    for AwsDynamoDbProjectInvitationRepository<ES>
+{
+}
+
+impl<ES: EventStore<AID = ProjectInvitationId, AG = ProjectInvitation, EV = ProjectInvitationEvent>>
+    AwsDynamoDbProjectInvitationRepository<ES>
+{
+    pub fn new(event_store: ES, snapshot_interval: usize) -> Self {
+        Self { event_store, snapshot_interval }
+    }
+
+    /// スナップショットを永続化するかどうかを判定する。
+    ///
+    /// # 引数
+    /// - `snapshot_interval` - スナップショットを永続化する間隔
+    /// - `created` - 招待が作成されたかどうか
+    /// - `invitation` - 招待
+    ///
+    /// # 戻り値
+    /// スナップショットを永続化する場合は `Some` 、そうでない場合は `None` 。
+    fn resolve_snapshot(
+        snapshot_interval: usize,
+        created: bool,
+        invitation: &ProjectInvitation,
+    ) -> Option<&ProjectInvitation> {
+        if created || invitation.seq_nr() % snapshot_interval == 0 {
+            Some(invitation)
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ES: EventStore<AID = ProjectInvitationId, AG = ProjectInvitation, EV = ProjectInvitationEvent>>
+    ProjectInvitationRepository for AwsDynamoDbProjectInvitationRepository<ES>
+{
+    async fn store(
+        &mut self,
+        event: &ProjectInvitationEvent,
+        snapshot: &ProjectInvitation,
+    ) -> Result<(), ProjectInvitationRepositoryError> {
+        let result = match Self::resolve_snapshot(self.snapshot_interval, event.is_created(), snapshot) {
+            Some(snapshot) => self.event_store.persist_event_and_snapshot(event, snapshot).await,
+            None => self.event_store.persist_event(event, snapshot.version()).await,
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(ProjectInvitationRepositoryError::StoreError(
+                Box::new(snapshot.clone()),
+                error,
+            )),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &ProjectInvitationId,
+    ) -> Result<Option<ProjectInvitation>, ProjectInvitationRepositoryError> {
+        let snapshot_opt = self.event_store.get_latest_snapshot_by_id(id).await;
+        match snapshot_opt {
+            Ok(None) => Ok(None),
+            Ok(Some(snapshot)) => {
+                let events = self.event_store.get_events_by_id_since_seq_nr(id, snapshot.seq_nr()).await;
+                match events {
+                    Ok(events) => {
+                        let result = ProjectInvitation::replay(&events, snapshot.clone());
+                        Ok(Some(result))
+                    },
+                    Err(error) => Err(ProjectInvitationRepositoryError::FindByIdError(
+                        id.clone(),
+                        error,
+                    )),
+                }
+            },
+            Err(error) => Err(ProjectInvitationRepositoryError::FindByIdError(
+                id.clone(),
+                error,
+            )),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::Client;
+use aws_sdk_dynamodb::operation::delete_item::DeleteItemError;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValuesOnConditionCheckFailure};
+
+use command_domain::project::{ProjectId, ProjectName};
+use command_interface_adaptor_if::{ProjectNameReservation, ProjectNameReservationError};
+
+const KEY_PREFIX: &str = "ProjectName";
+
+fn reservation_key(name: &ProjectName) -> String {
+    format!("{}#{}", KEY_PREFIX, name)
+}
+
+#[derive(Debug, Clone)]
+pub struct InMemoryProjectNameReservation {
+    reservations: HashMap<String, ProjectId>,
+}
+
+impl InMemoryProjectNameReservation {
+    pub fn new() -> Self {
+        Self { reservations: HashMap::new() }
+    }
+}
+
+impl Default for InMemoryProjectNameReservation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectNameReservation for InMemoryProjectNameReservation {
+    async fn reserve(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError> {
+        let key = reservation_key(name);
+        match self.reservations.get(&key) {
+            Some(reserved_by) if reserved_by != project_id => Err(ProjectNameReservationError::AlreadyReservedError(
+                name.clone(),
+                reserved_by.clone(),
+            )),
+            _ => {
+                self.reservations.insert(key, project_id.clone());
+                Ok(())
+            },
+        }
+    }
+
+    async fn release(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError> {
+        let key = reservation_key(name);
+        if self.reservations.get(&key) == Some(project_id) {
+            self.reservations.remove(&key);
+        }
+        Ok(())
+    }
+}
+
+/// 予約テーブルに対する条件付き書き込みでプロジェクト名を予約する。
+///
+/// 予約テーブルはパーティションキー `pkey` のみを持つ。
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbProjectNameReservation {
+    client: Client,
+    table_name: String,
+}
+
+impl AwsDynamoDbProjectNameReservation {
+    pub fn new(client: Client, table_name: String) -> Self {
+        Self { client, table_name }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectNameReservation for AwsDynamoDbProjectNameReservation {
+    async fn reserve(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError> {
+        let result = self
+            .client
+            .put_item()
+            .table_name(&self.table_name)
+            .item("pkey", AttributeValue::S(reservation_key(name)))
+            .item("project_id", AttributeValue::S(project_id.to_string()))
+            .condition_expression("attribute_not_exists(pkey) OR project_id = :project_id")
+            .expression_attribute_values(":project_id", AttributeValue::S(project_id.to_string()))
+            .return_values_on_condition_check_failure(ReturnValuesOnConditionCheckFailure::AllOld)
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => match error.into_service_error() {
+                PutItemError::ConditionalCheckFailedException(exception) => {
+                    let reserved_by = exception
+                        .item()
+                        .and_then(|item| item.get("project_id"))
+                        .and_then(|value| value.as_s().ok())
+                        .and_then(|value| ProjectId::from_str(value).ok())
+                        .ok_or_else(|| {
+                            ProjectNameReservationError::ReserveError(
+                                name.clone(),
+                                anyhow::anyhow!("The reservation item has no valid project_id"),
+                            )
+                        })?;
+                    Err(ProjectNameReservationError::AlreadyReservedError(
+                        name.clone(),
+                        reserved_by,
+                    ))
+                },
+                error => Err(ProjectNameReservationError::ReserveError(
+                    name.clone(),
+                    error.into(),
+                )),
+            },
+        }
+    }
+
+    async fn release(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError> {
+        let result = self
+            .client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(reservation_key(name)))
+            .condition_expression("project_id = :project_id")
+            .expression_attribute_values(":project_id", AttributeValue::S(project_id.to_string()))
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => match error.into_service_error() {
+                // 他のプロジェクトの予約、または予約が存在しない場合は何もしない
+                DeleteItemError::ConditionalCheckFailedException(_) => Ok(()),
+                error => Err(ProjectNameReservationError::ReleaseError(
+                    name.clone(),
+                    error.into(),
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_reservation() {
+        let mut reservation = InMemoryProjectNameReservation::new();
+        let name = ProjectName::new("Test").unwrap();
+        let project_id = ProjectId::new();
+        let other_project_id = ProjectId::new();
+
+        reservation.reserve(&name, &project_id).await.unwrap();
+        // 同じプロジェクトによる再予約は成功する
+        reservation.reserve(&name, &project_id).await.unwrap();
+        assert!(matches!(
+            reservation.reserve(&name, &other_project_id).await,
+            Err(ProjectNameReservationError::AlreadyReservedError(_, _))
+        ));
+
+        // 他のプロジェクトは予約を解放できない
+        reservation.release(&name, &other_project_id).await.unwrap();
+        assert!(reservation.reserve(&name, &other_project_id).await.is_err());
+
+        reservation.release(&name, &project_id).await.unwrap();
+        reservation.reserve(&name, &other_project_id).await.unwrap();
+    }
+}
@@ -1,6 +1,7 @@
 use event_store_adapter_rs::types::{Aggregate, Event, EventStore};
 use std::collections::{HashMap, VecDeque};
 
+use crate::gateways::replay_after_snapshot;
 use command_domain::project::ProjectEvent;
 use command_domain::project::{Project, ProjectId};
 use command_interface_adaptor_if::{ProjectRepository, ProjectRepositoryError};
@@ -42,14 +43,11 @@ impl ProjectRepository for MockProjectRepository {
     }
 
     async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, ProjectRepositoryError> {
-        let events = self.events.get(id).unwrap().iter().cloned().collect::<Vec<_>>();
-        let snapshot_opt = self.snapshot.get(id).unwrap().clone();
-        if let Some(snapshot) = snapshot_opt {
-            let result = Project::replay(&events, snapshot);
-            Ok(Some(result))
-        } else {
-            Ok(None)
-        }
+        Ok(replay_after_snapshot(
+            self.events.get(id),
+            self.snapshot.get(id).cloned().flatten(),
+            Project::replay,
+        ))
     }
 }
 
@@ -0,0 +1,137 @@
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::Client;
+use aws_sdk_dynamodb::types::AttributeValue;
+
+use command_domain::project::ProjectId;
+use command_domain::task::TaskId;
+use command_interface_adaptor_if::{TaskIndex, TaskIndexError};
+
+const KEY_PREFIX: &str = "ProjectTasks";
+
+fn index_key(project_id: &ProjectId) -> String {
+    format!("{}#{}", KEY_PREFIX, project_id)
+}
+
+#[derive(Debug, Clone)]
+pub struct InMemoryTaskIndex {
+    tasks: HashMap<String, BTreeSet<String>>,
+}
+
+impl InMemoryTaskIndex {
+    pub fn new() -> Self {
+        Self { tasks: HashMap::new() }
+    }
+}
+
+impl Default for InMemoryTaskIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskIndex for InMemoryTaskIndex {
+    async fn add(&mut self, project_id: &ProjectId, task_id: &TaskId) -> Result<(), TaskIndexError> {
+        self.tasks.entry(index_key(project_id)).or_default().insert(task_id.to_string());
+        Ok(())
+    }
+
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<TaskId>, TaskIndexError> {
+        self.tasks
+            .get(&index_key(project_id))
+            .into_iter()
+            .flatten()
+            .map(|value| {
+                TaskId::from_str(value)
+                    .map_err(|error| TaskIndexError::FindByProjectIdError(project_id.clone(), error.into()))
+            })
+            .collect()
+    }
+}
+
+/// プロジェクトごとに1つのアイテムへタスクIDの集合を保持する索引。
+///
+/// テーブルはパーティションキー `pkey` のみを持つ。
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbTaskIndex {
+    client: Client,
+    table_name: String,
+}
+
+impl AwsDynamoDbTaskIndex {
+    pub fn new(client: Client, table_name: String) -> Self {
+        Self { client, table_name }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskIndex for AwsDynamoDbTaskIndex {
+    async fn add(&mut self, project_id: &ProjectId, task_id: &TaskId) -> Result<(), TaskIndexError> {
+        self.client
+            .update_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(index_key(project_id)))
+            .update_expression("ADD task_ids :task_ids")
+            .expression_attribute_values(":task_ids", AttributeValue::Ss(vec![task_id.to_string()]))
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|error| {
+                TaskIndexError::AddError(
+                    project_id.clone(),
+                    task_id.clone(),
+                    error.into_service_error().into(),
+                )
+            })
+    }
+
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<TaskId>, TaskIndexError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(index_key(project_id)))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|error| {
+                TaskIndexError::FindByProjectIdError(project_id.clone(), error.into_service_error().into())
+            })?;
+        output
+            .item()
+            .and_then(|item| item.get("task_ids"))
+            .and_then(|value| value.as_ss().ok())
+            .into_iter()
+            .flatten()
+            .map(|value| {
+                TaskId::from_str(value)
+                    .map_err(|error| TaskIndexError::FindByProjectIdError(project_id.clone(), error.into()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_index() {
+        let mut index = InMemoryTaskIndex::new();
+        let project_id = ProjectId::new();
+        let task_id = TaskId::new();
+
+        assert!(index.find_by_project_id(&project_id).await.unwrap().is_empty());
+
+        index.add(&project_id, &task_id).await.unwrap();
+        // 同じタスクの再追加は重複しない
+        index.add(&project_id, &task_id).await.unwrap();
+        assert_eq!(
+            index.find_by_project_id(&project_id).await.unwrap(),
+            vec![task_id]
+        );
+        assert!(index.find_by_project_id(&ProjectId::new()).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use event_store_adapter_rs::types::{Aggregate, Event, EventStore};
+use std::collections::{HashMap, VecDeque};
+
+use crate::gateways::replay_after_snapshot;
+use command_domain::task::TaskEvent;
+use command_domain::task::{Task, TaskId};
+use command_interface_adaptor_if::{TaskRepository, TaskRepositoryError};
+
+#[derive(Debug, Clone)]
+pub struct MockTaskRepository {
+    events: HashMap<TaskId, VecDeque<TaskEvent>>,
+    snapshot: HashMap<TaskId, Option<Task>>,
+}
+
+impl MockTaskRepository {
+    pub fn new() -> Self {
+        Self {
+            events: HashMap::new(),
+            snapshot: HashMap::new(),
+        }
+    }
+}
+
+impl Default for MockTaskRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskRepository for MockTaskRepository {
+    async fn store(&mut self, event: &TaskEvent, snapshot: &Task) -> Result<(), TaskRepositoryError> {
+        self.events
+            .entry(event.aggregate_id().clone())
+            .or_default()
+            .push_back(event.clone());
+
+        *self
+            .snapshot
+            .entry(event.aggregate_id().clone())
+            .or_insert(Some(snapshot.clone())) = Some(snapshot.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<Task>, TaskRepositoryError> {
+        Ok(replay_after_snapshot(
+            self.events.get(id),
+            self.snapshot.get(id).cloned().flatten(),
+            Task::replay,
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbTaskRepository<ES: EventStore<AID = TaskId, AG = Task, EV = TaskEvent>> {
+    event_store: ES,
+    snapshot_interval: usize,
+}
+
+unsafe impl<ES: EventStore<AID = TaskId, AG = Task, EV = TaskEvent>> Sync for AwsDynamoDbTaskRepository<ES> {}
+
+unsafe impl<ES: EventStore<AID = TaskId, AG = Task, EV = TaskEvent>> Send for AwsDynamoDbTaskRepository<ES> {}
+
+impl<ES: EventStore<AID = TaskId, AG = Task, EV = TaskEvent>> AwsDynamoDbTaskRepository<ES> {
+    pub fn new(event_store: ES, snapshot_interval: usize) -> Self {
+        Self { event_store, snapshot_interval }
+    }
+
+    /// スナップショットを永続化するかどうかを判定する。
+    ///
+    /// # 引数
+    /// - `snapshot_interval` - スナップショットを永続化する間隔
+    /// - `created` - タスクが作成されたかどうか
+    /// - `task` - タスク
+    ///
+    /// # 戻り値
+    /// スナップショットを永続化する場合は `Some` 、そうでない場合は `None` 。
+    fn resolve_snapshot(snapshot_interval: usize, created: bool, task: &Task) -> Option<&Task> {
+        if created || task.seq_nr() % snapshot_interval == 0 {
+            Some(task)
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ES: EventStore<AID = TaskId, AG = Task, EV = TaskEvent>> TaskRepository for AwsDynamoDbTaskRepository<ES> {
+    async fn store(&mut self, event: &TaskEvent, snapshot: &Task) -> Result<(), TaskRepositoryError> {
+        let result = match Self::resolve_snapshot(self.snapshot_interval, event.is_created(), snapshot) {
+            Some(snapshot) => self.event_store.persist_event_and_snapshot(event, snapshot).await,
+            None => self.event_store.persist_event(event, snapshot.version()).await,
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(TaskRepositoryError::StoreError(
+                Box::new(snapshot.clone()),
+                error,
+            )),
+        }
+    }
+
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<Task>, TaskRepositoryError> {
+        let snapshot_opt = self.event_store.get_latest_snapshot_by_id(id).await;
+        match snapshot_opt {
+            Ok(None) => Ok(None),
+            Ok(Some(snapshot)) => {
+                let events = self.event_store.get_events_by_id_since_seq_nr(id, snapshot.seq_nr()).await;
+                match events {
+                    Ok(events) => {
+                        let result = Task::replay(&events, snapshot.clone());
+                        Ok(Some(result))
+                    },
+                    Err(error) => Err(TaskRepositoryError::FindByIdError(id.clone(), error)),
+                }
+            },
+            Err(error) => Err(TaskRepositoryError::FindByIdError(id.clone(), error)),
+        }
+    }
+}
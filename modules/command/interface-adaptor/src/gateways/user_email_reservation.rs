@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::Client;
+use aws_sdk_dynamodb::operation::delete_item::DeleteItemError;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValuesOnConditionCheckFailure};
+
+use command_domain::email::Email;
+use command_domain::user::UserId;
+use command_interface_adaptor_if::{UserEmailReservation, UserEmailReservationError};
+
+const KEY_PREFIX: &str = "UserEmail";
+
+fn reservation_key(email: &Email) -> String {
+    format!("{}#{}", KEY_PREFIX, email.normalized())
+}
+
+#[derive(Debug, Clone)]
+pub struct InMemoryUserEmailReservation {
+    reservations: HashMap<String, UserId>,
+}
+
+impl InMemoryUserEmailReservation {
+    pub fn new() -> Self {
+        Self { reservations: HashMap::new() }
+    }
+}
+
+impl Default for InMemoryUserEmailReservation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl UserEmailReservation for InMemoryUserEmailReservation {
+    async fn reserve(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError> {
+        let key = reservation_key(email);
+        match self.reservations.get(&key) {
+            Some(reserved_by) if reserved_by != user_id => Err(UserEmailReservationError::AlreadyReservedError(
+                email.clone(),
+                reserved_by.clone(),
+            )),
+            _ => {
+                self.reservations.insert(key, user_id.clone());
+                Ok(())
+            },
+        }
+    }
+
+    async fn release(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError> {
+        let key = reservation_key(email);
+        if self.reservations.get(&key) == Some(user_id) {
+            self.reservations.remove(&key);
+        }
+        Ok(())
+    }
+
+    async fn find_reserved_by(&self, email: &Email) -> Result<Option<UserId>, UserEmailReservationError> {
+        Ok(self.reservations.get(&reservation_key(email)).cloned())
+    }
+}
+
+/// 予約テーブルに対する条件付き書き込みでメールアドレスを予約する。
+///
+/// 予約テーブルはプロジェクト名の予約と共有し、キーの接頭辞で区別する。
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbUserEmailReservation {
+    client: Client,
+    table_name: String,
+}
+
+impl AwsDynamoDbUserEmailReservation {
+    pub fn new(client: Client, table_name: String) -> Self {
+        Self { client, table_name }
+    }
+}
+
+#[async_trait::async_trait]
+impl UserEmailReservation for AwsDynamoDbUserEmailReservation {
+    async fn reserve(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError> {
+        let result = self
+            .client
+            .put_item()
+            .table_name(&self.table_name)
+            .item("pkey", AttributeValue::S(reservation_key(email)))
+            .item("user_id", AttributeValue::S(user_id.to_string()))
+            .condition_expression("attribute_not_exists(pkey) OR user_id = :user_id")
+            .expression_attribute_values(":user_id", AttributeValue::S(user_id.to_string()))
+            .return_values_on_condition_check_failure(ReturnValuesOnConditionCheckFailure::AllOld)
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => match error.into_service_error() {
+                PutItemError::ConditionalCheckFailedException(exception) => {
+                    let reserved_by = exception
+                        .item()
+                        .and_then(|item| item.get("user_id"))
+                        .and_then(|value| value.as_s().ok())
+                        .and_then(|value| UserId::from_str(value).ok())
+                        .ok_or_else(|| {
+                            UserEmailReservationError::ReserveError(
+                                email.clone(),
+                                anyhow::anyhow!("The reservation item has no valid user_id"),
+                            )
+                        })?;
+                    Err(UserEmailReservationError::AlreadyReservedError(
+                        email.clone(),
+                        reserved_by,
+                    ))
+                },
+                error => Err(UserEmailReservationError::ReserveError(
+                    email.clone(),
+                    error.into(),
+                )),
+            },
+        }
+    }
+
+    async fn release(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError> {
+        let result = self
+            .client
+            .delete_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(reservation_key(email)))
+            .condition_expression("user_id = :user_id")
+            .expression_attribute_values(":user_id", AttributeValue::S(user_id.to_string()))
+            .send()
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => match error.into_service_error() {
+                // 他のユーザの予約、または予約が存在しない場合は何もしない
+                DeleteItemError::ConditionalCheckFailedException(_) => Ok(()),
+                error => Err(UserEmailReservationError::ReleaseError(
+                    email.clone(),
+                    error.into(),
+                )),
+            },
+        }
+    }
+
+    async fn find_reserved_by(&self, email: &Email) -> Result<Option<UserId>, UserEmailReservationError> {
+        let output = self
+            .client
+            .get_item()
+            .table_name(&self.table_name)
+            .key("pkey", AttributeValue::S(reservation_key(email)))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|error| UserEmailReservationError::FindError(email.clone(), error.into_service_error().into()))?;
+        match output.item() {
+            None => Ok(None),
+            Some(item) => item
+                .get("user_id")
+                .and_then(|value| value.as_s().ok())
+                .and_then(|value| UserId::from_str(value).ok())
+                .map(Some)
+                .ok_or_else(|| {
+                    UserEmailReservationError::FindError(
+                        email.clone(),
+                        anyhow::anyhow!("The reservation item has no valid user_id"),
+                    )
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_reservation() {
+        let mut reservation = InMemoryUserEmailReservation::new();
+        let email = Email::new("alice@example.com").unwrap();
+        let user_id = UserId::new();
+        let other_user_id = UserId::new();
+
+        reservation.reserve(&email, &user_id).await.unwrap();
+        // 同じユーザによる再予約は成功する
+        reservation.reserve(&email, &user_id).await.unwrap();
+        // 大文字と小文字の違いは同じメールアドレスとみなす
+        assert!(matches!(
+            reservation
+                .reserve(&Email::new("Alice@Example.com").unwrap(), &other_user_id)
+                .await,
+            Err(UserEmailReservationError::AlreadyReservedError(_, _))
+        ));
+
+        // 他のユーザは予約を解放できない
+        reservation.release(&email, &other_user_id).await.unwrap();
+        assert!(reservation.reserve(&email, &other_user_id).await.is_err());
+
+        reservation.release(&email, &user_id).await.unwrap();
+        assert_eq!(reservation.find_reserved_by(&email).await.unwrap(), None);
+        reservation.reserve(&email, &other_user_id).await.unwrap();
+        assert_eq!(
+            reservation
+                .find_reserved_by(&Email::new("ALICE@example.com").unwrap())
+                .await
+                .unwrap(),
+            Some(other_user_id)
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use event_store_adapter_rs::types::{Aggregate, Event, EventStore};
+use std::collections::{HashMap, VecDeque};
+
+use crate::gateways::replay_after_snapshot;
+use command_domain::user::UserEvent;
+use command_domain::user::{User, UserId};
+use command_interface_adaptor_if::{UserRepository, UserRepositoryError};
+
+#[derive(Debug, Clone)]
+pub struct MockUserRepository {
+    events: HashMap<UserId, VecDeque<UserEvent>>,
+    snapshot: HashMap<UserId, Option<User>>,
+}
+
+impl MockUserRepository {
+    pub fn new() -> Self {
+        Self {
+            events: HashMap::new(),
+            snapshot: HashMap::new(),
+        }
+    }
+}
+
+impl Default for MockUserRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for MockUserRepository {
+    async fn store(&mut self, event: &UserEvent, snapshot: &User) -> Result<(), UserRepositoryError> {
+        self.events
+            .entry(event.aggregate_id().clone())
+            .or_default()
+            .push_back(event.clone());
+
+        *self
+            .snapshot
+            .entry(event.aggregate_id().clone())
+            .or_insert(Some(snapshot.clone())) = Some(snapshot.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, UserRepositoryError> {
+        Ok(replay_after_snapshot(
+            self.events.get(id),
+            self.snapshot.get(id).cloned().flatten(),
+            User::replay,
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AwsDynamoDbUserRepository<ES: EventStore<AID = UserId, AG = User, EV = UserEvent>> {
+    event_store: ES,
+    snapshot_interval: usize,
+}
+
+unsafe impl<ES: EventStore<AID = UserId, AG = User, EV = UserEvent>> Sync for AwsDynamoDbUserRepository<ES> {}
+
+unsafe impl<ES: EventStore<AID = UserId, AG = User, EV = UserEvent>> Send for AwsDynamoDbUserRepository<ES> {}
+
+impl<ES: EventStore<AID = UserId, AG = User, EV = UserEvent>> AwsDynamoDbUserRepository<ES> {
+    pub fn new(event_store: ES, snapshot_interval: usize) -> Self {
+        Self { event_store, snapshot_interval }
+    }
+
+    /// スナップショットを永続化するかどうかを判定する。
+    ///
+    /// # 引数
+    /// - `snapshot_interval` - スナップショットを永続化する間隔
+    /// - `created` - ユーザが作成されたかどうか
+    /// - `user` - ユーザ
+    ///
+    /// # 戻り値
+    /// スナップショットを永続化する場合は `Some` 、そうでない場合は `None` 。
+    fn resolve_snapshot(snapshot_interval: usize, created: bool, user: &User) -> Option<&User> {
+        if created || user.seq_nr() % snapshot_interval == 0 {
+            Some(user)
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ES: EventStore<AID = UserId, AG = User, EV = UserEvent>> UserRepository for AwsDynamoDbUserRepository<ES> {
+    async fn store(&mut self, event: &UserEvent, snapshot: &User) -> Result<(), UserRepositoryError> {
+        let result = match Self::resolve_snapshot(self.snapshot_interval, event.is_created(), snapshot) {
+            Some(snapshot) => self.event_store.persist_event_and_snapshot(event, snapshot).await,
+            None => self.event_store.persist_event(event, snapshot.version()).await,
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(UserRepositoryError::StoreError(
+                Box::new(snapshot.clone()),
+                error,
+            )),
+        }
+    }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, UserRepositoryError> {
+        let snapshot_opt = self.event_store.get_latest_snapshot_by_id(id).await;
+        match snapshot_opt {
+            Ok(None) => Ok(None),
+            Ok(Some(snapshot)) => {
+                let events = self.event_store.get_events_by_id_since_seq_nr(id, snapshot.seq_nr()).await;
+                match events {
+                    Ok(events) => {
+                        let result = User::replay(&events, snapshot.clone());
+                        Ok(Some(result))
+                    },
+                    Err(error) => Err(UserRepositoryError::FindByIdError(id.clone(), error)),
+                }
+            },
+            Err(error) => Err(UserRepositoryError::FindByIdError(id.clone(), error)),
+        }
+    }
+}
@@ -1,52 +1,161 @@
 use std::sync::Arc;
 
-use async_graphql::{EmptySubscription, Object, Schema, SchemaBuilder};
+use async_graphql::{EmptySubscription, Schema, SchemaBuilder};
+use chrono::Duration;
 use event_store_adapter_rs::EventStoreForDynamoDB;
 use tokio::sync::Mutex;
 
+use command_domain::iteration::{Iteration, IterationEvent, IterationId};
 use command_domain::project::{Project, ProjectEvent, ProjectId};
-use command_interface_adaptor_if::ProjectRepository;
+use command_domain::project_invitation::{ProjectInvitation, ProjectInvitationEvent, ProjectInvitationId};
+use command_domain::task::{Task, TaskEvent, TaskId};
+use command_domain::user::{User, UserEvent, UserId};
+use command_interface_adaptor_if::{
+    IterationIndex, IterationRepository, Mailer, ProjectInvitationRepository, ProjectNameReservation,
+    ProjectRepository, TaskIndex, TaskRepository, UserEmailReservation, UserRepository,
+};
+use command_processor::iteration_command_processor::IterationCommandProcessor;
 use command_processor::project_command_processor::ProjectCommandProcessor;
+use command_processor::project_invitation_command_processor::ProjectInvitationCommandProcessor;
+use command_processor::task_command_processor::TaskCommandProcessor;
+use command_processor::user_command_processor::UserCommandProcessor;
 
+use crate::gateways::iteration_index::AwsDynamoDbIterationIndex;
+use crate::gateways::iteration_repository::AwsDynamoDbIterationRepository;
+use crate::gateways::mailer::LoggingMailer;
+use crate::gateways::project_invitation_repository::AwsDynamoDbProjectInvitationRepository;
+use crate::gateways::project_name_reservation::AwsDynamoDbProjectNameReservation;
 use crate::gateways::project_repository::AwsDynamoDbProjectRepository;
+use crate::gateways::task_index::AwsDynamoDbTaskIndex;
+use crate::gateways::task_repository::AwsDynamoDbTaskRepository;
+use crate::gateways::user_email_reservation::AwsDynamoDbUserEmailReservation;
+use crate::gateways::user_repository::AwsDynamoDbUserRepository;
 
 pub mod inputs;
 pub mod outputs;
 pub mod resolvers;
 
-pub struct ServiceContext<TR: ProjectRepository> {
-    project_command_processor: Arc<Mutex<ProjectCommandProcessor<TR>>>,
+pub struct ServiceContext<
+    TR: ProjectRepository,
+    IR: ProjectInvitationRepository,
+    NR: ProjectNameReservation,
+    ITR: IterationRepository,
+    IX: IterationIndex,
+    KR: TaskRepository,
+    KX: TaskIndex,
+    UR: UserRepository,
+    ER: UserEmailReservation,
+    M: Mailer,
+> {
+    project_command_processor: Arc<Mutex<ProjectCommandProcessor<TR, NR>>>,
+    project_invitation_command_processor: Arc<Mutex<ProjectInvitationCommandProcessor<TR, IR, UR>>>,
+    iteration_command_processor: Arc<Mutex<IterationCommandProcessor<TR, ITR, IX>>>,
+    task_command_processor: Arc<Mutex<TaskCommandProcessor<TR, ITR, KR, KX>>>,
+    user_command_processor: Arc<Mutex<UserCommandProcessor<UR, ER, M>>>,
 }
 
-impl<TR: ProjectRepository> ServiceContext<TR> {
-    pub fn new(project_command_processor: ProjectCommandProcessor<TR>) -> Self {
+impl<
+    TR: ProjectRepository,
+    IR: ProjectInvitationRepository,
+    NR: ProjectNameReservation,
+    ITR: IterationRepository,
+    IX: IterationIndex,
+    KR: TaskRepository,
+    KX: TaskIndex,
+    UR: UserRepository,
+    ER: UserEmailReservation,
+    M: Mailer,
+> ServiceContext<TR, IR, NR, ITR, IX, KR, KX, UR, ER, M>
+{
+    pub fn new(
+        project_command_processor: ProjectCommandProcessor<TR, NR>,
+        project_invitation_command_processor: ProjectInvitationCommandProcessor<TR, IR, UR>,
+        iteration_command_processor: IterationCommandProcessor<TR, ITR, IX>,
+        task_command_processor: TaskCommandProcessor<TR, ITR, KR, KX>,
+        user_command_processor: UserCommandProcessor<UR, ER, M>,
+    ) -> Self {
         Self {
             project_command_processor: Arc::new(Mutex::new(project_command_processor)),
+            project_invitation_command_processor: Arc::new(Mutex::new(project_invitation_command_processor)),
+            iteration_command_processor: Arc::new(Mutex::new(iteration_command_processor)),
+            task_command_processor: Arc::new(Mutex::new(task_command_processor)),
+            user_command_processor: Arc::new(Mutex::new(user_command_processor)),
         }
     }
 }
 
 pub struct QueryRoot;
 
-#[Object]
-impl QueryRoot {
-    async fn health_check(&self) -> String {
-        "OK".to_string()
-    }
-}
-
 pub struct MutationRoot;
 
 pub type ES = EventStoreForDynamoDB<ProjectId, Project, ProjectEvent>;
 
+pub type InvitationES = EventStoreForDynamoDB<ProjectInvitationId, ProjectInvitation, ProjectInvitationEvent>;
+
+pub type IterationES = EventStoreForDynamoDB<IterationId, Iteration, IterationEvent>;
+
+pub type TaskES = EventStoreForDynamoDB<TaskId, Task, TaskEvent>;
+
+pub type UserES = EventStoreForDynamoDB<UserId, User, UserEvent>;
+
+pub type AwsServiceContext = ServiceContext<
+    AwsDynamoDbProjectRepository<ES>,
+    AwsDynamoDbProjectInvitationRepository<InvitationES>,
+    AwsDynamoDbProjectNameReservation,
+    AwsDynamoDbIterationRepository<IterationES>,
+    AwsDynamoDbIterationIndex,
+    AwsDynamoDbTaskRepository<TaskES>,
+    AwsDynamoDbTaskIndex,
+    AwsDynamoDbUserRepository<UserES>,
+    AwsDynamoDbUserEmailReservation,
+    LoggingMailer,
+>;
+
 pub type ApiSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
 
 pub fn create_schema_builder() -> SchemaBuilder<QueryRoot, MutationRoot, EmptySubscription> {
     Schema::build(QueryRoot, MutationRoot, EmptySubscription)
 }
 
-pub fn create_schema(project_repository: AwsDynamoDbProjectRepository<ES>) -> ApiSchema {
-    let processor = ProjectCommandProcessor::new(project_repository);
-    let ctx = ServiceContext::new(processor);
+#[allow(clippy::too_many_arguments)]
+pub fn create_schema(
+    project_repository: AwsDynamoDbProjectRepository<ES>,
+    project_invitation_repository: AwsDynamoDbProjectInvitationRepository<InvitationES>,
+    project_name_reservation: AwsDynamoDbProjectNameReservation,
+    iteration_repository: AwsDynamoDbIterationRepository<IterationES>,
+    iteration_index: AwsDynamoDbIterationIndex,
+    task_repository: AwsDynamoDbTaskRepository<TaskES>,
+    task_index: AwsDynamoDbTaskIndex,
+    user_repository: AwsDynamoDbUserRepository<UserES>,
+    user_email_reservation: AwsDynamoDbUserEmailReservation,
+    mailer: LoggingMailer,
+    restore_grace_period: Duration,
+) -> ApiSchema {
+    let processor = ProjectCommandProcessor::new(project_repository.clone(), project_name_reservation)
+        .with_restore_grace_period(restore_grace_period);
+    let invitation_processor = ProjectInvitationCommandProcessor::new(
+        project_repository.clone(),
+        project_invitation_repository,
+        user_repository.clone(),
+    );
+    let iteration_processor = IterationCommandProcessor::new(
+        project_repository.clone(),
+        iteration_repository.clone(),
+        iteration_index,
+    );
+    let task_processor = TaskCommandProcessor::new(
+        project_repository,
+        iteration_repository,
+        task_repository,
+        task_index,
+    );
+    let user_processor = UserCommandProcessor::new(user_repository, user_email_reservation, mailer);
+    let ctx = ServiceContext::new(
+        processor,
+        invitation_processor,
+        iteration_processor,
+        task_processor,
+        user_processor,
+    );
     create_schema_builder().data(ctx).finish()
 }
@@ -1,8 +1,10 @@
 use async_graphql::InputObject;
+use chrono::NaiveDate;
 
 #[derive(Debug, Clone, InputObject)]
 pub struct CreateProjectInput {
     pub name: String,
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, InputObject)]
@@ -10,6 +12,11 @@ pub struct DeleteProjectInput {
     pub project_id: String,
 }
 
+#[derive(Debug, Clone, InputObject)]
+pub struct RestoreProjectInput {
+    pub project_id: String,
+}
+
 #[derive(Debug, Clone, InputObject)]
 pub struct AddMemberInput {
     pub project_id: String,
@@ -23,8 +30,281 @@ pub struct RemoveMemberInput {
     pub user_id: String,
 }
 
+#[derive(Debug, Clone, InputObject)]
+pub struct LeaveProjectInput {
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct UnassignRemovedMembersInput {
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ChangeMemberRoleInput {
+    pub project_id: String,
+    pub user_id: String,
+    pub role: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct TransferProjectOwnershipInput {
+    pub project_id: String,
+    pub new_owner_id: String,
+}
+
 #[derive(Debug, Clone, InputObject)]
 pub struct RenameProjectInput {
     pub project_id: String,
     pub new_name: String,
 }
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ChangeProjectDescriptionInput {
+    pub project_id: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct WorkflowTransitionInput {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct DefineWorkflowInput {
+    pub project_id: String,
+    pub statuses: Vec<String>,
+    pub transitions: Vec<WorkflowTransitionInput>,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct StartProjectInput {
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct CompleteProjectInput {
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ArchiveProjectInput {
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ReopenProjectInput {
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RegisterUserInput {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct DeleteUserInput {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RenameUserInput {
+    pub user_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RequestEmailChangeInput {
+    pub user_id: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ConfirmEmailChangeInput {
+    pub user_id: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct SendProjectInvitationInput {
+    pub project_id: String,
+    pub email: String,
+    pub role: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct AcceptProjectInvitationInput {
+    pub invitation_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct DeclineProjectInvitationInput {
+    pub invitation_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct CreateIterationInput {
+    pub project_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RenameIterationInput {
+    pub iteration_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RescheduleIterationInput {
+    pub iteration_id: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct StartIterationInput {
+    pub iteration_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct CompleteIterationInput {
+    pub iteration_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct CarryOverIterationInput {
+    pub iteration_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct CreateTaskInput {
+    pub project_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<String>,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RenameTaskInput {
+    pub task_id: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ChangeTaskPriorityInput {
+    pub task_id: String,
+    pub priority: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ChangeTaskStatusInput {
+    pub task_id: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct ScheduleTaskInput {
+    pub task_id: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct DeleteTaskInput {
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct AssignTaskInput {
+    pub task_id: String,
+    pub assignee_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct UnassignTaskInput {
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct MoveTaskToIterationInput {
+    pub task_id: String,
+    pub iteration_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct MoveTaskToBacklogInput {
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct AddCommentInput {
+    pub task_id: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct EditCommentInput {
+    pub task_id: String,
+    pub comment_id: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct DeleteCommentInput {
+    pub task_id: String,
+    pub comment_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct CreateTagInput {
+    pub project_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RenameTagInput {
+    pub project_id: String,
+    pub tag_id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct DeleteTagInput {
+    pub project_id: String,
+    pub tag_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct UntagDeletedTagInput {
+    pub project_id: String,
+    pub tag_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct TagTaskInput {
+    pub task_id: String,
+    pub tag_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct UntagTaskInput {
+    pub task_id: String,
+    pub tag_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct AddTaskDependencyInput {
+    pub task_id: String,
+    pub blocker_id: String,
+}
+
+#[derive(Debug, Clone, InputObject)]
+pub struct RemoveTaskDependencyInput {
+    pub task_id: String,
+    pub blocker_id: String,
+}
@@ -1,4 +1,8 @@
 use async_graphql::SimpleObject;
+use chrono::NaiveDate;
+use event_store_adapter_rs::types::Aggregate;
+
+use command_domain::task::Task;
 
 #[derive(Debug, Clone, SimpleObject)]
 pub struct ProjectOut {
@@ -10,3 +14,115 @@ impl ProjectOut {
         Self { project_id }
     }
 }
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct UserOut {
+    user_id: String,
+}
+
+impl UserOut {
+    pub fn new(user_id: String) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct ProjectInvitationOut {
+    invitation_id: String,
+}
+
+impl ProjectInvitationOut {
+    pub fn new(invitation_id: String) -> Self {
+        Self { invitation_id }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TagOut {
+    project_id: String,
+    tag_id: String,
+}
+
+impl TagOut {
+    pub fn new(project_id: String, tag_id: String) -> Self {
+        Self { project_id, tag_id }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct IterationOut {
+    iteration_id: String,
+}
+
+impl IterationOut {
+    pub fn new(iteration_id: String) -> Self {
+        Self { iteration_id }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TaskOut {
+    task_id: String,
+}
+
+impl TaskOut {
+    pub fn new(task_id: String) -> Self {
+        Self { task_id }
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+pub struct CommentOut {
+    task_id: String,
+    comment_id: String,
+}
+
+impl CommentOut {
+    pub fn new(task_id: String, comment_id: String) -> Self {
+        Self { task_id, comment_id }
+    }
+}
+
+/// タスクの予定期間と実績期間
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TaskScheduleOut {
+    task_id: String,
+    planned_start_date: Option<NaiveDate>,
+    planned_end_date: Option<NaiveDate>,
+    actual_start_date: Option<NaiveDate>,
+    actual_end_date: Option<NaiveDate>,
+    overdue: bool,
+}
+
+impl TaskScheduleOut {
+    pub fn new(task: &Task, today: &NaiveDate) -> Self {
+        Self {
+            task_id: task.id().to_string(),
+            planned_start_date: task.planned_period().map(|period| period.start()),
+            planned_end_date: task.planned_period().map(|period| period.end()),
+            actual_start_date: task.actual_started_on(),
+            actual_end_date: task.actual_finished_on(),
+            overdue: task.is_overdue(today),
+        }
+    }
+}
+
+/// 依存関係に基づくプロジェクトのタスクの計画
+#[derive(Debug, Clone, SimpleObject)]
+pub struct TaskPlanOut {
+    project_id: String,
+    /// 先行タスクが必ず前に来るように並べたタスクのID
+    topological_order: Vec<String>,
+    /// 未完了のタスクのクリティカルパス
+    critical_path: Vec<String>,
+}
+
+impl TaskPlanOut {
+    pub fn new(project_id: String, topological_order: Vec<String>, critical_path: Vec<String>) -> Self {
+        Self {
+            project_id,
+            topological_order,
+            critical_path,
+        }
+    }
+}
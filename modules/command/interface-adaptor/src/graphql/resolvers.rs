@@ -1,38 +1,113 @@
 use async_graphql::{Context, Error, ErrorExtensions, FieldResult, Object};
+use chrono::{NaiveDate, Utc};
 use event_store_adapter_rs::types::EventStoreWriteError;
 use std::str::FromStr;
 
-use command_domain::project::{MemberRole, ProjectId, ProjectName};
-use command_domain::user::UserId;
-use command_interface_adaptor_if::ProjectRepositoryError;
+use command_domain::description::Description;
+use command_domain::email::Email;
+use command_domain::iteration::{IterationId, IterationName};
+use command_domain::period::Period;
+use command_domain::project::{
+    MemberId, MemberRole, ProjectId, ProjectName, TagId, TagName, Workflow, WorkflowTransition,
+};
+use command_domain::project_invitation::ProjectInvitationId;
+use command_domain::task::{CommentBody, CommentId, Priority, TaskDependencyService, TaskId, TaskStatus, TaskTitle};
+use command_domain::user::{EmailVerificationToken, UserId, UserName};
+use command_interface_adaptor_if::{
+    IterationRepositoryError, ProjectInvitationRepositoryError, ProjectRepositoryError, TaskRepositoryError,
+    UserRepositoryError,
+};
+use command_processor::iteration_command_processor::IterationCommandProcessError;
 use command_processor::project_command_processor::CommandProcessError;
+use command_processor::project_invitation_command_processor::ProjectInvitationCommandProcessError;
+use command_processor::task_command_processor::TaskCommandProcessError;
+use command_processor::user_command_processor::UserCommandProcessError;
 
 use crate::controllers::extractor::AuthorizedUser;
-use crate::gateways::project_repository::AwsDynamoDbProjectRepository;
 use crate::graphql::inputs::{
-    AddMemberInput, CreateProjectInput, DeleteProjectInput, RemoveMemberInput, RenameProjectInput,
+    AcceptProjectInvitationInput, AddCommentInput, AddMemberInput, AddTaskDependencyInput, ArchiveProjectInput,
+    AssignTaskInput, CarryOverIterationInput, ChangeMemberRoleInput, ChangeProjectDescriptionInput,
+    ChangeTaskPriorityInput, ChangeTaskStatusInput, CompleteIterationInput, CompleteProjectInput,
+    ConfirmEmailChangeInput, CreateIterationInput, CreateProjectInput, CreateTagInput, CreateTaskInput,
+    DeclineProjectInvitationInput, DefineWorkflowInput, DeleteCommentInput, DeleteProjectInput, DeleteTagInput,
+    DeleteTaskInput, DeleteUserInput, EditCommentInput, LeaveProjectInput, MoveTaskToBacklogInput,
+    MoveTaskToIterationInput, RegisterUserInput, RemoveMemberInput, RemoveTaskDependencyInput, RenameIterationInput,
+    RenameProjectInput, RenameTagInput, RenameTaskInput, RenameUserInput, ReopenProjectInput, RequestEmailChangeInput,
+    RescheduleIterationInput, RestoreProjectInput, ScheduleTaskInput, SendProjectInvitationInput, StartIterationInput,
+    StartProjectInput, TagTaskInput, TransferProjectOwnershipInput, UnassignRemovedMembersInput, UnassignTaskInput,
+    UntagDeletedTagInput, UntagTaskInput, WorkflowTransitionInput,
 };
-use crate::graphql::outputs::ProjectOut;
-use crate::graphql::{ES, MutationRoot, ServiceContext};
+use crate::graphql::outputs::{
+    CommentOut, IterationOut, ProjectInvitationOut, ProjectOut, TagOut, TaskOut, TaskPlanOut, TaskScheduleOut, UserOut,
+};
+use crate::graphql::{AwsServiceContext, MutationRoot, QueryRoot};
+
+#[Object]
+impl QueryRoot {
+    async fn health_check(&self) -> String {
+        "OK".to_string()
+    }
+
+    /// タスクの予定期間と実績期間、期限切れかどうかを返す
+    async fn task_schedule(&self, ctx: &Context<'_>, task_id: String) -> FieldResult<TaskScheduleOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&task_id)?;
+
+        let processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .find_task(task_id, authorized_user.user_id.clone())
+            .await
+            .map(|task| TaskScheduleOut::new(&task, &Utc::now().date_naive()))
+            .map_err(task_error_handling)
+    }
+
+    /// 依存関係に基づいて、プロジェクトのタスクの並び順とクリティカルパスを返す
+    async fn task_plan(&self, ctx: &Context<'_>, project_id: String) -> FieldResult<TaskPlanOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&project_id)?;
+
+        let processor = service_ctx.task_command_processor.lock().await;
+        let tasks = processor
+            .find_tasks(project_id.clone(), authorized_user.user_id.clone())
+            .await
+            .map_err(task_error_handling)?;
+        let topological_order = TaskDependencyService::topological_order(&tasks)
+            .map_err(TaskCommandProcessError::DomainLogicError)
+            .map_err(task_error_handling)?;
+        let critical_path = TaskDependencyService::critical_path(&tasks)
+            .map_err(TaskCommandProcessError::DomainLogicError)
+            .map_err(task_error_handling)?;
+        Ok(TaskPlanOut::new(
+            project_id.to_string(),
+            topological_order.iter().map(ToString::to_string).collect(),
+            critical_path.iter().map(ToString::to_string).collect(),
+        ))
+    }
+}
 
 #[Object]
 impl MutationRoot {
     async fn create_project(&self, ctx: &Context<'_>, input: CreateProjectInput) -> FieldResult<ProjectOut> {
-        let service_ctx = ctx.data::<ServiceContext<AwsDynamoDbProjectRepository<ES>>>().unwrap();
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
         let authorized_user = ctx.data::<AuthorizedUser>()?;
 
         let project_name = validate_project_name(&input.name)?;
+        let description = validate_description(input.description.as_deref().unwrap_or_default())?;
 
         let mut processor = service_ctx.project_command_processor.lock().await;
         processor
-            .create_project(project_name, authorized_user.user_id.clone())
+            .create_project(project_name, description, authorized_user.user_id.clone())
             .await
             .map(|project_id| ProjectOut::new(project_id.to_string()))
             .map_err(error_handling)
     }
 
     async fn delete_project(&self, ctx: &Context<'_>, input: DeleteProjectInput) -> FieldResult<ProjectOut> {
-        let service_ctx = ctx.data::<ServiceContext<AwsDynamoDbProjectRepository<ES>>>().unwrap();
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
         let authorized_user = ctx.data::<AuthorizedUser>()?;
 
         let project_id = validate_project_id(&input.project_id)?;
@@ -45,8 +120,23 @@ impl MutationRoot {
             .map_err(error_handling)
     }
 
+    async fn restore_project(&self, ctx: &Context<'_>, input: RestoreProjectInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+
+        processor
+            .restore_project(project_id, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
+    }
+
     async fn add_member(&self, ctx: &Context<'_>, input: AddMemberInput) -> FieldResult<ProjectOut> {
-        let service_ctx = ctx.data::<ServiceContext<AwsDynamoDbProjectRepository<ES>>>().unwrap();
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
         let authorized_user = ctx.data::<AuthorizedUser>()?;
 
         let project_id = validate_project_id(&input.project_id)?;
@@ -62,7 +152,7 @@ impl MutationRoot {
     }
 
     async fn remove_member(&self, ctx: &Context<'_>, input: RemoveMemberInput) -> FieldResult<ProjectOut> {
-        let service_ctx = ctx.data::<ServiceContext<AwsDynamoDbProjectRepository<ES>>>().unwrap();
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
         let authorized_user = ctx.data::<AuthorizedUser>()?;
 
         let project_id = validate_project_id(&input.project_id)?;
@@ -70,15 +160,92 @@ impl MutationRoot {
 
         let mut processor = service_ctx.project_command_processor.lock().await;
 
-        processor
+        let project_id = processor
             .remove_member(project_id, user_id, authorized_user.user_id.clone())
             .await
+            .map_err(error_handling)?;
+        drop(processor);
+
+        unassign_removed_members(service_ctx, &project_id, &authorized_user.user_id).await;
+        Ok(ProjectOut::new(project_id.to_string()))
+    }
+
+    async fn leave_project(&self, ctx: &Context<'_>, input: LeaveProjectInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+
+        let project_id = processor
+            .leave_project(project_id, authorized_user.user_id.clone())
+            .await
+            .map_err(error_handling)?;
+        drop(processor);
+
+        unassign_removed_members(service_ctx, &project_id, &authorized_user.user_id).await;
+        Ok(ProjectOut::new(project_id.to_string()))
+    }
+
+    /// プロジェクトのメンバーでなくなった担当者の未完了タスクの割り当てを解除する。
+    ///
+    /// メンバーの削除・脱退の後に割り当ての解除が失敗した場合の再実行に利用する。
+    async fn unassign_removed_members(
+        &self,
+        ctx: &Context<'_>,
+        input: UnassignRemovedMembersInput,
+    ) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .unassign_removed_members(project_id.clone(), authorized_user.user_id.clone())
+            .await
+            .map(|_| ProjectOut::new(project_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn change_member_role(&self, ctx: &Context<'_>, input: ChangeMemberRoleInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let user_id = validate_user_id(&input.user_id)?;
+        let role = validate_member_role(&input.role)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .change_member_role(project_id, user_id, role, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
+    }
+
+    async fn transfer_project_ownership(
+        &self,
+        ctx: &Context<'_>,
+        input: TransferProjectOwnershipInput,
+    ) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let new_owner_id = validate_user_id(&input.new_owner_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .transfer_ownership(project_id, new_owner_id, authorized_user.user_id.clone())
+            .await
             .map(|project_id| ProjectOut::new(project_id.to_string()))
             .map_err(error_handling)
     }
 
     async fn rename_project(&self, ctx: &Context<'_>, input: RenameProjectInput) -> FieldResult<ProjectOut> {
-        let service_ctx = ctx.data::<ServiceContext<AwsDynamoDbProjectRepository<ES>>>().unwrap();
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
         let authorized_user = ctx.data::<AuthorizedUser>()?;
 
         let project_id = validate_project_id(&input.project_id)?;
@@ -91,46 +258,1057 @@ impl MutationRoot {
             .map(|project_id| ProjectOut::new(project_id.to_string()))
             .map_err(error_handling)
     }
-}
 
-fn error_handling_repository_error(error: &CommandProcessError, cause: &ProjectRepositoryError) -> Error {
-    match cause {
-        ProjectRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => {
-            Error::new(error.to_string())
-                .extend_with(|_, e| e.set("code", "409"))
-                .extend_with(|_, e| e.set("cause", cause.to_string()))
-        },
-        ProjectRepositoryError::StoreError(_, _) => Error::new(error.to_string())
-            .extend_with(|_, e| e.set("code", "500"))
-            .extend_with(|_, e| e.set("cause", cause.to_string())),
-        ProjectRepositoryError::FindByIdError(_, _) => Error::new(error.to_string())
-            .extend_with(|_, e| e.set("code", "500"))
-            .extend_with(|_, e| e.set("cause", cause.to_string())),
+    async fn change_project_description(
+        &self,
+        ctx: &Context<'_>,
+        input: ChangeProjectDescriptionInput,
+    ) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let new_description = validate_description(&input.description)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .change_project_description(project_id, new_description, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
     }
-}
 
-fn error_handling(error: CommandProcessError) -> Error {
-    match error {
-        CommandProcessError::DomainLogicError(ref cause) => Error::new(error.to_string())
-            .extend_with(|_, e| e.set("code", "422"))
-            .extend_with(|_, e| e.set("cause", cause.to_string())),
-        CommandProcessError::NotFoundError => Error::new(error.to_string()).extend_with(|_, e| e.set("code", "404")),
-        CommandProcessError::RepositoryError(ref cause) => error_handling_repository_error(&error, cause),
+    async fn define_workflow(&self, ctx: &Context<'_>, input: DefineWorkflowInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let workflow = validate_workflow(&input.statuses, &input.transitions)?;
+
+        let statuses_in_use = {
+            let processor = service_ctx.task_command_processor.lock().await;
+            processor
+                .find_statuses_in_use(project_id.clone())
+                .await
+                .map_err(task_error_handling)?
+        };
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .define_workflow(
+                project_id,
+                workflow,
+                statuses_in_use,
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
     }
-}
 
-fn validate_project_id(value: &str) -> Result<ProjectId, Error> {
-    ProjectId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
-}
+    async fn create_tag(&self, ctx: &Context<'_>, input: CreateTagInput) -> FieldResult<TagOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
 
-fn validate_project_name(value: &str) -> Result<ProjectName, Error> {
-    ProjectName::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
-}
+        let project_id = validate_project_id(&input.project_id)?;
+        let name = validate_tag_name(&input.name)?;
 
-fn validate_member_role(value: &str) -> Result<MemberRole, Error> {
-    MemberRole::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
-}
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .create_tag(project_id.clone(), name, authorized_user.user_id.clone())
+            .await
+            .map(|tag_id| TagOut::new(project_id.to_string(), tag_id.to_string()))
+            .map_err(error_handling)
+    }
 
-fn validate_user_id(value: &str) -> Result<UserId, Error> {
-    UserId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+    async fn rename_tag(&self, ctx: &Context<'_>, input: RenameTagInput) -> FieldResult<TagOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let tag_id = validate_tag_id(&input.tag_id)?;
+        let name = validate_tag_name(&input.name)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .rename_tag(
+                project_id,
+                tag_id.clone(),
+                name,
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|project_id| TagOut::new(project_id.to_string(), tag_id.to_string()))
+            .map_err(error_handling)
+    }
+
+    async fn delete_tag(&self, ctx: &Context<'_>, input: DeleteTagInput) -> FieldResult<TagOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let tag_id = validate_tag_id(&input.tag_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+
+        let project_id = processor
+            .delete_tag(project_id, tag_id.clone(), authorized_user.user_id.clone())
+            .await
+            .map_err(error_handling)?;
+        drop(processor);
+
+        untag_deleted_tag(service_ctx, &project_id, &tag_id, &authorized_user.user_id).await;
+        Ok(TagOut::new(project_id.to_string(), tag_id.to_string()))
+    }
+
+    /// 削除されたタグをプロジェクトのタスクから外す。
+    ///
+    /// タグの削除の後にタスクからタグを外す処理が失敗した場合の再実行に利用する。
+    async fn untag_deleted_tag(&self, ctx: &Context<'_>, input: UntagDeletedTagInput) -> FieldResult<TagOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let tag_id = validate_tag_id(&input.tag_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .untag_deleted_tag(
+                project_id.clone(),
+                tag_id.clone(),
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|_| TagOut::new(project_id.to_string(), tag_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn start_project(&self, ctx: &Context<'_>, input: StartProjectInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .start_project(project_id, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
+    }
+
+    async fn complete_project(&self, ctx: &Context<'_>, input: CompleteProjectInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .complete_project(project_id, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
+    }
+
+    async fn archive_project(&self, ctx: &Context<'_>, input: ArchiveProjectInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .archive_project(project_id, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
+    }
+
+    async fn reopen_project(&self, ctx: &Context<'_>, input: ReopenProjectInput) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+
+        let mut processor = service_ctx.project_command_processor.lock().await;
+        processor
+            .reopen_project(project_id, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(error_handling)
+    }
+
+    async fn register_user(&self, ctx: &Context<'_>, input: RegisterUserInput) -> FieldResult<UserOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+
+        let user_name = validate_user_name(&input.name)?;
+        let email = validate_email(&input.email)?;
+
+        let mut processor = service_ctx.user_command_processor.lock().await;
+        processor
+            .register_user(user_name, email)
+            .await
+            .map(|user_id| UserOut::new(user_id.to_string()))
+            .map_err(user_error_handling)
+    }
+
+    async fn delete_user(&self, ctx: &Context<'_>, input: DeleteUserInput) -> FieldResult<UserOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let user_id = validate_user_id(&input.user_id)?;
+
+        let mut processor = service_ctx.user_command_processor.lock().await;
+        processor
+            .delete_user(user_id, authorized_user.user_id.clone())
+            .await
+            .map(|user_id| UserOut::new(user_id.to_string()))
+            .map_err(user_error_handling)
+    }
+
+    async fn rename_user(&self, ctx: &Context<'_>, input: RenameUserInput) -> FieldResult<UserOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let user_id = validate_user_id(&input.user_id)?;
+        let user_name = validate_user_name(&input.name)?;
+
+        let mut processor = service_ctx.user_command_processor.lock().await;
+        processor
+            .rename_user(user_id, user_name, authorized_user.user_id.clone())
+            .await
+            .map(|user_id| UserOut::new(user_id.to_string()))
+            .map_err(user_error_handling)
+    }
+
+    async fn request_email_change(&self, ctx: &Context<'_>, input: RequestEmailChangeInput) -> FieldResult<UserOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let user_id = validate_user_id(&input.user_id)?;
+        let email = validate_email(&input.email)?;
+
+        let mut processor = service_ctx.user_command_processor.lock().await;
+        processor
+            .request_email_change(user_id, email, authorized_user.user_id.clone())
+            .await
+            .map(|user_id| UserOut::new(user_id.to_string()))
+            .map_err(user_error_handling)
+    }
+
+    async fn confirm_email_change(&self, ctx: &Context<'_>, input: ConfirmEmailChangeInput) -> FieldResult<UserOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let user_id = validate_user_id(&input.user_id)?;
+        let token = validate_email_verification_token(&input.token)?;
+
+        let mut processor = service_ctx.user_command_processor.lock().await;
+        processor
+            .confirm_email_change(user_id, token, authorized_user.user_id.clone())
+            .await
+            .map(|user_id| UserOut::new(user_id.to_string()))
+            .map_err(user_error_handling)
+    }
+
+    async fn send_project_invitation(
+        &self,
+        ctx: &Context<'_>,
+        input: SendProjectInvitationInput,
+    ) -> FieldResult<ProjectInvitationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let email = validate_email(&input.email)?;
+        let role = validate_member_role(&input.role)?;
+
+        let mut processor = service_ctx.project_invitation_command_processor.lock().await;
+        processor
+            .send_invitation(project_id, email, role, authorized_user.user_id.clone())
+            .await
+            .map(|invitation_id| ProjectInvitationOut::new(invitation_id.to_string()))
+            .map_err(invitation_error_handling)
+    }
+
+    async fn accept_project_invitation(
+        &self,
+        ctx: &Context<'_>,
+        input: AcceptProjectInvitationInput,
+    ) -> FieldResult<ProjectOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let invitation_id = validate_project_invitation_id(&input.invitation_id)?;
+
+        let mut processor = service_ctx.project_invitation_command_processor.lock().await;
+        processor
+            .accept_invitation(invitation_id, authorized_user.user_id.clone())
+            .await
+            .map(|project_id| ProjectOut::new(project_id.to_string()))
+            .map_err(invitation_error_handling)
+    }
+
+    async fn decline_project_invitation(
+        &self,
+        ctx: &Context<'_>,
+        input: DeclineProjectInvitationInput,
+    ) -> FieldResult<ProjectInvitationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let invitation_id = validate_project_invitation_id(&input.invitation_id)?;
+
+        let mut processor = service_ctx.project_invitation_command_processor.lock().await;
+        processor
+            .decline_invitation(invitation_id, authorized_user.user_id.clone())
+            .await
+            .map(|invitation_id| ProjectInvitationOut::new(invitation_id.to_string()))
+            .map_err(invitation_error_handling)
+    }
+
+    async fn create_iteration(&self, ctx: &Context<'_>, input: CreateIterationInput) -> FieldResult<IterationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let name = validate_iteration_name(&input.name)?;
+        let description = validate_description(input.description.as_deref().unwrap_or_default())?;
+        let period = validate_period(input.start_date, input.end_date)?;
+
+        let mut processor = service_ctx.iteration_command_processor.lock().await;
+        processor
+            .create_iteration(
+                project_id,
+                name,
+                description,
+                period,
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|iteration_id| IterationOut::new(iteration_id.to_string()))
+            .map_err(iteration_error_handling)
+    }
+
+    async fn rename_iteration(&self, ctx: &Context<'_>, input: RenameIterationInput) -> FieldResult<IterationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let iteration_id = validate_iteration_id(&input.iteration_id)?;
+        let name = validate_iteration_name(&input.name)?;
+
+        let mut processor = service_ctx.iteration_command_processor.lock().await;
+        processor
+            .rename_iteration(iteration_id, name, authorized_user.user_id.clone())
+            .await
+            .map(|iteration_id| IterationOut::new(iteration_id.to_string()))
+            .map_err(iteration_error_handling)
+    }
+
+    async fn reschedule_iteration(
+        &self,
+        ctx: &Context<'_>,
+        input: RescheduleIterationInput,
+    ) -> FieldResult<IterationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let iteration_id = validate_iteration_id(&input.iteration_id)?;
+        let period = validate_period(input.start_date, input.end_date)?;
+
+        let mut processor = service_ctx.iteration_command_processor.lock().await;
+        processor
+            .reschedule_iteration(iteration_id, period, authorized_user.user_id.clone())
+            .await
+            .map(|iteration_id| IterationOut::new(iteration_id.to_string()))
+            .map_err(iteration_error_handling)
+    }
+
+    async fn start_iteration(&self, ctx: &Context<'_>, input: StartIterationInput) -> FieldResult<IterationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let iteration_id = validate_iteration_id(&input.iteration_id)?;
+
+        let mut processor = service_ctx.iteration_command_processor.lock().await;
+        processor
+            .start_iteration(iteration_id, authorized_user.user_id.clone())
+            .await
+            .map(|iteration_id| IterationOut::new(iteration_id.to_string()))
+            .map_err(iteration_error_handling)
+    }
+
+    async fn complete_iteration(&self, ctx: &Context<'_>, input: CompleteIterationInput) -> FieldResult<IterationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let iteration_id = validate_iteration_id(&input.iteration_id)?;
+
+        let mut processor = service_ctx.iteration_command_processor.lock().await;
+        let iteration_id = processor
+            .complete_iteration(iteration_id, authorized_user.user_id.clone())
+            .await
+            .map_err(iteration_error_handling)?;
+        drop(processor);
+
+        carry_over_unfinished_tasks(service_ctx, &iteration_id, &authorized_user.user_id).await;
+        Ok(IterationOut::new(iteration_id.to_string()))
+    }
+
+    /// 完了したイテレーションの未完了タスクを引き継ぐ。
+    ///
+    /// イテレーションの完了後に引き継ぎが失敗した場合の再実行に利用する。
+    async fn carry_over_iteration(
+        &self,
+        ctx: &Context<'_>,
+        input: CarryOverIterationInput,
+    ) -> FieldResult<IterationOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let iteration_id = validate_iteration_id(&input.iteration_id)?;
+
+        let destination_id = find_carry_over_destination(service_ctx, &iteration_id).await?;
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .carry_over_iteration(
+                iteration_id.clone(),
+                destination_id,
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|_| IterationOut::new(iteration_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn create_task(&self, ctx: &Context<'_>, input: CreateTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let project_id = validate_project_id(&input.project_id)?;
+        let title = validate_task_title(&input.title)?;
+        let description = validate_description(input.description.as_deref().unwrap_or_default())?;
+        let priority = match input.priority.as_deref() {
+            Some(value) => validate_priority(value)?,
+            None => Priority::default(),
+        };
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .create_task(
+                project_id,
+                title,
+                description,
+                priority,
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn rename_task(&self, ctx: &Context<'_>, input: RenameTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let title = validate_task_title(&input.title)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .rename_task(task_id, title, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn change_task_priority(&self, ctx: &Context<'_>, input: ChangeTaskPriorityInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let priority = validate_priority(&input.priority)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .change_task_priority(task_id, priority, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn change_task_status(&self, ctx: &Context<'_>, input: ChangeTaskStatusInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let status = validate_task_status(&input.status)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .change_task_status(task_id, status, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn schedule_task(&self, ctx: &Context<'_>, input: ScheduleTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let period = validate_period(input.start_date, input.end_date)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .schedule_task(task_id, period, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn delete_task(&self, ctx: &Context<'_>, input: DeleteTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .delete_task(task_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn assign_task(&self, ctx: &Context<'_>, input: AssignTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let assignee_id = validate_member_id(&input.assignee_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .assign_task(task_id, assignee_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn unassign_task(&self, ctx: &Context<'_>, input: UnassignTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .unassign_task(task_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn move_task_to_iteration(&self, ctx: &Context<'_>, input: MoveTaskToIterationInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let iteration_id = validate_iteration_id(&input.iteration_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .move_task_to_iteration(task_id, iteration_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn move_task_to_backlog(&self, ctx: &Context<'_>, input: MoveTaskToBacklogInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .move_task_to_backlog(task_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn tag_task(&self, ctx: &Context<'_>, input: TagTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let tag_id = validate_tag_id(&input.tag_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .tag_task(task_id, tag_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn untag_task(&self, ctx: &Context<'_>, input: UntagTaskInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let tag_id = validate_tag_id(&input.tag_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .untag_task(task_id, tag_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn add_task_dependency(&self, ctx: &Context<'_>, input: AddTaskDependencyInput) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let blocker_id = validate_task_id(&input.blocker_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .add_task_dependency(task_id, blocker_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn remove_task_dependency(
+        &self,
+        ctx: &Context<'_>,
+        input: RemoveTaskDependencyInput,
+    ) -> FieldResult<TaskOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let blocker_id = validate_task_id(&input.blocker_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .remove_task_dependency(task_id, blocker_id, authorized_user.user_id.clone())
+            .await
+            .map(|task_id| TaskOut::new(task_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn add_comment(&self, ctx: &Context<'_>, input: AddCommentInput) -> FieldResult<CommentOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let body = validate_comment_body(&input.body)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .add_comment(task_id.clone(), body, authorized_user.user_id.clone())
+            .await
+            .map(|comment_id| CommentOut::new(task_id.to_string(), comment_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn edit_comment(&self, ctx: &Context<'_>, input: EditCommentInput) -> FieldResult<CommentOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let comment_id = validate_comment_id(&input.comment_id)?;
+        let body = validate_comment_body(&input.body)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .edit_comment(
+                task_id,
+                comment_id.clone(),
+                body,
+                authorized_user.user_id.clone(),
+            )
+            .await
+            .map(|task_id| CommentOut::new(task_id.to_string(), comment_id.to_string()))
+            .map_err(task_error_handling)
+    }
+
+    async fn delete_comment(&self, ctx: &Context<'_>, input: DeleteCommentInput) -> FieldResult<CommentOut> {
+        let service_ctx = ctx.data::<AwsServiceContext>().unwrap();
+        let authorized_user = ctx.data::<AuthorizedUser>()?;
+
+        let task_id = validate_task_id(&input.task_id)?;
+        let comment_id = validate_comment_id(&input.comment_id)?;
+
+        let mut processor = service_ctx.task_command_processor.lock().await;
+        processor
+            .delete_comment(task_id, comment_id.clone(), authorized_user.user_id.clone())
+            .await
+            .map(|task_id| CommentOut::new(task_id.to_string(), comment_id.to_string()))
+            .map_err(task_error_handling)
+    }
+}
+
+/// イテレーションの完了に伴い、未完了タスクを引き継ぎ先のイテレーションまたはバックログへ移動する。
+///
+/// イテレーションの完了は保存済みのため、失敗した場合はログに記録し、`carryOverIteration` での再実行に委ねる。
+/// 移動済みのタスクは対象外となる。
+async fn carry_over_unfinished_tasks(
+    service_ctx: &AwsServiceContext,
+    iteration_id: &IterationId,
+    executor_id: &UserId,
+) {
+    let result = match find_carry_over_destination(service_ctx, iteration_id).await {
+        Ok(destination_id) => {
+            let mut processor = service_ctx.task_command_processor.lock().await;
+            processor
+                .handle_iteration_completed(iteration_id.clone(), destination_id, executor_id.clone())
+                .await
+                .map(|_| ())
+                .map_err(task_error_handling)
+        },
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        log::error!(
+            "Failed to carry over the unfinished tasks: iteration_id = {}, error = {:?}",
+            iteration_id,
+            error
+        );
+    }
+}
+
+/// 未完了タスクの引き継ぎ先となるイテレーションを取得する。
+async fn find_carry_over_destination(
+    service_ctx: &AwsServiceContext,
+    iteration_id: &IterationId,
+) -> Result<Option<IterationId>, Error> {
+    let processor = service_ctx.iteration_command_processor.lock().await;
+    processor
+        .find_carry_over_destination(iteration_id.clone())
+        .await
+        .map_err(iteration_error_handling)
+}
+
+/// メンバーの削除・脱退に伴い、担当していた未完了タスクの割り当てを解除する。
+///
+/// メンバーの削除・脱退は保存済みのため、失敗した場合はログに記録し、`unassignRemovedMembers` での再実行に委ねる。
+/// 割り当てが解除済みのタスクは対象外となる。
+async fn unassign_removed_members(service_ctx: &AwsServiceContext, project_id: &ProjectId, executor_id: &UserId) {
+    let mut processor = service_ctx.task_command_processor.lock().await;
+    if let Err(error) = processor.handle_member_removed(project_id.clone(), executor_id.clone()).await {
+        log::error!(
+            "Failed to unassign the removed members: project_id = {}, error = {:?}",
+            project_id,
+            error
+        );
+    }
+}
+
+/// タグの削除に伴い、削除されたタグをプロジェクトのタスクから外す。
+///
+/// タグの削除は保存済みのため、失敗した場合はログに記録し、`untagDeletedTag` での再実行に委ねる。
+/// タグを外し済みのタスクは対象外となる。
+async fn untag_deleted_tag(
+    service_ctx: &AwsServiceContext,
+    project_id: &ProjectId,
+    tag_id: &TagId,
+    executor_id: &UserId,
+) {
+    let mut processor = service_ctx.task_command_processor.lock().await;
+    if let Err(error) = processor
+        .handle_tag_deleted(project_id.clone(), tag_id.clone(), executor_id.clone())
+        .await
+    {
+        log::error!(
+            "Failed to untag the deleted tag: project_id = {}, tag_id = {}, error = {:?}",
+            project_id,
+            tag_id,
+            error
+        );
+    }
+}
+
+fn error_handling_repository_error(error: &CommandProcessError, cause: &ProjectRepositoryError) -> Error {
+    match cause {
+        ProjectRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => {
+            Error::new(error.to_string())
+                .extend_with(|_, e| e.set("code", "409"))
+                .extend_with(|_, e| e.set("cause", cause.to_string()))
+        },
+        ProjectRepositoryError::StoreError(_, _) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        ProjectRepositoryError::FindByIdError(_, _) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+    }
+}
+
+fn error_handling(error: CommandProcessError) -> Error {
+    match error {
+        CommandProcessError::DomainLogicError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "422"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        CommandProcessError::NotFoundError => Error::new(error.to_string()).extend_with(|_, e| e.set("code", "404")),
+        CommandProcessError::RepositoryError(ref cause) => error_handling_repository_error(&error, cause),
+        CommandProcessError::ReservationError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+    }
+}
+
+fn invitation_error_handling(error: ProjectInvitationCommandProcessError) -> Error {
+    match error {
+        ProjectInvitationCommandProcessError::ProjectDomainLogicError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "422"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        ProjectInvitationCommandProcessError::InvitationDomainLogicError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "422"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        ProjectInvitationCommandProcessError::ProjectNotFoundError
+        | ProjectInvitationCommandProcessError::InvitationNotFoundError
+        | ProjectInvitationCommandProcessError::UserNotFoundError => {
+            Error::new(error.to_string()).extend_with(|_, e| e.set("code", "404"))
+        },
+        ProjectInvitationCommandProcessError::ProjectRepositoryError(ref cause) => {
+            let code = match cause {
+                ProjectRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => "409",
+                _ => "500",
+            };
+            Error::new(error.to_string())
+                .extend_with(|_, e| e.set("code", code))
+                .extend_with(|_, e| e.set("cause", cause.to_string()))
+        },
+        ProjectInvitationCommandProcessError::InvitationRepositoryError(ref cause) => {
+            let code = match cause {
+                ProjectInvitationRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => "409",
+                _ => "500",
+            };
+            Error::new(error.to_string())
+                .extend_with(|_, e| e.set("code", code))
+                .extend_with(|_, e| e.set("cause", cause.to_string()))
+        },
+        ProjectInvitationCommandProcessError::UserRepositoryError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+    }
+}
+
+fn iteration_error_handling(error: IterationCommandProcessError) -> Error {
+    match error {
+        IterationCommandProcessError::DomainLogicError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "422"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        IterationCommandProcessError::ProjectNotFoundError | IterationCommandProcessError::IterationNotFoundError => {
+            Error::new(error.to_string()).extend_with(|_, e| e.set("code", "404"))
+        },
+        IterationCommandProcessError::ProjectRepositoryError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        IterationCommandProcessError::IterationIndexError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        IterationCommandProcessError::IterationRepositoryError(ref cause) => {
+            let code = match cause {
+                IterationRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => "409",
+                _ => "500",
+            };
+            Error::new(error.to_string())
+                .extend_with(|_, e| e.set("code", code))
+                .extend_with(|_, e| e.set("cause", cause.to_string()))
+        },
+    }
+}
+
+fn task_error_handling(error: TaskCommandProcessError) -> Error {
+    match error {
+        TaskCommandProcessError::DomainLogicError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "422"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        TaskCommandProcessError::ProjectNotFoundError
+        | TaskCommandProcessError::TaskNotFoundError
+        | TaskCommandProcessError::IterationNotFoundError => {
+            Error::new(error.to_string()).extend_with(|_, e| e.set("code", "404"))
+        },
+        TaskCommandProcessError::ProjectRepositoryError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        TaskCommandProcessError::IterationRepositoryError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        TaskCommandProcessError::TaskIndexError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        TaskCommandProcessError::TaskRepositoryError(ref cause) => {
+            let code = match cause {
+                TaskRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => "409",
+                _ => "500",
+            };
+            Error::new(error.to_string())
+                .extend_with(|_, e| e.set("code", code))
+                .extend_with(|_, e| e.set("cause", cause.to_string()))
+        },
+    }
+}
+
+fn user_error_handling(error: UserCommandProcessError) -> Error {
+    match error {
+        UserCommandProcessError::DomainLogicError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "422"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        UserCommandProcessError::UserNotFoundError => {
+            Error::new(error.to_string()).extend_with(|_, e| e.set("code", "404"))
+        },
+        UserCommandProcessError::UserRepositoryError(ref cause) => {
+            let code = match cause {
+                UserRepositoryError::StoreError(_, EventStoreWriteError::OptimisticLockError(_)) => "409",
+                _ => "500",
+            };
+            Error::new(error.to_string())
+                .extend_with(|_, e| e.set("code", code))
+                .extend_with(|_, e| e.set("cause", cause.to_string()))
+        },
+        UserCommandProcessError::MailerError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+        // 楽観的ロックの失敗と区別できるよう、専用のエラーコードを付ける
+        UserCommandProcessError::EmailAlreadyInUseError(_) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "409"))
+            .extend_with(|_, e| e.set("error_code", "EMAIL_ALREADY_IN_USE")),
+        UserCommandProcessError::ReservationError(ref cause) => Error::new(error.to_string())
+            .extend_with(|_, e| e.set("code", "500"))
+            .extend_with(|_, e| e.set("cause", cause.to_string())),
+    }
+}
+
+fn validate_project_id(value: &str) -> Result<ProjectId, Error> {
+    ProjectId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_project_name(value: &str) -> Result<ProjectName, Error> {
+    ProjectName::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_tag_id(value: &str) -> Result<TagId, Error> {
+    TagId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_tag_name(value: &str) -> Result<TagName, Error> {
+    TagName::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_description(value: &str) -> Result<Description, Error> {
+    Description::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_project_invitation_id(value: &str) -> Result<ProjectInvitationId, Error> {
+    ProjectInvitationId::from_str(value)
+        .map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_iteration_id(value: &str) -> Result<IterationId, Error> {
+    IterationId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_iteration_name(value: &str) -> Result<IterationName, Error> {
+    IterationName::from_str(value)
+        .map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_period(start: NaiveDate, end: NaiveDate) -> Result<Period, Error> {
+    Period::new(start, end).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_task_id(value: &str) -> Result<TaskId, Error> {
+    TaskId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_task_title(value: &str) -> Result<TaskTitle, Error> {
+    TaskTitle::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_priority(value: &str) -> Result<Priority, Error> {
+    Priority::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_comment_id(value: &str) -> Result<CommentId, Error> {
+    CommentId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_comment_body(value: &str) -> Result<CommentBody, Error> {
+    CommentBody::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_task_status(value: &str) -> Result<TaskStatus, Error> {
+    TaskStatus::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_workflow(statuses: &[String], transitions: &[WorkflowTransitionInput]) -> Result<Workflow, Error> {
+    let statuses = statuses
+        .iter()
+        .map(|status| validate_task_status(status))
+        .collect::<Result<Vec<_>, _>>()?;
+    let transitions = transitions
+        .iter()
+        .map(|transition| {
+            Ok(WorkflowTransition::new(
+                validate_task_status(&transition.from)?,
+                validate_task_status(&transition.to)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Workflow::new(statuses, transitions)
+        .map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_email(value: &str) -> Result<Email, Error> {
+    Email::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_member_role(value: &str) -> Result<MemberRole, Error> {
+    MemberRole::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_member_id(value: &str) -> Result<MemberId, Error> {
+    MemberId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_user_id(value: &str) -> Result<UserId, Error> {
+    UserId::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_user_name(value: &str) -> Result<UserName, Error> {
+    UserName::from_str(value).map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+fn validate_email_verification_token(value: &str) -> Result<EmailVerificationToken, Error> {
+    EmailVerificationToken::from_str(value)
+        .map_err(|error| Error::new(error.to_string()).extend_with(|_, e| e.set("code", "400")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::Value;
+
+    #[test]
+    fn test_email_already_in_use_maps_to_conflict() {
+        let error = user_error_handling(UserCommandProcessError::EmailAlreadyInUseError(
+            Email::new("alice@example.com").unwrap(),
+        ));
+
+        let extensions = error.extensions.unwrap();
+        assert_eq!(extensions.get("code"), Some(&Value::from("409")));
+        assert_eq!(
+            extensions.get("error_code"),
+            Some(&Value::from("EMAIL_ALREADY_IN_USE"))
+        );
+    }
 }
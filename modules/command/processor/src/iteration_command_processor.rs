@@ -0,0 +1,335 @@
+use event_store_adapter_rs::types::{Aggregate, Event};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use command_domain::description::Description;
+use command_domain::iteration::{
+    Iteration, IterationError, IterationEvent, IterationId, IterationName, IterationService,
+};
+use command_domain::period::Period;
+use command_domain::project::{Project, ProjectId};
+use command_domain::user::UserId;
+use command_interface_adaptor_if::{
+    IterationIndex, IterationIndexError, IterationRepository, IterationRepositoryError, ProjectRepository,
+    ProjectRepositoryError,
+};
+
+#[derive(Error, Debug)]
+pub enum IterationCommandProcessError {
+    #[error("Project not found.")]
+    ProjectNotFoundError,
+    #[error("Iteration not found.")]
+    IterationNotFoundError,
+    #[error("ProjectRepositoryError: {0:?}")]
+    ProjectRepositoryError(#[from] ProjectRepositoryError),
+    #[error("IterationRepositoryError: {0:?}")]
+    IterationRepositoryError(#[from] IterationRepositoryError),
+    #[error("IterationIndexError: {0:?}")]
+    IterationIndexError(#[from] IterationIndexError),
+    #[error("IterationError: {0:?}")]
+    DomainLogicError(#[from] IterationError),
+}
+
+pub struct IterationCommandProcessor<TR: ProjectRepository, IR: IterationRepository, IX: IterationIndex> {
+    project_repository: Arc<Mutex<TR>>,
+    iteration_repository: Arc<Mutex<IR>>,
+    iteration_index: Arc<Mutex<IX>>,
+}
+
+impl<TR: ProjectRepository, IR: IterationRepository, IX: IterationIndex> IterationCommandProcessor<TR, IR, IX> {
+    pub fn new(project_repository: TR, iteration_repository: IR, iteration_index: IX) -> Self {
+        Self {
+            project_repository: Arc::new(Mutex::new(project_repository)),
+            iteration_repository: Arc::new(Mutex::new(iteration_repository)),
+            iteration_index: Arc::new(Mutex::new(iteration_index)),
+        }
+    }
+
+    pub async fn create_iteration(
+        &mut self,
+        project_id: ProjectId,
+        name: IterationName,
+        description: Description,
+        period: Period,
+        executor_id: UserId,
+    ) -> Result<IterationId, IterationCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut iteration_repository_mg = self.iteration_repository.lock().await;
+        let mut iteration_index_mg = self.iteration_index.lock().await;
+
+        let project = Self::find_project(&*project_repository_mg, &project_id).await?;
+        let iterations = Self::find_iterations(&*iteration_repository_mg, &*iteration_index_mg, &project_id).await?;
+
+        let (iteration, iteration_event) = Iteration::new(&project, name, description, period, executor_id)
+            .map_err(IterationCommandProcessError::DomainLogicError)?;
+        IterationService::ensure_no_overlap(&iterations, None, iteration.period())
+            .map_err(IterationCommandProcessError::DomainLogicError)?;
+
+        // 索引への追加は冪等なため先に行い、保存に失敗しても索引から漏れたイテレーションが残らないようにする
+        iteration_index_mg
+            .add(&project_id, iteration.id())
+            .await
+            .map_err(IterationCommandProcessError::IterationIndexError)?;
+        iteration_repository_mg
+            .store(&iteration_event, &iteration)
+            .await
+            .map(|_| iteration_event.aggregate_id().clone())
+            .map_err(IterationCommandProcessError::IterationRepositoryError)
+    }
+
+    pub async fn rename_iteration(
+        &mut self,
+        iteration_id: IterationId,
+        new_name: IterationName,
+        executor_id: UserId,
+    ) -> Result<IterationId, IterationCommandProcessError> {
+        self.update_iteration(iteration_id, |iteration, project| {
+            iteration.rename(project, new_name, executor_id)
+        })
+        .await
+    }
+
+    pub async fn reschedule_iteration(
+        &mut self,
+        iteration_id: IterationId,
+        new_period: Period,
+        executor_id: UserId,
+    ) -> Result<IterationId, IterationCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut iteration_repository_mg = self.iteration_repository.lock().await;
+        let iteration_index_mg = self.iteration_index.lock().await;
+
+        let mut iteration = Self::find_iteration(&*iteration_repository_mg, &iteration_id).await?;
+        let project = Self::find_project(&*project_repository_mg, iteration.project_id()).await?;
+        let iterations = Self::find_iterations(
+            &*iteration_repository_mg,
+            &*iteration_index_mg,
+            iteration.project_id(),
+        )
+        .await?;
+
+        let iteration_event = iteration
+            .reschedule(&project, new_period, executor_id)
+            .map_err(IterationCommandProcessError::DomainLogicError)?;
+        IterationService::ensure_no_overlap(&iterations, Some(&iteration_id), &new_period)
+            .map_err(IterationCommandProcessError::DomainLogicError)?;
+
+        iteration_repository_mg
+            .store(&iteration_event, &iteration)
+            .await
+            .map(|_| iteration_event.aggregate_id().clone())
+            .map_err(IterationCommandProcessError::IterationRepositoryError)
+    }
+
+    pub async fn start_iteration(
+        &mut self,
+        iteration_id: IterationId,
+        executor_id: UserId,
+    ) -> Result<IterationId, IterationCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut iteration_repository_mg = self.iteration_repository.lock().await;
+        let iteration_index_mg = self.iteration_index.lock().await;
+
+        let mut iteration = Self::find_iteration(&*iteration_repository_mg, &iteration_id).await?;
+        let project = Self::find_project(&*project_repository_mg, iteration.project_id()).await?;
+        let iterations = Self::find_iterations(
+            &*iteration_repository_mg,
+            &*iteration_index_mg,
+            iteration.project_id(),
+        )
+        .await?;
+
+        let iteration_event = iteration
+            .start(&project, executor_id)
+            .map_err(IterationCommandProcessError::DomainLogicError)?;
+        IterationService::ensure_no_active_iteration(&iterations, &iteration_id)
+            .map_err(IterationCommandProcessError::DomainLogicError)?;
+
+        iteration_repository_mg
+            .store(&iteration_event, &iteration)
+            .await
+            .map(|_| iteration_event.aggregate_id().clone())
+            .map_err(IterationCommandProcessError::IterationRepositoryError)
+    }
+
+    pub async fn complete_iteration(
+        &mut self,
+        iteration_id: IterationId,
+        executor_id: UserId,
+    ) -> Result<IterationId, IterationCommandProcessError> {
+        self.update_iteration(iteration_id, |iteration, project| {
+            iteration.complete(project, executor_id)
+        })
+        .await
+    }
+
+    /// 完了したイテレーションの未完了タスクを引き継ぐイテレーションを返す。
+    ///
+    /// # 戻り値
+    /// - 引き継ぐイテレーションのID。該当するイテレーションがない場合は `None`
+    pub async fn find_carry_over_destination(
+        &self,
+        iteration_id: IterationId,
+    ) -> Result<Option<IterationId>, IterationCommandProcessError> {
+        let iteration_repository_mg = self.iteration_repository.lock().await;
+        let iteration_index_mg = self.iteration_index.lock().await;
+
+        let iteration = Self::find_iteration(&*iteration_repository_mg, &iteration_id).await?;
+        let iterations = Self::find_iterations(
+            &*iteration_repository_mg,
+            &*iteration_index_mg,
+            iteration.project_id(),
+        )
+        .await?;
+        Ok(IterationService::find_carry_over_destination(
+            &iterations,
+            &iteration,
+        ))
+    }
+
+    /// イテレーションと所属するプロジェクトを取得し、コマンドを実行してイベントを保存する。
+    async fn update_iteration<F>(
+        &mut self,
+        iteration_id: IterationId,
+        command: F,
+    ) -> Result<IterationId, IterationCommandProcessError>
+    where
+        F: FnOnce(&mut Iteration, &Project) -> Result<IterationEvent, IterationError>,
+    {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut iteration_repository_mg = self.iteration_repository.lock().await;
+
+        let mut iteration = Self::find_iteration(&*iteration_repository_mg, &iteration_id).await?;
+        let project = Self::find_project(&*project_repository_mg, iteration.project_id()).await?;
+
+        let iteration_event =
+            command(&mut iteration, &project).map_err(IterationCommandProcessError::DomainLogicError)?;
+
+        iteration_repository_mg
+            .store(&iteration_event, &iteration)
+            .await
+            .map(|_| iteration_event.aggregate_id().clone())
+            .map_err(IterationCommandProcessError::IterationRepositoryError)
+    }
+
+    async fn find_project(
+        project_repository: &TR,
+        project_id: &ProjectId,
+    ) -> Result<Project, IterationCommandProcessError> {
+        project_repository
+            .find_by_id(project_id)
+            .await
+            .map_err(IterationCommandProcessError::ProjectRepositoryError)?
+            .ok_or(IterationCommandProcessError::ProjectNotFoundError)
+    }
+
+    async fn find_iteration(
+        iteration_repository: &IR,
+        iteration_id: &IterationId,
+    ) -> Result<Iteration, IterationCommandProcessError> {
+        iteration_repository
+            .find_by_id(iteration_id)
+            .await
+            .map_err(IterationCommandProcessError::IterationRepositoryError)?
+            .ok_or(IterationCommandProcessError::IterationNotFoundError)
+    }
+
+    /// 索引からプロジェクトに属するイテレーションを取得する。
+    ///
+    /// 保存に失敗したイテレーションの ID が索引に残っている場合があるため、存在しないイテレーションは除外する。
+    async fn find_iterations(
+        iteration_repository: &IR,
+        iteration_index: &IX,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Iteration>, IterationCommandProcessError> {
+        let iteration_ids = iteration_index
+            .find_by_project_id(project_id)
+            .await
+            .map_err(IterationCommandProcessError::IterationIndexError)?;
+        let mut iterations = Vec::with_capacity(iteration_ids.len());
+        for iteration_id in iteration_ids {
+            if let Some(iteration) = iteration_repository
+                .find_by_id(&iteration_id)
+                .await
+                .map_err(IterationCommandProcessError::IterationRepositoryError)?
+            {
+                iterations.push(iteration);
+            }
+        }
+        Ok(iterations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::InMemoryIterationIndex;
+    use crate::test_support::InMemoryIterationRepository;
+    use crate::test_support::InMemoryProjectRepository;
+    use chrono::NaiveDate;
+    use command_domain::project::{Members, ProjectName};
+
+    fn period(start_day: u32, end_day: u32) -> Period {
+        Period::new(
+            NaiveDate::from_ymd_opt(2025, 4, start_day).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 4, end_day).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_create_iteration_after_store_failure() {
+        let owner_id = UserId::new();
+        let (project, project_event) = Project::new(
+            ProjectName::new("Alpha").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        let mut project_repository = InMemoryProjectRepository::new();
+        project_repository.store(&project_event, &project).await.unwrap();
+        let iteration_repository = InMemoryIterationRepository::new();
+        let iteration_index = InMemoryIterationIndex::new();
+        let mut processor = IterationCommandProcessor::new(
+            project_repository,
+            iteration_repository.clone(),
+            iteration_index.clone(),
+        );
+
+        iteration_repository.fail_next_store();
+        let result = processor
+            .create_iteration(
+                project.id().clone(),
+                IterationName::new("Sprint 1").unwrap(),
+                Description::default(),
+                period(1, 14),
+                owner_id.clone(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(IterationCommandProcessError::IterationRepositoryError(_))
+        ));
+
+        // 保存に失敗したイテレーションは索引に残っても期間の重複の判定に影響しない
+        let iteration_id = processor
+            .create_iteration(
+                project.id().clone(),
+                IterationName::new("Sprint 1").unwrap(),
+                Description::default(),
+                period(1, 14),
+                owner_id,
+            )
+            .await
+            .unwrap();
+        assert!(iteration_repository.find_by_id(&iteration_id).await.unwrap().is_some());
+        assert!(
+            iteration_index
+                .find_by_project_id(project.id())
+                .await
+                .unwrap()
+                .contains(&iteration_id)
+        );
+    }
+}
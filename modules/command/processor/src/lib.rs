@@ -1 +1,8 @@
+pub mod iteration_command_processor;
 pub mod project_command_processor;
+pub mod project_invitation_command_processor;
+pub mod task_command_processor;
+pub mod user_command_processor;
+
+#[cfg(test)]
+mod test_support;
@@ -1,11 +1,19 @@
-use event_store_adapter_rs::types::Event;
+use chrono::Duration;
+use event_store_adapter_rs::types::{Aggregate, Event};
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::Mutex;
 
-use command_domain::project::{MemberId, MemberRole, Members, Project, ProjectError, ProjectId, ProjectName};
+use command_domain::description::Description;
+use command_domain::project::{
+    DEFAULT_RESTORE_GRACE_PERIOD_DAYS, MemberId, MemberRole, Members, Project, ProjectError, ProjectId, ProjectName,
+    TagId, TagName, Workflow,
+};
+use command_domain::task::TaskStatus;
 use command_domain::user::UserId;
-use command_interface_adaptor_if::{ProjectRepository, ProjectRepositoryError};
+use command_interface_adaptor_if::{
+    ProjectNameReservation, ProjectNameReservationError, ProjectRepository, ProjectRepositoryError,
+};
 
 #[derive(Error, Debug)]
 pub enum CommandProcessError {
@@ -15,34 +23,61 @@ pub enum CommandProcessError {
     RepositoryError(#[from] ProjectRepositoryError),
     #[error("ProjectError: {0:?}")]
     DomainLogicError(#[from] ProjectError),
+    #[error("ProjectNameReservationError: {0:?}")]
+    ReservationError(ProjectNameReservationError),
 }
 
-pub struct ProjectCommandProcessor<TR: ProjectRepository> {
+impl From<ProjectNameReservationError> for CommandProcessError {
+    fn from(error: ProjectNameReservationError) -> Self {
+        match error {
+            ProjectNameReservationError::AlreadyReservedError(name, reserved_by) => {
+                CommandProcessError::DomainLogicError(ProjectError::AlreadyExistsNameError(reserved_by, name))
+            },
+            error => CommandProcessError::ReservationError(error),
+        }
+    }
+}
+
+pub struct ProjectCommandProcessor<TR: ProjectRepository, NR: ProjectNameReservation> {
     project_repository: Arc<Mutex<TR>>,
+    project_name_reservation: Arc<Mutex<NR>>,
+    restore_grace_period: Duration,
 }
 
-impl<TR: ProjectRepository> ProjectCommandProcessor<TR> {
-    pub fn new(project_repository: TR) -> Self {
+impl<TR: ProjectRepository, NR: ProjectNameReservation> ProjectCommandProcessor<TR, NR> {
+    pub fn new(project_repository: TR, project_name_reservation: NR) -> Self {
         Self {
             project_repository: Arc::new(Mutex::new(project_repository)),
+            project_name_reservation: Arc::new(Mutex::new(project_name_reservation)),
+            restore_grace_period: Duration::days(DEFAULT_RESTORE_GRACE_PERIOD_DAYS),
         }
     }
 
+    /// 削除されたプロジェクトを復元できる猶予期間を設定する。
+    pub fn with_restore_grace_period(mut self, restore_grace_period: Duration) -> Self {
+        self.restore_grace_period = restore_grace_period;
+        self
+    }
+
     pub async fn create_project(
         &mut self,
         name: ProjectName,
+        description: Description,
         executor_id: UserId,
     ) -> Result<ProjectId, CommandProcessError> {
         let mut repository_mg = self.project_repository.lock().await;
+        let mut reservation_mg = self.project_name_reservation.lock().await;
 
         let members = Members::new(executor_id.clone());
-        let (project, project_event) = Project::new(name, members, executor_id);
+        let (project, project_event) = Project::new(name.clone(), description, members, executor_id);
 
-        repository_mg
-            .store(&project_event, &project)
-            .await
-            .map(|_| project_event.aggregate_id().clone())
-            .map_err(CommandProcessError::RepositoryError)
+        reservation_mg.reserve(&name, project.id()).await?;
+
+        if let Err(error) = repository_mg.store(&project_event, &project).await {
+            Self::release_reservation(&mut *reservation_mg, &name, project.id()).await;
+            return Err(CommandProcessError::RepositoryError(error));
+        }
+        Ok(project_event.aggregate_id().clone())
     }
 
     pub async fn add_member(
@@ -97,6 +132,79 @@ impl<TR: ProjectRepository> ProjectCommandProcessor<TR> {
             .map_err(CommandProcessError::RepositoryError)
     }
 
+    pub async fn leave_project(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project.leave(executor_id).map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn change_member_role(
+        &mut self,
+        project_id: ProjectId,
+        user_id: UserId,
+        new_role: MemberRole,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project
+            .change_member_role(user_id, new_role, executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn transfer_ownership(
+        &mut self,
+        project_id: ProjectId,
+        new_owner_id: UserId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project
+            .transfer_ownership(new_owner_id, executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
     pub async fn rename_project(
         &mut self,
         project_id: ProjectId,
@@ -105,6 +213,150 @@ impl<TR: ProjectRepository> ProjectCommandProcessor<TR> {
     ) -> Result<ProjectId, CommandProcessError> {
         let mut repository_mg = self.project_repository.lock().await;
 
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let old_name = project.name().clone();
+        let project_event = project
+            .rename(new_name.clone(), executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        if old_name == new_name {
+            return repository_mg
+                .store(&project_event, &project)
+                .await
+                .map(|_| project_event.aggregate_id().clone())
+                .map_err(CommandProcessError::RepositoryError);
+        }
+
+        let mut reservation_mg = self.project_name_reservation.lock().await;
+        reservation_mg.reserve(&new_name, &project_id).await?;
+
+        if let Err(error) = repository_mg.store(&project_event, &project).await {
+            Self::release_reservation(&mut *reservation_mg, &new_name, &project_id).await;
+            return Err(CommandProcessError::RepositoryError(error));
+        }
+        Self::release_reservation(&mut *reservation_mg, &old_name, &project_id).await;
+        Ok(project_event.aggregate_id().clone())
+    }
+
+    pub async fn change_project_description(
+        &mut self,
+        project_id: ProjectId,
+        new_description: Description,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project
+            .change_description(new_description, executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    /// ワークフローを定義する。
+    ///
+    /// statuses_in_use にはプロジェクトのタスクが利用している状態を渡す。
+    pub async fn define_workflow(
+        &mut self,
+        project_id: ProjectId,
+        workflow: Workflow,
+        statuses_in_use: Vec<TaskStatus>,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project
+            .define_workflow(workflow, &statuses_in_use, executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn create_tag(
+        &mut self,
+        project_id: ProjectId,
+        name: TagName,
+        executor_id: UserId,
+    ) -> Result<TagId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let tag_id = TagId::new();
+        let project_event = project
+            .create_tag(tag_id.clone(), name, executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| tag_id)
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn rename_tag(
+        &mut self,
+        project_id: ProjectId,
+        tag_id: TagId,
+        new_name: TagName,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project
+            .rename_tag(tag_id, new_name, executor_id)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn delete_tag(
+        &mut self,
+        project_id: ProjectId,
+        tag_id: TagId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
         let mut project = repository_mg
             .find_by_id(&project_id)
             .await
@@ -112,7 +364,7 @@ impl<TR: ProjectRepository> ProjectCommandProcessor<TR> {
             .ok_or(CommandProcessError::NotFoundError)?;
 
         let project_event = project
-            .rename(new_name, executor_id)
+            .delete_tag(tag_id, executor_id)
             .map_err(CommandProcessError::DomainLogicError)?;
 
         repository_mg
@@ -137,10 +389,304 @@ impl<TR: ProjectRepository> ProjectCommandProcessor<TR> {
 
         let project_event = project.delete(executor_id).map_err(CommandProcessError::DomainLogicError)?;
 
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?;
+
+        let mut reservation_mg = self.project_name_reservation.lock().await;
+        Self::release_reservation(&mut *reservation_mg, project.name(), &project_id).await;
+        Ok(project_event.aggregate_id().clone())
+    }
+
+    pub async fn restore_project(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project
+            .restore(executor_id, self.restore_grace_period)
+            .map_err(CommandProcessError::DomainLogicError)?;
+
+        // 削除中に同じ名前が他のプロジェクトに使われている場合は復元できない
+        let mut reservation_mg = self.project_name_reservation.lock().await;
+        reservation_mg.reserve(project.name(), &project_id).await?;
+
+        if let Err(error) = repository_mg.store(&project_event, &project).await {
+            Self::release_reservation(&mut *reservation_mg, project.name(), &project_id).await;
+            return Err(CommandProcessError::RepositoryError(error));
+        }
+        Ok(project_event.aggregate_id().clone())
+    }
+
+    pub async fn start_project(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project.start(executor_id).map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn complete_project(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project.complete(executor_id).map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn archive_project(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project.archive(executor_id).map_err(CommandProcessError::DomainLogicError)?;
+
+        repository_mg
+            .store(&project_event, &project)
+            .await
+            .map(|_| project_event.aggregate_id().clone())
+            .map_err(CommandProcessError::RepositoryError)
+    }
+
+    pub async fn reopen_project(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, CommandProcessError> {
+        let mut repository_mg = self.project_repository.lock().await;
+
+        let mut project = repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(CommandProcessError::RepositoryError)?
+            .ok_or(CommandProcessError::NotFoundError)?;
+
+        let project_event = project.reopen(executor_id).map_err(CommandProcessError::DomainLogicError)?;
+
         repository_mg
             .store(&project_event, &project)
             .await
             .map(|_| project_event.aggregate_id().clone())
             .map_err(CommandProcessError::RepositoryError)
     }
+
+    /// プロジェクト名の予約を解放する。
+    ///
+    /// 解放は保存の成否が確定した後に行うため、失敗してもコマンドの結果は変えずにログに記録する。
+    async fn release_reservation(reservation: &mut NR, name: &ProjectName, project_id: &ProjectId) {
+        if let Err(error) = reservation.release(name, project_id).await {
+            log::error!(
+                "Failed to release the project name reservation: name = {}, project_id = {}, error = {:?}",
+                name,
+                project_id,
+                error
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::InMemoryProjectNameReservation;
+    use crate::test_support::InMemoryProjectRepository;
+
+    type TestProcessor = ProjectCommandProcessor<InMemoryProjectRepository, InMemoryProjectNameReservation>;
+
+    fn create_processor() -> TestProcessor {
+        ProjectCommandProcessor::new(
+            InMemoryProjectRepository::new(),
+            InMemoryProjectNameReservation::new(),
+        )
+    }
+
+    fn name(value: &str) -> ProjectName {
+        ProjectName::new(value).unwrap()
+    }
+
+    fn is_already_exists_name_error<T>(result: &Result<T, CommandProcessError>) -> bool {
+        matches!(
+            result,
+            Err(CommandProcessError::DomainLogicError(
+                ProjectError::AlreadyExistsNameError(_, _)
+            ))
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_project_with_duplicate_name() {
+        let mut processor = create_processor();
+        let executor_id = UserId::new();
+
+        let project_id = processor
+            .create_project(name("Alpha"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+
+        let result = processor
+            .create_project(name("Alpha"), Description::default(), UserId::new())
+            .await;
+        assert!(matches!(
+            result,
+            Err(CommandProcessError::DomainLogicError(ProjectError::AlreadyExistsNameError(reserved_by, _)))
+                if reserved_by == project_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rename_project_releases_old_name() {
+        let mut processor = create_processor();
+        let executor_id = UserId::new();
+
+        let project_id = processor
+            .create_project(name("Alpha"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+        let other_project_id = processor
+            .create_project(name("Beta"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+
+        let result = processor
+            .rename_project(other_project_id, name("Alpha"), executor_id.clone())
+            .await;
+        assert!(is_already_exists_name_error(&result));
+
+        processor
+            .rename_project(project_id, name("Gamma"), executor_id.clone())
+            .await
+            .unwrap();
+        processor
+            .create_project(name("Alpha"), Description::default(), executor_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_project_releases_name() {
+        let mut processor = create_processor();
+        let executor_id = UserId::new();
+
+        let project_id = processor
+            .create_project(name("Alpha"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+        processor.delete_project(project_id, executor_id.clone()).await.unwrap();
+
+        processor
+            .create_project(name("Alpha"), Description::default(), executor_id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_restore_project_with_taken_name() {
+        let mut processor = create_processor();
+        let executor_id = UserId::new();
+
+        let project_id = processor
+            .create_project(name("Alpha"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+        processor.delete_project(project_id.clone(), executor_id.clone()).await.unwrap();
+        let other_project_id = processor
+            .create_project(name("Alpha"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+
+        let result = processor.restore_project(project_id.clone(), executor_id.clone()).await;
+        assert!(is_already_exists_name_error(&result));
+
+        // 名前が解放されれば復元できる
+        processor.delete_project(other_project_id, executor_id.clone()).await.unwrap();
+        processor.restore_project(project_id, executor_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_project_returns_store_error_when_release_fails() {
+        let repository = InMemoryProjectRepository::new();
+        let reservation = InMemoryProjectNameReservation::new();
+        let mut processor = ProjectCommandProcessor::new(repository.clone(), reservation.clone());
+
+        repository.fail_next_store();
+        reservation.fail_releases();
+        let result = processor
+            .create_project(name("Alpha"), Description::default(), UserId::new())
+            .await;
+        assert!(matches!(
+            result,
+            Err(CommandProcessError::RepositoryError(
+                ProjectRepositoryError::StoreError(_, _)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rename_and_delete_project_succeed_when_release_fails() {
+        let repository = InMemoryProjectRepository::new();
+        let reservation = InMemoryProjectNameReservation::new();
+        let mut processor = ProjectCommandProcessor::new(repository.clone(), reservation.clone());
+        let executor_id = UserId::new();
+
+        let project_id = processor
+            .create_project(name("Alpha"), Description::default(), executor_id.clone())
+            .await
+            .unwrap();
+        reservation.fail_releases();
+
+        processor
+            .rename_project(project_id.clone(), name("Beta"), executor_id.clone())
+            .await
+            .unwrap();
+        processor.delete_project(project_id.clone(), executor_id).await.unwrap();
+
+        let project = repository.find_by_id(&project_id).await.unwrap().unwrap();
+        assert_eq!(project.name(), &name("Beta"));
+        assert!(project.is_deleted());
+    }
 }
@@ -0,0 +1,344 @@
+use chrono::Utc;
+use event_store_adapter_rs::types::Event;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use command_domain::email::Email;
+use command_domain::project::{MemberId, MemberRole, ProjectError, ProjectId};
+use command_domain::project_invitation::{ProjectInvitation, ProjectInvitationError, ProjectInvitationId};
+use command_domain::user::{User, UserId};
+use command_interface_adaptor_if::{
+    ProjectInvitationRepository, ProjectInvitationRepositoryError, ProjectRepository, ProjectRepositoryError,
+    UserRepository, UserRepositoryError,
+};
+
+#[derive(Error, Debug)]
+pub enum ProjectInvitationCommandProcessError {
+    #[error("Project not found.")]
+    ProjectNotFoundError,
+    #[error("Project invitation not found.")]
+    InvitationNotFoundError,
+    #[error("User not found.")]
+    UserNotFoundError,
+    #[error("ProjectRepositoryError: {0:?}")]
+    ProjectRepositoryError(#[from] ProjectRepositoryError),
+    #[error("ProjectInvitationRepositoryError: {0:?}")]
+    InvitationRepositoryError(#[from] ProjectInvitationRepositoryError),
+    #[error("UserRepositoryError: {0:?}")]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error("ProjectError: {0:?}")]
+    ProjectDomainLogicError(#[from] ProjectError),
+    #[error("ProjectInvitationError: {0:?}")]
+    InvitationDomainLogicError(#[from] ProjectInvitationError),
+}
+
+pub struct ProjectInvitationCommandProcessor<TR: ProjectRepository, IR: ProjectInvitationRepository, UR: UserRepository>
+{
+    project_repository: Arc<Mutex<TR>>,
+    invitation_repository: Arc<Mutex<IR>>,
+    user_repository: Arc<Mutex<UR>>,
+}
+
+impl<TR: ProjectRepository, IR: ProjectInvitationRepository, UR: UserRepository>
+    ProjectInvitationCommandProcessor<TR, IR, UR>
+{
+    pub fn new(project_repository: TR, invitation_repository: IR, user_repository: UR) -> Self {
+        Self {
+            project_repository: Arc::new(Mutex::new(project_repository)),
+            invitation_repository: Arc::new(Mutex::new(invitation_repository)),
+            user_repository: Arc::new(Mutex::new(user_repository)),
+        }
+    }
+
+    pub async fn send_invitation(
+        &mut self,
+        project_id: ProjectId,
+        email: Email,
+        role: MemberRole,
+        executor_id: UserId,
+    ) -> Result<ProjectInvitationId, ProjectInvitationCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut invitation_repository_mg = self.invitation_repository.lock().await;
+
+        let project = project_repository_mg
+            .find_by_id(&project_id)
+            .await
+            .map_err(ProjectInvitationCommandProcessError::ProjectRepositoryError)?
+            .ok_or(ProjectInvitationCommandProcessError::ProjectNotFoundError)?;
+
+        let (invitation, invitation_event) = ProjectInvitation::new(&project, email, role, executor_id, Utc::now())
+            .map_err(ProjectInvitationCommandProcessError::InvitationDomainLogicError)?;
+
+        invitation_repository_mg
+            .store(&invitation_event, &invitation)
+            .await
+            .map(|_| invitation_event.aggregate_id().clone())
+            .map_err(ProjectInvitationCommandProcessError::InvitationRepositoryError)
+    }
+
+    /// 招待を承諾し、承諾したユーザをプロジェクトのメンバーに追加する。
+    ///
+    /// 有効期限が切れている場合は InvitationExpired イベントを保存した上でエラーを返す。
+    /// メンバーの追加を保存した後に招待の保存に失敗した場合に再試行できるよう、
+    /// 承諾したユーザが既にメンバーの場合は招待の承諾だけを保存する。
+    pub async fn accept_invitation(
+        &mut self,
+        invitation_id: ProjectInvitationId,
+        executor_id: UserId,
+    ) -> Result<ProjectId, ProjectInvitationCommandProcessError> {
+        let mut project_repository_mg = self.project_repository.lock().await;
+        let mut invitation_repository_mg = self.invitation_repository.lock().await;
+        let user_repository_mg = self.user_repository.lock().await;
+
+        let executor = Self::find_user(&*user_repository_mg, &executor_id).await?;
+
+        let mut invitation = invitation_repository_mg
+            .find_by_id(&invitation_id)
+            .await
+            .map_err(ProjectInvitationCommandProcessError::InvitationRepositoryError)?
+            .ok_or(ProjectInvitationCommandProcessError::InvitationNotFoundError)?;
+
+        let mut project = project_repository_mg
+            .find_by_id(invitation.project_id())
+            .await
+            .map_err(ProjectInvitationCommandProcessError::ProjectRepositoryError)?
+            .ok_or(ProjectInvitationCommandProcessError::ProjectNotFoundError)?;
+
+        let now = Utc::now();
+        let invitation_event = match invitation.accept(&executor, now) {
+            Ok(event) => event,
+            Err(error @ ProjectInvitationError::ExpiredError(_)) => {
+                let expired_event = invitation.expire(now)?;
+                invitation_repository_mg
+                    .store(&expired_event, &invitation)
+                    .await
+                    .map_err(ProjectInvitationCommandProcessError::InvitationRepositoryError)?;
+                return Err(ProjectInvitationCommandProcessError::InvitationDomainLogicError(error));
+            },
+            Err(error) => return Err(ProjectInvitationCommandProcessError::InvitationDomainLogicError(error)),
+        };
+
+        if !project.members().is_member(&executor_id) {
+            let project_event = project
+                .join_by_invitation(&invitation, MemberId::new(), executor_id)
+                .map_err(ProjectInvitationCommandProcessError::ProjectDomainLogicError)?;
+            project_repository_mg
+                .store(&project_event, &project)
+                .await
+                .map_err(ProjectInvitationCommandProcessError::ProjectRepositoryError)?;
+        }
+
+        invitation_repository_mg
+            .store(&invitation_event, &invitation)
+            .await
+            .map(|_| invitation.project_id().clone())
+            .map_err(ProjectInvitationCommandProcessError::InvitationRepositoryError)
+    }
+
+    pub async fn decline_invitation(
+        &mut self,
+        invitation_id: ProjectInvitationId,
+        executor_id: UserId,
+    ) -> Result<ProjectInvitationId, ProjectInvitationCommandProcessError> {
+        let mut invitation_repository_mg = self.invitation_repository.lock().await;
+        let user_repository_mg = self.user_repository.lock().await;
+
+        let executor = Self::find_user(&*user_repository_mg, &executor_id).await?;
+
+        let mut invitation = invitation_repository_mg
+            .find_by_id(&invitation_id)
+            .await
+            .map_err(ProjectInvitationCommandProcessError::InvitationRepositoryError)?
+            .ok_or(ProjectInvitationCommandProcessError::InvitationNotFoundError)?;
+
+        let invitation_event = invitation
+            .decline(&executor, Utc::now())
+            .map_err(ProjectInvitationCommandProcessError::InvitationDomainLogicError)?;
+
+        invitation_repository_mg
+            .store(&invitation_event, &invitation)
+            .await
+            .map(|_| invitation_event.aggregate_id().clone())
+            .map_err(ProjectInvitationCommandProcessError::InvitationRepositoryError)
+    }
+
+    async fn find_user(user_repository: &UR, user_id: &UserId) -> Result<User, ProjectInvitationCommandProcessError> {
+        user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(ProjectInvitationCommandProcessError::UserRepositoryError)?
+            .ok_or(ProjectInvitationCommandProcessError::UserNotFoundError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::InMemoryProjectInvitationRepository;
+    use crate::test_support::InMemoryProjectRepository;
+    use crate::test_support::InMemoryUserRepository;
+    use command_domain::description::Description;
+    use command_domain::project::{Members, Project, ProjectName};
+    use command_domain::project_invitation::ProjectInvitationStatus;
+    use command_domain::user::UserName;
+    use event_store_adapter_rs::types::Aggregate;
+
+    type TestProcessor = ProjectInvitationCommandProcessor<
+        InMemoryProjectRepository,
+        InMemoryProjectInvitationRepository,
+        InMemoryUserRepository,
+    >;
+
+    async fn create_user(user_repository: &mut InMemoryUserRepository, email: &str) -> UserId {
+        let (user, user_event) = User::new(UserName::new("user").unwrap(), Email::new(email).unwrap());
+        user_repository.store(&user_event, &user).await.unwrap();
+        user.id().clone()
+    }
+
+    struct Fixture {
+        processor: TestProcessor,
+        project_repository: InMemoryProjectRepository,
+        invitation_repository: InMemoryProjectInvitationRepository,
+        invitation_id: ProjectInvitationId,
+        invitee_id: UserId,
+        other_id: UserId,
+    }
+
+    async fn create_fixture() -> Fixture {
+        let mut project_repository = InMemoryProjectRepository::new();
+        let mut user_repository = InMemoryUserRepository::new();
+        let owner_id = create_user(&mut user_repository, "owner@example.com").await;
+        let invitee_id = create_user(&mut user_repository, "Invitee@Example.com").await;
+        let other_id = create_user(&mut user_repository, "other@example.com").await;
+
+        let (project, project_event) = Project::new(
+            ProjectName::new("Test").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        project_repository.store(&project_event, &project).await.unwrap();
+
+        let invitation_repository = InMemoryProjectInvitationRepository::new();
+        let mut processor = ProjectInvitationCommandProcessor::new(
+            project_repository.clone(),
+            invitation_repository.clone(),
+            user_repository,
+        );
+        let invitation_id = processor
+            .send_invitation(
+                project.id().clone(),
+                Email::new("invitee@example.com").unwrap(),
+                MemberRole::Member,
+                owner_id,
+            )
+            .await
+            .unwrap();
+        Fixture {
+            processor,
+            project_repository,
+            invitation_repository,
+            invitation_id,
+            invitee_id,
+            other_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation() {
+        let Fixture {
+            mut processor,
+            project_repository,
+            invitation_id,
+            invitee_id,
+            ..
+        } = create_fixture().await;
+
+        let project_id = processor
+            .accept_invitation(invitation_id.clone(), invitee_id.clone())
+            .await
+            .unwrap();
+        let project = project_repository.find_by_id(&project_id).await.unwrap().unwrap();
+        assert!(project.members().is_member(&invitee_id));
+
+        let result = processor.accept_invitation(invitation_id, invitee_id).await;
+        assert!(matches!(
+            result,
+            Err(
+                ProjectInvitationCommandProcessError::InvitationDomainLogicError(
+                    ProjectInvitationError::NotPendingError(_, _)
+                )
+            )
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retry_accept_invitation_after_invitation_store_failure() {
+        let Fixture {
+            mut processor,
+            project_repository,
+            invitation_repository,
+            invitation_id,
+            invitee_id,
+            ..
+        } = create_fixture().await;
+
+        invitation_repository.fail_next_store();
+        let result = processor.accept_invitation(invitation_id.clone(), invitee_id.clone()).await;
+        assert!(matches!(
+            result,
+            Err(ProjectInvitationCommandProcessError::InvitationRepositoryError(_))
+        ));
+
+        let project_id = processor
+            .accept_invitation(invitation_id.clone(), invitee_id.clone())
+            .await
+            .unwrap();
+        let project = project_repository.find_by_id(&project_id).await.unwrap().unwrap();
+        let invitation = invitation_repository.find_by_id(&invitation_id).await.unwrap().unwrap();
+        assert_eq!(
+            project
+                .members()
+                .to_vec()
+                .into_iter()
+                .filter(|member| member.breach_encapsulation_of_user_id() == &invitee_id)
+                .count(),
+            1
+        );
+        assert_eq!(invitation.status(), &ProjectInvitationStatus::Accepted);
+    }
+
+    #[tokio::test]
+    async fn test_accept_and_decline_invitation_by_other_user() {
+        let Fixture {
+            mut processor,
+            invitation_id,
+            invitee_id,
+            other_id,
+            ..
+        } = create_fixture().await;
+
+        let result = processor.accept_invitation(invitation_id.clone(), other_id.clone()).await;
+        assert!(matches!(
+            result,
+            Err(
+                ProjectInvitationCommandProcessError::InvitationDomainLogicError(
+                    ProjectInvitationError::NotInviteeError(_, _)
+                )
+            )
+        ));
+
+        let result = processor.decline_invitation(invitation_id.clone(), other_id).await;
+        assert!(matches!(
+            result,
+            Err(
+                ProjectInvitationCommandProcessError::InvitationDomainLogicError(
+                    ProjectInvitationError::NotInviteeError(_, _)
+                )
+            )
+        ));
+
+        processor.decline_invitation(invitation_id, invitee_id).await.unwrap();
+    }
+}
@@ -0,0 +1,1011 @@
+use event_store_adapter_rs::types::{Aggregate, Event};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use command_domain::description::Description;
+use command_domain::iteration::{Iteration, IterationId};
+use command_domain::period::Period;
+use command_domain::project::{MemberId, Permission, Project, ProjectId, TagId};
+use command_domain::task::{
+    CommentBody, CommentId, Priority, Task, TaskError, TaskEvent, TaskId, TaskStatus, TaskTitle,
+};
+use command_domain::user::UserId;
+use command_interface_adaptor_if::{
+    IterationRepository, IterationRepositoryError, ProjectRepository, ProjectRepositoryError, TaskIndex,
+    TaskIndexError, TaskRepository, TaskRepositoryError,
+};
+
+#[derive(Error, Debug)]
+pub enum TaskCommandProcessError {
+    #[error("Project not found.")]
+    ProjectNotFoundError,
+    #[error("Task not found.")]
+    TaskNotFoundError,
+    #[error("Iteration not found.")]
+    IterationNotFoundError,
+    #[error("ProjectRepositoryError: {0:?}")]
+    ProjectRepositoryError(#[from] ProjectRepositoryError),
+    #[error("IterationRepositoryError: {0:?}")]
+    IterationRepositoryError(#[from] IterationRepositoryError),
+    #[error("TaskRepositoryError: {0:?}")]
+    TaskRepositoryError(#[from] TaskRepositoryError),
+    #[error("TaskIndexError: {0:?}")]
+    TaskIndexError(#[from] TaskIndexError),
+    #[error("TaskError: {0:?}")]
+    DomainLogicError(#[from] TaskError),
+}
+
+pub struct TaskCommandProcessor<TR: ProjectRepository, IR: IterationRepository, KR: TaskRepository, KX: TaskIndex> {
+    project_repository: Arc<Mutex<TR>>,
+    iteration_repository: Arc<Mutex<IR>>,
+    task_repository: Arc<Mutex<KR>>,
+    task_index: Arc<Mutex<KX>>,
+}
+
+impl<TR: ProjectRepository, IR: IterationRepository, KR: TaskRepository, KX: TaskIndex>
+    TaskCommandProcessor<TR, IR, KR, KX>
+{
+    pub fn new(project_repository: TR, iteration_repository: IR, task_repository: KR, task_index: KX) -> Self {
+        Self {
+            project_repository: Arc::new(Mutex::new(project_repository)),
+            iteration_repository: Arc::new(Mutex::new(iteration_repository)),
+            task_repository: Arc::new(Mutex::new(task_repository)),
+            task_index: Arc::new(Mutex::new(task_index)),
+        }
+    }
+
+    pub async fn create_task(
+        &mut self,
+        project_id: ProjectId,
+        title: TaskTitle,
+        description: Description,
+        priority: Priority,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut task_repository_mg = self.task_repository.lock().await;
+
+        let project = Self::find_project(&*project_repository_mg, &project_id).await?;
+
+        let (task, task_event) = Task::new(&project, title, description, priority, executor_id)
+            .map_err(TaskCommandProcessError::DomainLogicError)?;
+
+        // 索引への追加は冪等なため先に行い、保存に失敗しても索引から漏れたタスクが残らないようにする
+        let mut task_index_mg = self.task_index.lock().await;
+        task_index_mg
+            .add(&project_id, task.id())
+            .await
+            .map_err(TaskCommandProcessError::TaskIndexError)?;
+        task_repository_mg
+            .store(&task_event, &task)
+            .await
+            .map(|_| task_event.aggregate_id().clone())
+            .map_err(TaskCommandProcessError::TaskRepositoryError)
+    }
+
+    pub async fn rename_task(
+        &mut self,
+        task_id: TaskId,
+        new_title: TaskTitle,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.rename(project, new_title, executor_id)
+        })
+        .await
+    }
+
+    pub async fn change_task_priority(
+        &mut self,
+        task_id: TaskId,
+        new_priority: Priority,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.change_priority(project, new_priority, executor_id)
+        })
+        .await
+    }
+
+    pub async fn change_task_status(
+        &mut self,
+        task_id: TaskId,
+        new_status: TaskStatus,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut task_repository_mg = self.task_repository.lock().await;
+
+        let mut task = Self::find_task_by_id(&*task_repository_mg, &task_id).await?;
+        let project = Self::find_project(&*project_repository_mg, task.project_id()).await?;
+        // 先行タスクは完了にする場合のみ検証に用いる。見つからない先行タスクはドメインでエラーになる
+        let mut blockers = Vec::new();
+        if new_status.is_done() {
+            for blocker_id in task.blocker_ids() {
+                if let Some(blocker) = task_repository_mg
+                    .find_by_id(blocker_id)
+                    .await
+                    .map_err(TaskCommandProcessError::TaskRepositoryError)?
+                {
+                    blockers.push(blocker);
+                }
+            }
+        }
+
+        let task_event = task
+            .change_status(&project, new_status, &blockers, executor_id)
+            .map_err(TaskCommandProcessError::DomainLogicError)?;
+
+        task_repository_mg
+            .store(&task_event, &task)
+            .await
+            .map(|_| task_event.aggregate_id().clone())
+            .map_err(TaskCommandProcessError::TaskRepositoryError)
+    }
+
+    pub async fn delete_task(
+        &mut self,
+        task_id: TaskId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| task.delete(project, executor_id))
+            .await
+    }
+
+    pub async fn assign_task(
+        &mut self,
+        task_id: TaskId,
+        assignee_id: MemberId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.assign(project, assignee_id, executor_id)
+        })
+        .await
+    }
+
+    pub async fn unassign_task(
+        &mut self,
+        task_id: TaskId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| task.unassign(project, executor_id))
+            .await
+    }
+
+    pub async fn schedule_task(
+        &mut self,
+        task_id: TaskId,
+        planned_period: Period,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.schedule(project, planned_period, executor_id)
+        })
+        .await
+    }
+
+    pub async fn tag_task(
+        &mut self,
+        task_id: TaskId,
+        tag_id: TagId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.tag(project, tag_id, executor_id)
+        })
+        .await
+    }
+
+    pub async fn untag_task(
+        &mut self,
+        task_id: TaskId,
+        tag_id: TagId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.untag(project, tag_id, executor_id)
+        })
+        .await
+    }
+
+    pub async fn move_task_to_iteration(
+        &mut self,
+        task_id: TaskId,
+        iteration_id: IterationId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        let iteration = {
+            let iteration_repository_mg = self.iteration_repository.lock().await;
+            Self::find_iteration(&*iteration_repository_mg, &iteration_id).await?
+        };
+        self.update_task(task_id, |task, project| {
+            task.move_to_iteration(project, &iteration, executor_id)
+        })
+        .await
+    }
+
+    pub async fn move_task_to_backlog(
+        &mut self,
+        task_id: TaskId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.move_to_backlog(project, executor_id)
+        })
+        .await
+    }
+
+    pub async fn add_task_dependency(
+        &mut self,
+        task_id: TaskId,
+        blocker_id: TaskId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut task_repository_mg = self.task_repository.lock().await;
+        let task_index_mg = self.task_index.lock().await;
+
+        let mut task = Self::find_task_by_id(&*task_repository_mg, &task_id).await?;
+        let blocker = Self::find_task_by_id(&*task_repository_mg, &blocker_id).await?;
+        let project = Self::find_project(&*project_repository_mg, task.project_id()).await?;
+        let tasks = Self::find_tasks_by_project_id(&*task_repository_mg, &*task_index_mg, task.project_id()).await?;
+
+        let task_event = task
+            .add_blocker(&project, &blocker, &tasks, executor_id)
+            .map_err(TaskCommandProcessError::DomainLogicError)?;
+
+        task_repository_mg
+            .store(&task_event, &task)
+            .await
+            .map(|_| task_event.aggregate_id().clone())
+            .map_err(TaskCommandProcessError::TaskRepositoryError)
+    }
+
+    pub async fn remove_task_dependency(
+        &mut self,
+        task_id: TaskId,
+        blocker_id: TaskId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.remove_blocker(project, blocker_id, executor_id)
+        })
+        .await
+    }
+
+    pub async fn add_comment(
+        &mut self,
+        task_id: TaskId,
+        body: CommentBody,
+        executor_id: UserId,
+    ) -> Result<CommentId, TaskCommandProcessError> {
+        let comment_id = CommentId::new();
+        self.update_task(task_id, |task, project| {
+            task.add_comment(project, comment_id.clone(), body, executor_id)
+        })
+        .await
+        .map(|_| comment_id)
+    }
+
+    pub async fn edit_comment(
+        &mut self,
+        task_id: TaskId,
+        comment_id: CommentId,
+        new_body: CommentBody,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.edit_comment(project, comment_id, new_body, executor_id)
+        })
+        .await
+    }
+
+    pub async fn delete_comment(
+        &mut self,
+        task_id: TaskId,
+        comment_id: CommentId,
+        executor_id: UserId,
+    ) -> Result<TaskId, TaskCommandProcessError> {
+        self.update_task(task_id, |task, project| {
+            task.delete_comment(project, comment_id, executor_id)
+        })
+        .await
+    }
+
+    /// タスクを取得する。
+    ///
+    /// 実行者がプロジェクトのメンバーでない場合は、タスクが存在しないものとして扱う。
+    pub async fn find_task(&self, task_id: TaskId, executor_id: UserId) -> Result<Task, TaskCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let task_repository_mg = self.task_repository.lock().await;
+
+        let task = Self::find_task_by_id(&*task_repository_mg, &task_id).await?;
+        let project = Self::find_project(&*project_repository_mg, task.project_id()).await?;
+        if !project.members().is_member(&executor_id) {
+            return Err(TaskCommandProcessError::TaskNotFoundError);
+        }
+        Ok(task)
+    }
+
+    /// プロジェクトに属するタスクを取得する。
+    ///
+    /// 実行者がプロジェクトのメンバーでない場合は、プロジェクトが存在しないものとして扱う。
+    pub async fn find_tasks(
+        &self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<Vec<Task>, TaskCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let task_repository_mg = self.task_repository.lock().await;
+        let task_index_mg = self.task_index.lock().await;
+
+        let project = Self::find_project(&*project_repository_mg, &project_id).await?;
+        if !project.members().is_member(&executor_id) {
+            return Err(TaskCommandProcessError::ProjectNotFoundError);
+        }
+        Self::find_tasks_by_project_id(&*task_repository_mg, &*task_index_mg, &project_id).await
+    }
+
+    /// プロジェクトの削除されていないタスクが利用している状態を返す。
+    ///
+    /// ワークフローの定義時に、タスクが遷移できなくなる状態がないかを検証するために利用する。
+    pub async fn find_statuses_in_use(
+        &self,
+        project_id: ProjectId,
+    ) -> Result<Vec<TaskStatus>, TaskCommandProcessError> {
+        let task_repository_mg = self.task_repository.lock().await;
+        let task_index_mg = self.task_index.lock().await;
+
+        let mut statuses = Vec::new();
+        for task in Self::find_tasks_by_project_id(&*task_repository_mg, &*task_index_mg, &project_id).await? {
+            if !task.is_deleted() && !statuses.contains(&task.status()) {
+                statuses.push(task.status());
+            }
+        }
+        Ok(statuses)
+    }
+
+    /// プロジェクトのメンバーが削除・脱退したときのイベントハンドラ。
+    ///
+    /// プロジェクトのメンバーでなくなった担当者の未完了タスクの割り当てを解除する。
+    /// 割り当てが解除済みのタスクは対象外となるため、途中で失敗しても再実行できる。
+    ///
+    /// # 戻り値
+    /// - 割り当てを解除したタスクのID
+    pub async fn handle_member_removed(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError> {
+        self.update_project_tasks(project_id, |task, project| {
+            task.release_removed_assignee(project, executor_id.clone())
+        })
+        .await
+    }
+
+    /// タグカタログからタグが削除されたときのイベントハンドラ。
+    ///
+    /// 削除されたタグをプロジェクトのタスクから外す。
+    /// タグを外し済みのタスクは対象外となるため、途中で失敗しても再実行できる。
+    ///
+    /// # 戻り値
+    /// - タグを外したタスクのID
+    pub async fn handle_tag_deleted(
+        &mut self,
+        project_id: ProjectId,
+        tag_id: TagId,
+        executor_id: UserId,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError> {
+        self.update_project_tasks(project_id, |task, project| {
+            task.release_deleted_tag(project, tag_id.clone(), executor_id.clone())
+        })
+        .await
+    }
+
+    /// イテレーションが完了したときのイベントハンドラ。
+    ///
+    /// 完了したイテレーションに残った未完了タスクを引き継ぎ先のイテレーションへ移動する。
+    /// 引き継ぎ先がない場合はバックログに戻す。
+    /// 移動済みのタスクは対象外となるため、途中で失敗しても再実行できる。
+    ///
+    /// # 戻り値
+    /// - 移動したタスクのID
+    pub async fn handle_iteration_completed(
+        &mut self,
+        iteration_id: IterationId,
+        destination_id: Option<IterationId>,
+        executor_id: UserId,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError> {
+        let (completed, destination) = {
+            let iteration_repository_mg = self.iteration_repository.lock().await;
+            let completed = Self::find_iteration(&*iteration_repository_mg, &iteration_id).await?;
+            let destination = match &destination_id {
+                Some(destination_id) => Some(Self::find_iteration(&*iteration_repository_mg, destination_id).await?),
+                None => None,
+            };
+            (completed, destination)
+        };
+        self.update_project_tasks(completed.project_id().clone(), |task, project| {
+            task.carry_over(
+                project,
+                &completed,
+                destination.as_ref(),
+                executor_id.clone(),
+            )
+        })
+        .await
+    }
+
+    /// メンバーでなくなった担当者の割り当ての解除を再実行する。
+    ///
+    /// メンバーの削除・脱退に伴う割り当ての解除が失敗した場合に利用者が実行するため、
+    /// 実行者に [Permission::ManageMembers] を要求する。
+    ///
+    /// # 戻り値
+    /// - 割り当てを解除したタスクのID
+    pub async fn unassign_removed_members(
+        &mut self,
+        project_id: ProjectId,
+        executor_id: UserId,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError> {
+        self.ensure_permission(&project_id, &executor_id, Permission::ManageMembers)
+            .await?;
+        self.handle_member_removed(project_id, executor_id).await
+    }
+
+    /// 削除されたタグをタスクから外す処理を再実行する。
+    ///
+    /// タグの削除に伴う処理が失敗した場合に利用者が実行するため、実行者に [Permission::ManageTags] を要求する。
+    ///
+    /// # 戻り値
+    /// - タグを外したタスクのID
+    pub async fn untag_deleted_tag(
+        &mut self,
+        project_id: ProjectId,
+        tag_id: TagId,
+        executor_id: UserId,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError> {
+        self.ensure_permission(&project_id, &executor_id, Permission::ManageTags)
+            .await?;
+        self.handle_tag_deleted(project_id, tag_id, executor_id).await
+    }
+
+    /// 完了したイテレーションの未完了タスクの引き継ぎを再実行する。
+    ///
+    /// イテレーションの完了に伴う引き継ぎが失敗した場合に利用者が実行するため、
+    /// 実行者に [Permission::ManageIterations] を要求する。
+    ///
+    /// # 戻り値
+    /// - 移動したタスクのID
+    pub async fn carry_over_iteration(
+        &mut self,
+        iteration_id: IterationId,
+        destination_id: Option<IterationId>,
+        executor_id: UserId,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError> {
+        let project_id = {
+            let iteration_repository_mg = self.iteration_repository.lock().await;
+            Self::find_iteration(&*iteration_repository_mg, &iteration_id)
+                .await?
+                .project_id()
+                .clone()
+        };
+        self.ensure_permission(&project_id, &executor_id, Permission::ManageIterations)
+            .await?;
+        self.handle_iteration_completed(iteration_id, destination_id, executor_id).await
+    }
+
+    /// 実行者がプロジェクトで指定した権限を持つかどうかを検証する。
+    async fn ensure_permission(
+        &self,
+        project_id: &ProjectId,
+        executor_id: &UserId,
+        permission: Permission,
+    ) -> Result<(), TaskCommandProcessError> {
+        let project_repository_mg = self.project_repository.lock().await;
+        let project = Self::find_project(&*project_repository_mg, project_id).await?;
+        if !project.members().has_permission(executor_id, &permission) {
+            return Err(TaskCommandProcessError::DomainLogicError(
+                TaskError::PermissionDeniedError("executor_id".to_string(), permission, executor_id.clone()),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 索引からプロジェクトに属するタスクを取得し、イベントを返したタスクのみ保存する。
+    async fn update_project_tasks<F>(
+        &mut self,
+        project_id: ProjectId,
+        command: F,
+    ) -> Result<Vec<TaskId>, TaskCommandProcessError>
+    where
+        F: Fn(&mut Task, &Project) -> Result<Option<TaskEvent>, TaskError>,
+    {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut task_repository_mg = self.task_repository.lock().await;
+        let task_index_mg = self.task_index.lock().await;
+
+        let project = Self::find_project(&*project_repository_mg, &project_id).await?;
+        let task_ids = task_index_mg
+            .find_by_project_id(&project_id)
+            .await
+            .map_err(TaskCommandProcessError::TaskIndexError)?;
+
+        let mut updated_task_ids = Vec::new();
+        for task_id in task_ids {
+            let Some(mut task) = task_repository_mg
+                .find_by_id(&task_id)
+                .await
+                .map_err(TaskCommandProcessError::TaskRepositoryError)?
+            else {
+                continue;
+            };
+            if let Some(task_event) = command(&mut task, &project).map_err(TaskCommandProcessError::DomainLogicError)? {
+                task_repository_mg
+                    .store(&task_event, &task)
+                    .await
+                    .map_err(TaskCommandProcessError::TaskRepositoryError)?;
+                updated_task_ids.push(task_id);
+            }
+        }
+        Ok(updated_task_ids)
+    }
+
+    /// タスクと所属するプロジェクトを取得し、コマンドを実行してイベントを保存する。
+    async fn update_task<F>(&mut self, task_id: TaskId, command: F) -> Result<TaskId, TaskCommandProcessError>
+    where
+        F: FnOnce(&mut Task, &Project) -> Result<TaskEvent, TaskError>,
+    {
+        let project_repository_mg = self.project_repository.lock().await;
+        let mut task_repository_mg = self.task_repository.lock().await;
+
+        let mut task = Self::find_task_by_id(&*task_repository_mg, &task_id).await?;
+        let project = Self::find_project(&*project_repository_mg, task.project_id()).await?;
+
+        let task_event = command(&mut task, &project).map_err(TaskCommandProcessError::DomainLogicError)?;
+
+        task_repository_mg
+            .store(&task_event, &task)
+            .await
+            .map(|_| task_event.aggregate_id().clone())
+            .map_err(TaskCommandProcessError::TaskRepositoryError)
+    }
+
+    async fn find_project(project_repository: &TR, project_id: &ProjectId) -> Result<Project, TaskCommandProcessError> {
+        project_repository
+            .find_by_id(project_id)
+            .await
+            .map_err(TaskCommandProcessError::ProjectRepositoryError)?
+            .ok_or(TaskCommandProcessError::ProjectNotFoundError)
+    }
+
+    async fn find_iteration(
+        iteration_repository: &IR,
+        iteration_id: &IterationId,
+    ) -> Result<Iteration, TaskCommandProcessError> {
+        iteration_repository
+            .find_by_id(iteration_id)
+            .await
+            .map_err(TaskCommandProcessError::IterationRepositoryError)?
+            .ok_or(TaskCommandProcessError::IterationNotFoundError)
+    }
+
+    async fn find_task_by_id(task_repository: &KR, task_id: &TaskId) -> Result<Task, TaskCommandProcessError> {
+        task_repository
+            .find_by_id(task_id)
+            .await
+            .map_err(TaskCommandProcessError::TaskRepositoryError)?
+            .ok_or(TaskCommandProcessError::TaskNotFoundError)
+    }
+
+    /// 索引からプロジェクトに属するタスクを取得する。
+    ///
+    /// 保存に失敗したタスクの ID が索引に残っている場合があるため、存在しないタスクは除外する。
+    async fn find_tasks_by_project_id(
+        task_repository: &KR,
+        task_index: &KX,
+        project_id: &ProjectId,
+    ) -> Result<Vec<Task>, TaskCommandProcessError> {
+        let task_ids = task_index
+            .find_by_project_id(project_id)
+            .await
+            .map_err(TaskCommandProcessError::TaskIndexError)?;
+        let mut tasks = Vec::with_capacity(task_ids.len());
+        for task_id in task_ids {
+            if let Some(task) = task_repository
+                .find_by_id(&task_id)
+                .await
+                .map_err(TaskCommandProcessError::TaskRepositoryError)?
+            {
+                tasks.push(task);
+            }
+        }
+        Ok(tasks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::InMemoryIterationRepository;
+    use crate::test_support::InMemoryProjectRepository;
+    use crate::test_support::InMemoryTaskIndex;
+    use crate::test_support::InMemoryTaskRepository;
+    use chrono::NaiveDate;
+    use command_domain::iteration::{IterationEvent, IterationName};
+    use command_domain::project::{MemberRole, Members, ProjectName, TagName};
+
+    type TestProcessor = TaskCommandProcessor<
+        InMemoryProjectRepository,
+        InMemoryIterationRepository,
+        InMemoryTaskRepository,
+        InMemoryTaskIndex,
+    >;
+
+    fn create_iteration(
+        project: &Project,
+        owner_id: &UserId,
+        start_day: u32,
+        end_day: u32,
+    ) -> (Iteration, IterationEvent) {
+        Iteration::new(
+            project,
+            IterationName::new("Sprint").unwrap(),
+            Description::default(),
+            Period::new(
+                NaiveDate::from_ymd_opt(2025, 4, start_day).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 4, end_day).unwrap(),
+            )
+            .unwrap(),
+            owner_id.clone(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_handle_iteration_completed() {
+        let owner_id = UserId::new();
+        let (project, project_event) = Project::new(
+            ProjectName::new("Alpha").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        let mut project_repository = InMemoryProjectRepository::new();
+        project_repository.store(&project_event, &project).await.unwrap();
+
+        let (mut current, _) = create_iteration(&project, &owner_id, 1, 14);
+        let (next, next_event) = create_iteration(&project, &owner_id, 15, 28);
+        let mut task_repository = InMemoryTaskRepository::new();
+        let mut task_index = InMemoryTaskIndex::new();
+        let mut task_ids = Vec::new();
+        for title in ["Write docs", "Fix bugs"] {
+            let (mut task, task_event) = Task::new(
+                &project,
+                TaskTitle::new(title).unwrap(),
+                Description::default(),
+                Priority::default(),
+                owner_id.clone(),
+            )
+            .unwrap();
+            task_repository.store(&task_event, &task).await.unwrap();
+            let task_event = task.move_to_iteration(&project, &current, owner_id.clone()).unwrap();
+            task_repository.store(&task_event, &task).await.unwrap();
+            task_index.add(project.id(), task.id()).await.unwrap();
+            task_ids.push(task.id().clone());
+        }
+        // バックログのタスクは引き継ぎの対象外
+        let (backlog_task, task_event) = Task::new(
+            &project,
+            TaskTitle::new("Someday").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        task_repository.store(&task_event, &backlog_task).await.unwrap();
+        task_index.add(project.id(), backlog_task.id()).await.unwrap();
+
+        current.start(&project, owner_id.clone()).unwrap();
+        let iteration_event = current.complete(&project, owner_id.clone()).unwrap();
+        let mut iteration_repository = InMemoryIterationRepository::new();
+        iteration_repository.store(&iteration_event, &current).await.unwrap();
+        iteration_repository.store(&next_event, &next).await.unwrap();
+
+        let mut processor: TestProcessor = TaskCommandProcessor::new(
+            project_repository,
+            iteration_repository,
+            task_repository,
+            task_index,
+        );
+
+        let mut moved = processor
+            .handle_iteration_completed(
+                current.id().clone(),
+                Some(next.id().clone()),
+                owner_id.clone(),
+            )
+            .await
+            .unwrap();
+        moved.sort_by_key(|task_id| task_id.to_string());
+        task_ids.sort_by_key(|task_id| task_id.to_string());
+        assert_eq!(moved, task_ids);
+        for task_id in &task_ids {
+            let task = processor.find_task(task_id.clone(), owner_id.clone()).await.unwrap();
+            assert_eq!(task.iteration_id(), Some(next.id()));
+        }
+
+        // 引き継ぎの再実行にはイテレーションを管理する権限が必要
+        let result = processor
+            .carry_over_iteration(current.id().clone(), Some(next.id().clone()), UserId::new())
+            .await;
+        assert!(matches!(
+            result,
+            Err(TaskCommandProcessError::DomainLogicError(
+                TaskError::PermissionDeniedError(_, Permission::ManageIterations, _)
+            ))
+        ));
+
+        // 引き継ぎ済みのタスクは対象外となるため、再実行しても何もしない
+        let moved = processor
+            .carry_over_iteration(
+                current.id().clone(),
+                Some(next.id().clone()),
+                owner_id.clone(),
+            )
+            .await
+            .unwrap();
+        assert!(moved.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unassign_removed_members() {
+        let owner_id = UserId::new();
+        let assignee_id = UserId::new();
+        let viewer_id = UserId::new();
+        let assignee_member_id = MemberId::new();
+        let (mut project, _) = Project::new(
+            ProjectName::new("Alpha").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        project
+            .add_member(
+                assignee_member_id.clone(),
+                assignee_id.clone(),
+                MemberRole::Member,
+                owner_id.clone(),
+            )
+            .unwrap();
+        project
+            .add_member(
+                MemberId::new(),
+                viewer_id.clone(),
+                MemberRole::Member,
+                owner_id.clone(),
+            )
+            .unwrap();
+
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Write docs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let task_event = task.assign(&project, assignee_member_id, owner_id.clone()).unwrap();
+        let mut task_repository = InMemoryTaskRepository::new();
+        task_repository.store(&task_event, &task).await.unwrap();
+        let mut task_index = InMemoryTaskIndex::new();
+        task_index.add(project.id(), task.id()).await.unwrap();
+
+        let project_event = project.remove_member(assignee_id, owner_id.clone()).unwrap();
+        let mut project_repository = InMemoryProjectRepository::new();
+        project_repository.store(&project_event, &project).await.unwrap();
+
+        let mut processor: TestProcessor = TaskCommandProcessor::new(
+            project_repository,
+            InMemoryIterationRepository::new(),
+            task_repository,
+            task_index,
+        );
+
+        // 割り当ての解除の再実行にはメンバーを管理する権限が必要
+        let result = processor.unassign_removed_members(project.id().clone(), viewer_id).await;
+        assert!(matches!(
+            result,
+            Err(TaskCommandProcessError::DomainLogicError(
+                TaskError::PermissionDeniedError(_, Permission::ManageMembers, _)
+            ))
+        ));
+
+        let unassigned = processor
+            .unassign_removed_members(project.id().clone(), owner_id.clone())
+            .await
+            .unwrap();
+        assert_eq!(unassigned, vec![task.id().clone()]);
+        let task = processor.find_task(task.id().clone(), owner_id).await.unwrap();
+        assert_eq!(task.assignee_id(), None);
+    }
+
+    #[tokio::test]
+    async fn test_untag_deleted_tag() {
+        let owner_id = UserId::new();
+        let member_id = UserId::new();
+        let tag_id = TagId::new();
+        let (mut project, _) = Project::new(
+            ProjectName::new("Alpha").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        project
+            .add_member(
+                MemberId::new(),
+                member_id.clone(),
+                MemberRole::Member,
+                owner_id.clone(),
+            )
+            .unwrap();
+        project
+            .create_tag(
+                tag_id.clone(),
+                TagName::new("bug").unwrap(),
+                owner_id.clone(),
+            )
+            .unwrap();
+
+        let (mut task, _) = Task::new(
+            &project,
+            TaskTitle::new("Fix bugs").unwrap(),
+            Description::default(),
+            Priority::default(),
+            owner_id.clone(),
+        )
+        .unwrap();
+        let task_event = task.tag(&project, tag_id.clone(), owner_id.clone()).unwrap();
+        let mut task_repository = InMemoryTaskRepository::new();
+        task_repository.store(&task_event, &task).await.unwrap();
+        let mut task_index = InMemoryTaskIndex::new();
+        task_index.add(project.id(), task.id()).await.unwrap();
+
+        let project_event = project.delete_tag(tag_id.clone(), owner_id.clone()).unwrap();
+        let mut project_repository = InMemoryProjectRepository::new();
+        project_repository.store(&project_event, &project).await.unwrap();
+
+        let mut processor: TestProcessor = TaskCommandProcessor::new(
+            project_repository,
+            InMemoryIterationRepository::new(),
+            task_repository,
+            task_index,
+        );
+
+        // タグを外す処理の再実行にはタグを管理する権限が必要
+        let result = processor
+            .untag_deleted_tag(project.id().clone(), tag_id.clone(), member_id)
+            .await;
+        assert!(matches!(
+            result,
+            Err(TaskCommandProcessError::DomainLogicError(
+                TaskError::PermissionDeniedError(_, Permission::ManageTags, _)
+            ))
+        ));
+
+        let untagged = processor
+            .untag_deleted_tag(project.id().clone(), tag_id, owner_id.clone())
+            .await
+            .unwrap();
+        assert_eq!(untagged, vec![task.id().clone()]);
+        let task = processor.find_task(task.id().clone(), owner_id).await.unwrap();
+        assert!(task.tag_ids().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_task_after_store_failure() {
+        let owner_id = UserId::new();
+        let (project, project_event) = Project::new(
+            ProjectName::new("Alpha").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        let mut project_repository = InMemoryProjectRepository::new();
+        project_repository.store(&project_event, &project).await.unwrap();
+        let task_repository = InMemoryTaskRepository::new();
+        let mut processor: TestProcessor = TaskCommandProcessor::new(
+            project_repository,
+            InMemoryIterationRepository::new(),
+            task_repository.clone(),
+            InMemoryTaskIndex::new(),
+        );
+
+        task_repository.fail_next_store();
+        let result = processor
+            .create_task(
+                project.id().clone(),
+                TaskTitle::new("Write docs").unwrap(),
+                Description::default(),
+                Priority::default(),
+                owner_id.clone(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(TaskCommandProcessError::TaskRepositoryError(_))
+        ));
+
+        // 保存に失敗したタスクは索引に残っても取得の対象外となる
+        let task_id = processor
+            .create_task(
+                project.id().clone(),
+                TaskTitle::new("Write docs").unwrap(),
+                Description::default(),
+                Priority::default(),
+                owner_id.clone(),
+            )
+            .await
+            .unwrap();
+        let tasks = processor.find_tasks(project.id().clone(), owner_id).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].id(), &task_id);
+    }
+
+    #[tokio::test]
+    async fn test_change_task_status_with_missing_blocker() {
+        let owner_id = UserId::new();
+        let (project, project_event) = Project::new(
+            ProjectName::new("Alpha").unwrap(),
+            Description::default(),
+            Members::new(owner_id.clone()),
+            owner_id.clone(),
+        );
+        let mut project_repository = InMemoryProjectRepository::new();
+        project_repository.store(&project_event, &project).await.unwrap();
+
+        let new_task = |title: &str| {
+            Task::new(
+                &project,
+                TaskTitle::new(title).unwrap(),
+                Description::default(),
+                Priority::default(),
+                owner_id.clone(),
+            )
+            .unwrap()
+        };
+        let (design, _) = new_task("Design");
+        let (mut build, build_event) = new_task("Build");
+        let mut task_repository = InMemoryTaskRepository::new();
+        task_repository.store(&build_event, &build).await.unwrap();
+        let task_event = build.add_blocker(&project, &design, &[], owner_id.clone()).unwrap();
+        // 先行タスクは保存しないため、リポジトリからは見つからない
+        task_repository.store(&task_event, &build).await.unwrap();
+
+        let mut processor: TestProcessor = TaskCommandProcessor::new(
+            project_repository,
+            InMemoryIterationRepository::new(),
+            task_repository,
+            InMemoryTaskIndex::new(),
+        );
+        for status in [TaskStatus::InProgress, TaskStatus::InReview] {
+            processor
+                .change_task_status(build.id().clone(), status, owner_id.clone())
+                .await
+                .unwrap();
+        }
+
+        let result = processor
+            .change_task_status(build.id().clone(), TaskStatus::Done, owner_id.clone())
+            .await;
+        assert!(matches!(
+            result,
+            Err(TaskCommandProcessError::DomainLogicError(TaskError::BlockerNotFoundError(blocker_id)))
+                if &blocker_id == design.id()
+        ));
+        let task = processor.find_task(build.id().clone(), owner_id).await.unwrap();
+        assert_eq!(task.status(), TaskStatus::InReview);
+    }
+}
@@ -0,0 +1,311 @@
+//! コマンドプロセッサのテストで利用するポートのインメモリ実装。
+//!
+//! 複製したインスタンス同士は状態を共有するため、コマンドプロセッサに渡した後も状態を確認・操作できる。
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use event_store_adapter_rs::types::{Aggregate, EventStoreWriteError};
+
+use command_domain::email::Email;
+use command_domain::iteration::{Iteration, IterationEvent, IterationId};
+use command_domain::project::{Project, ProjectEvent, ProjectId, ProjectName};
+use command_domain::project_invitation::{ProjectInvitation, ProjectInvitationEvent, ProjectInvitationId};
+use command_domain::task::{Task, TaskEvent, TaskId};
+use command_domain::user::{EmailVerificationToken, User, UserEvent, UserId};
+use command_interface_adaptor_if::{
+    IterationIndex, IterationIndexError, IterationRepository, IterationRepositoryError, Mailer, MailerError,
+    ProjectInvitationRepository, ProjectInvitationRepositoryError, ProjectNameReservation, ProjectNameReservationError,
+    ProjectRepository, ProjectRepositoryError, TaskIndex, TaskIndexError, TaskRepository, TaskRepositoryError,
+    UserEmailReservation, UserEmailReservationError, UserRepository, UserRepositoryError,
+};
+
+/// 集約の最新のスナップショットを保持するリポジトリ
+#[derive(Debug, Clone)]
+pub struct InMemoryRepository<ID, AG> {
+    snapshots: Arc<Mutex<HashMap<ID, AG>>>,
+    failing_stores: Arc<Mutex<usize>>,
+}
+
+pub type InMemoryProjectRepository = InMemoryRepository<ProjectId, Project>;
+pub type InMemoryProjectInvitationRepository = InMemoryRepository<ProjectInvitationId, ProjectInvitation>;
+pub type InMemoryIterationRepository = InMemoryRepository<IterationId, Iteration>;
+pub type InMemoryTaskRepository = InMemoryRepository<TaskId, Task>;
+pub type InMemoryUserRepository = InMemoryRepository<UserId, User>;
+
+impl<ID: Clone + Eq + Hash, AG: Aggregate<ID = ID>> InMemoryRepository<ID, AG> {
+    pub fn new() -> Self {
+        Self {
+            snapshots: Arc::new(Mutex::new(HashMap::new())),
+            failing_stores: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// 次の保存を失敗させる
+    pub fn fail_next_store(&self) {
+        *self.failing_stores.lock().unwrap() += 1;
+    }
+
+    fn store_snapshot(&self, snapshot: &AG) -> Result<(), Box<EventStoreWriteError>> {
+        let mut failing_stores = self.failing_stores.lock().unwrap();
+        if *failing_stores > 0 {
+            *failing_stores -= 1;
+            return Err(Box::new(EventStoreWriteError::OtherError(
+                "store failed".to_string(),
+            )));
+        }
+        self.snapshots.lock().unwrap().insert(snapshot.id().clone(), snapshot.clone());
+        Ok(())
+    }
+
+    fn find_snapshot(&self, id: &ID) -> Option<AG> {
+        self.snapshots.lock().unwrap().get(id).cloned()
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectRepository for InMemoryProjectRepository {
+    async fn store(&mut self, _event: &ProjectEvent, snapshot: &Project) -> Result<(), ProjectRepositoryError> {
+        self.store_snapshot(snapshot)
+            .map_err(|error| ProjectRepositoryError::StoreError(snapshot.clone(), *error))
+    }
+
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, ProjectRepositoryError> {
+        Ok(self.find_snapshot(id))
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectInvitationRepository for InMemoryProjectInvitationRepository {
+    async fn store(
+        &mut self,
+        _event: &ProjectInvitationEvent,
+        snapshot: &ProjectInvitation,
+    ) -> Result<(), ProjectInvitationRepositoryError> {
+        self.store_snapshot(snapshot)
+            .map_err(|error| ProjectInvitationRepositoryError::StoreError(Box::new(snapshot.clone()), *error))
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &ProjectInvitationId,
+    ) -> Result<Option<ProjectInvitation>, ProjectInvitationRepositoryError> {
+        Ok(self.find_snapshot(id))
+    }
+}
+
+#[async_trait::async_trait]
+impl IterationRepository for InMemoryIterationRepository {
+    async fn store(&mut self, _event: &IterationEvent, snapshot: &Iteration) -> Result<(), IterationRepositoryError> {
+        self.store_snapshot(snapshot)
+            .map_err(|error| IterationRepositoryError::StoreError(Box::new(snapshot.clone()), *error))
+    }
+
+    async fn find_by_id(&self, id: &IterationId) -> Result<Option<Iteration>, IterationRepositoryError> {
+        Ok(self.find_snapshot(id))
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskRepository for InMemoryTaskRepository {
+    async fn store(&mut self, _event: &TaskEvent, snapshot: &Task) -> Result<(), TaskRepositoryError> {
+        self.store_snapshot(snapshot)
+            .map_err(|error| TaskRepositoryError::StoreError(Box::new(snapshot.clone()), *error))
+    }
+
+    async fn find_by_id(&self, id: &TaskId) -> Result<Option<Task>, TaskRepositoryError> {
+        Ok(self.find_snapshot(id))
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn store(&mut self, _event: &UserEvent, snapshot: &User) -> Result<(), UserRepositoryError> {
+        self.store_snapshot(snapshot)
+            .map_err(|error| UserRepositoryError::StoreError(Box::new(snapshot.clone()), *error))
+    }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, UserRepositoryError> {
+        Ok(self.find_snapshot(id))
+    }
+}
+
+/// プロジェクトに属する集約のIDを保持する索引
+#[derive(Debug, Clone)]
+pub struct InMemoryIndex<ID> {
+    ids: Arc<Mutex<HashMap<ProjectId, Vec<ID>>>>,
+}
+
+pub type InMemoryIterationIndex = InMemoryIndex<IterationId>;
+pub type InMemoryTaskIndex = InMemoryIndex<TaskId>;
+
+impl<ID: Clone + PartialEq> InMemoryIndex<ID> {
+    pub fn new() -> Self {
+        Self {
+            ids: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn add_id(&self, project_id: &ProjectId, id: &ID) -> Result<(), anyhow::Error> {
+        let mut ids = self.ids.lock().unwrap();
+        let ids = ids.entry(project_id.clone()).or_default();
+        if !ids.contains(id) {
+            ids.push(id.clone());
+        }
+        Ok(())
+    }
+
+    fn find_ids(&self, project_id: &ProjectId) -> Vec<ID> {
+        self.ids.lock().unwrap().get(project_id).cloned().unwrap_or_default()
+    }
+}
+
+#[async_trait::async_trait]
+impl IterationIndex for InMemoryIterationIndex {
+    async fn add(&mut self, project_id: &ProjectId, iteration_id: &IterationId) -> Result<(), IterationIndexError> {
+        self.add_id(project_id, iteration_id)
+            .map_err(|error| IterationIndexError::AddError(project_id.clone(), iteration_id.clone(), error))
+    }
+
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<IterationId>, IterationIndexError> {
+        Ok(self.find_ids(project_id))
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskIndex for InMemoryTaskIndex {
+    async fn add(&mut self, project_id: &ProjectId, task_id: &TaskId) -> Result<(), TaskIndexError> {
+        self.add_id(project_id, task_id)
+            .map_err(|error| TaskIndexError::AddError(project_id.clone(), task_id.clone(), error))
+    }
+
+    async fn find_by_project_id(&self, project_id: &ProjectId) -> Result<Vec<TaskId>, TaskIndexError> {
+        Ok(self.find_ids(project_id))
+    }
+}
+
+/// キーごとに予約している集約のIDを保持する予約
+#[derive(Debug, Clone)]
+pub struct InMemoryReservation<ID> {
+    reservations: Arc<Mutex<HashMap<String, ID>>>,
+    failing_releases: Arc<Mutex<bool>>,
+}
+
+pub type InMemoryProjectNameReservation = InMemoryReservation<ProjectId>;
+pub type InMemoryUserEmailReservation = InMemoryReservation<UserId>;
+
+impl<ID: Clone + PartialEq> InMemoryReservation<ID> {
+    pub fn new() -> Self {
+        Self {
+            reservations: Arc::new(Mutex::new(HashMap::new())),
+            failing_releases: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    /// 以降の解放をすべて失敗させる
+    pub fn fail_releases(&self) {
+        *self.failing_releases.lock().unwrap() = true;
+    }
+
+    /// キーを予約しているIDを返す
+    pub fn reserved_by(&self, key: &str) -> Option<ID> {
+        self.reservations.lock().unwrap().get(key).cloned()
+    }
+
+    /// 他のIDが予約している場合は、予約しているIDを返す
+    fn reserve_key(&self, key: String, id: &ID) -> Result<(), ID> {
+        let mut reservations = self.reservations.lock().unwrap();
+        match reservations.get(&key) {
+            Some(reserved_by) if reserved_by != id => Err(reserved_by.clone()),
+            _ => {
+                reservations.insert(key, id.clone());
+                Ok(())
+            },
+        }
+    }
+
+    fn release_key(&self, key: &str, id: &ID) -> Result<(), anyhow::Error> {
+        if *self.failing_releases.lock().unwrap() {
+            return Err(anyhow::anyhow!("release failed"));
+        }
+        let mut reservations = self.reservations.lock().unwrap();
+        if reservations.get(key) == Some(id) {
+            reservations.remove(key);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ProjectNameReservation for InMemoryProjectNameReservation {
+    async fn reserve(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError> {
+        self.reserve_key(name.to_string(), project_id)
+            .map_err(|reserved_by| ProjectNameReservationError::AlreadyReservedError(name.clone(), reserved_by))
+    }
+
+    async fn release(&mut self, name: &ProjectName, project_id: &ProjectId) -> Result<(), ProjectNameReservationError> {
+        self.release_key(&name.to_string(), project_id)
+            .map_err(|error| ProjectNameReservationError::ReleaseError(name.clone(), error))
+    }
+}
+
+#[async_trait::async_trait]
+impl UserEmailReservation for InMemoryUserEmailReservation {
+    async fn reserve(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError> {
+        self.reserve_key(email.normalized(), user_id)
+            .map_err(|reserved_by| UserEmailReservationError::AlreadyReservedError(email.clone(), reserved_by))
+    }
+
+    async fn release(&mut self, email: &Email, user_id: &UserId) -> Result<(), UserEmailReservationError> {
+        self.release_key(&email.normalized(), user_id)
+            .map_err(|error| UserEmailReservationError::ReleaseError(email.clone(), error))
+    }
+
+    async fn find_reserved_by(&self, email: &Email) -> Result<Option<UserId>, UserEmailReservationError> {
+        Ok(self.reserved_by(&email.normalized()))
+    }
+}
+
+/// 送信したメールアドレスの確認メール
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentEmailVerification {
+    pub email: Email,
+    pub token: EmailVerificationToken,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 送信したメールを記録するメーラー
+#[derive(Debug, Clone)]
+pub struct InMemoryMailer {
+    sent: Arc<Mutex<Vec<SentEmailVerification>>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self { sent: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    /// 送信したメールを送信順に返す
+    pub fn sent_email_verifications(&self) -> Vec<SentEmailVerification> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send_email_verification(
+        &mut self,
+        email: &Email,
+        token: &EmailVerificationToken,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<(), MailerError> {
+        self.sent.lock().unwrap().push(SentEmailVerification {
+            email: email.clone(),
+            token: token.clone(),
+            expires_at: *expires_at,
+        });
+        Ok(())
+    }
+}
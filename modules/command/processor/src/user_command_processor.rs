@@ -0,0 +1,488 @@
+use chrono::Utc;
+use event_store_adapter_rs::types::{Aggregate, Event};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use command_domain::email::Email;
+use command_domain::user::{EmailVerificationToken, User, UserError, UserEvent, UserId, UserName};
+use command_interface_adaptor_if::{
+    Mailer, MailerError, UserEmailReservation, UserEmailReservationError, UserRepository, UserRepositoryError,
+};
+
+#[derive(Error, Debug)]
+pub enum UserCommandProcessError {
+    #[error("User not found.")]
+    UserNotFoundError,
+    #[error("UserRepositoryError: {0:?}")]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error("UserError: {0:?}")]
+    DomainLogicError(#[from] UserError),
+    #[error("MailerError: {0:?}")]
+    MailerError(#[from] MailerError),
+    #[error("The email is already in use: {0}")]
+    EmailAlreadyInUseError(Email),
+    #[error("UserEmailReservationError: {0:?}")]
+    ReservationError(UserEmailReservationError),
+}
+
+impl From<UserEmailReservationError> for UserCommandProcessError {
+    fn from(error: UserEmailReservationError) -> Self {
+        match error {
+            UserEmailReservationError::AlreadyReservedError(email, _) => {
+                UserCommandProcessError::EmailAlreadyInUseError(email)
+            },
+            error => UserCommandProcessError::ReservationError(error),
+        }
+    }
+}
+
+pub struct UserCommandProcessor<UR: UserRepository, ER: UserEmailReservation, M: Mailer> {
+    user_repository: Arc<Mutex<UR>>,
+    user_email_reservation: Arc<Mutex<ER>>,
+    mailer: Arc<Mutex<M>>,
+}
+
+impl<UR: UserRepository, ER: UserEmailReservation, M: Mailer> UserCommandProcessor<UR, ER, M> {
+    pub fn new(user_repository: UR, user_email_reservation: ER, mailer: M) -> Self {
+        Self {
+            user_repository: Arc::new(Mutex::new(user_repository)),
+            user_email_reservation: Arc::new(Mutex::new(user_email_reservation)),
+            mailer: Arc::new(Mutex::new(mailer)),
+        }
+    }
+
+    pub async fn register_user(
+        &mut self,
+        user_name: UserName,
+        email: Email,
+    ) -> Result<UserId, UserCommandProcessError> {
+        let mut user_repository_mg = self.user_repository.lock().await;
+        let mut reservation_mg = self.user_email_reservation.lock().await;
+
+        let (user, user_event) = User::new(user_name, email);
+
+        reservation_mg.reserve(user.email(), user.id()).await?;
+
+        if let Err(error) = user_repository_mg.store(&user_event, &user).await {
+            Self::release_reservation(&mut *reservation_mg, user.email(), user.id()).await;
+            return Err(UserCommandProcessError::UserRepositoryError(error));
+        }
+        Ok(user.id().clone())
+    }
+
+    pub async fn delete_user(
+        &mut self,
+        user_id: UserId,
+        executor_id: UserId,
+    ) -> Result<UserId, UserCommandProcessError> {
+        let mut user_repository_mg = self.user_repository.lock().await;
+
+        let mut user = Self::find_user(&*user_repository_mg, &user_id).await?;
+
+        let user_event = user.delete(executor_id).map_err(UserCommandProcessError::DomainLogicError)?;
+
+        user_repository_mg
+            .store(&user_event, &user)
+            .await
+            .map_err(UserCommandProcessError::UserRepositoryError)?;
+
+        // 削除したユーザのメールアドレスは他のユーザが使えるようにする
+        let mut reservation_mg = self.user_email_reservation.lock().await;
+        Self::release_reservation(&mut *reservation_mg, user.email(), user.id()).await;
+        Ok(user_event.aggregate_id().clone())
+    }
+
+    pub async fn rename_user(
+        &mut self,
+        user_id: UserId,
+        user_name: UserName,
+        executor_id: UserId,
+    ) -> Result<UserId, UserCommandProcessError> {
+        self.update_user(user_id, |user| user.rename(user_name, executor_id)).await
+    }
+
+    /// メールアドレスの変更を要求し、変更後のメールアドレスに確認用のトークンを送信する。
+    ///
+    /// 他のユーザが使用しているメールアドレスへの変更は要求の時点で拒否する。
+    /// 予約は確認の時点で行うため、確認までに他のユーザが予約した場合は確認で拒否される。
+    /// 送信に失敗した場合も要求は保存されたままとなるため、再度要求すればトークンを発行し直せる。
+    pub async fn request_email_change(
+        &mut self,
+        user_id: UserId,
+        email: Email,
+        executor_id: UserId,
+    ) -> Result<UserId, UserCommandProcessError> {
+        let mut user_repository_mg = self.user_repository.lock().await;
+        let reservation_mg = self.user_email_reservation.lock().await;
+        let mut mailer_mg = self.mailer.lock().await;
+
+        let mut user = Self::find_user(&*user_repository_mg, &user_id).await?;
+        let reserved_by = reservation_mg.find_reserved_by(&email).await?;
+        if reserved_by.is_some_and(|reserved_by| reserved_by != user_id) {
+            return Err(UserCommandProcessError::EmailAlreadyInUseError(email));
+        }
+
+        let user_event = user
+            .request_email_change(email, executor_id, Utc::now())
+            .map_err(UserCommandProcessError::DomainLogicError)?;
+
+        user_repository_mg
+            .store(&user_event, &user)
+            .await
+            .map_err(UserCommandProcessError::UserRepositoryError)?;
+        if let Some(pending) = user.pending_email_change() {
+            mailer_mg
+                .send_email_verification(pending.email(), pending.token(), pending.expires_at())
+                .await
+                .map_err(UserCommandProcessError::MailerError)?;
+        }
+        Ok(user_event.aggregate_id().clone())
+    }
+
+    /// 確認用のトークンを検証し、メールアドレスを変更する。
+    ///
+    /// 変更後のメールアドレスは確認の時点で予約し、変更前のメールアドレスの予約を解放する。
+    pub async fn confirm_email_change(
+        &mut self,
+        user_id: UserId,
+        token: EmailVerificationToken,
+        executor_id: UserId,
+    ) -> Result<UserId, UserCommandProcessError> {
+        let mut user_repository_mg = self.user_repository.lock().await;
+        let mut reservation_mg = self.user_email_reservation.lock().await;
+
+        let mut user = Self::find_user(&*user_repository_mg, &user_id).await?;
+        let old_email = user.email().clone();
+
+        let user_event = user
+            .confirm_email_change(&token, executor_id, Utc::now())
+            .map_err(UserCommandProcessError::DomainLogicError)?;
+
+        // 大文字と小文字の違いだけの変更では予約が変わらないため、予約も解放もしない
+        let reservation_changed = old_email.normalized() != user.email().normalized();
+        if reservation_changed {
+            reservation_mg.reserve(user.email(), &user_id).await?;
+        }
+
+        if let Err(error) = user_repository_mg.store(&user_event, &user).await {
+            if reservation_changed {
+                Self::release_reservation(&mut *reservation_mg, user.email(), &user_id).await;
+            }
+            return Err(UserCommandProcessError::UserRepositoryError(error));
+        }
+        if reservation_changed {
+            Self::release_reservation(&mut *reservation_mg, &old_email, &user_id).await;
+        }
+        Ok(user_event.aggregate_id().clone())
+    }
+
+    /// ユーザを取得し、コマンドを実行してイベントを保存する。
+    async fn update_user<F>(&mut self, user_id: UserId, command: F) -> Result<UserId, UserCommandProcessError>
+    where
+        F: FnOnce(&mut User) -> Result<UserEvent, UserError>,
+    {
+        let mut user_repository_mg = self.user_repository.lock().await;
+
+        let mut user = Self::find_user(&*user_repository_mg, &user_id).await?;
+
+        let user_event = command(&mut user).map_err(UserCommandProcessError::DomainLogicError)?;
+
+        user_repository_mg
+            .store(&user_event, &user)
+            .await
+            .map(|_| user_event.aggregate_id().clone())
+            .map_err(UserCommandProcessError::UserRepositoryError)
+    }
+
+    /// メールアドレスの予約を解放する。
+    ///
+    /// 解放できずに残った予約はユーザの保存結果に影響しないため、エラーはログに記録するにとどめる。
+    async fn release_reservation(reservation: &mut ER, email: &Email, user_id: &UserId) {
+        if let Err(error) = reservation.release(email, user_id).await {
+            log::error!(
+                "Failed to release the user email reservation: email = {}, user_id = {}, error = {:?}",
+                email,
+                user_id,
+                error
+            );
+        }
+    }
+
+    async fn find_user(user_repository: &UR, user_id: &UserId) -> Result<User, UserCommandProcessError> {
+        user_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(UserCommandProcessError::UserRepositoryError)?
+            .ok_or(UserCommandProcessError::UserNotFoundError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::InMemoryMailer;
+    use crate::test_support::InMemoryUserEmailReservation;
+    use crate::test_support::InMemoryUserRepository;
+
+    type TestProcessor = UserCommandProcessor<InMemoryUserRepository, InMemoryUserEmailReservation, InMemoryMailer>;
+
+    fn create_processor(mailer: InMemoryMailer) -> TestProcessor {
+        UserCommandProcessor::new(
+            InMemoryUserRepository::new(),
+            InMemoryUserEmailReservation::new(),
+            mailer,
+        )
+    }
+
+    fn email(value: &str) -> Email {
+        Email::new(value).unwrap()
+    }
+
+    fn is_domain_error<T>(result: &Result<T, UserCommandProcessError>, expected: fn(&UserError) -> bool) -> bool {
+        matches!(result, Err(UserCommandProcessError::DomainLogicError(error)) if expected(error))
+    }
+
+    #[tokio::test]
+    async fn test_register_and_delete_user() {
+        let mut processor = create_processor(InMemoryMailer::new());
+
+        let user_id = processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+
+        // 他のユーザは削除できない
+        let result = processor.delete_user(user_id.clone(), UserId::new()).await;
+        assert!(is_domain_error(&result, |error| matches!(
+            error,
+            UserError::NotSelfError(_, _)
+        )));
+
+        assert_eq!(
+            processor.delete_user(user_id.clone(), user_id.clone()).await.unwrap(),
+            user_id
+        );
+        let result = processor.delete_user(user_id.clone(), user_id.clone()).await;
+        assert!(is_domain_error(&result, |error| matches!(
+            error,
+            UserError::AlreadyDeletedError(_)
+        )));
+
+        let unknown_id = UserId::new();
+        let result = processor.delete_user(unknown_id.clone(), unknown_id).await;
+        assert!(matches!(
+            result,
+            Err(UserCommandProcessError::UserNotFoundError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rename_user() {
+        let mut processor = create_processor(InMemoryMailer::new());
+        let user_id = processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+
+        processor
+            .rename_user(
+                user_id.clone(),
+                UserName::new("Alicia").unwrap(),
+                user_id.clone(),
+            )
+            .await
+            .unwrap();
+
+        let result = processor
+            .rename_user(
+                user_id.clone(),
+                UserName::new("Bob").unwrap(),
+                UserId::new(),
+            )
+            .await;
+        assert!(is_domain_error(&result, |error| matches!(
+            error,
+            UserError::NotSelfError(_, _)
+        )));
+
+        let unknown_id = UserId::new();
+        let result = processor
+            .rename_user(
+                unknown_id.clone(),
+                UserName::new("Bob").unwrap(),
+                unknown_id,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(UserCommandProcessError::UserNotFoundError)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_request_and_confirm_email_change() {
+        let mailer = InMemoryMailer::new();
+        let mut processor = create_processor(mailer.clone());
+        let user_id = processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+
+        processor
+            .request_email_change(user_id.clone(), email("alice@example.org"), user_id.clone())
+            .await
+            .unwrap();
+        let sent = mailer.sent_email_verifications();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].email, email("alice@example.org"));
+
+        // 送信していないトークンでは変更できない
+        let result = processor
+            .confirm_email_change(
+                user_id.clone(),
+                EmailVerificationToken::new(),
+                user_id.clone(),
+            )
+            .await;
+        assert!(is_domain_error(&result, |error| matches!(
+            error,
+            UserError::InvalidVerificationTokenError(_)
+        )));
+
+        processor
+            .confirm_email_change(user_id.clone(), sent[0].token.clone(), user_id.clone())
+            .await
+            .unwrap();
+        let result = processor
+            .confirm_email_change(user_id.clone(), sent[0].token.clone(), user_id.clone())
+            .await;
+        assert!(is_domain_error(&result, |error| matches!(
+            error,
+            UserError::NoPendingEmailChangeError(_)
+        )));
+
+        // 変更後のメールアドレスが現在のメールアドレスになる
+        let result = processor
+            .request_email_change(user_id.clone(), email("alice@example.org"), user_id)
+            .await;
+        assert!(is_domain_error(&result, |error| matches!(
+            error,
+            UserError::SameEmailError(_, _)
+        )));
+        assert_eq!(mailer.sent_email_verifications().len(), 1);
+    }
+
+    fn is_email_already_in_use_error<T>(result: &Result<T, UserCommandProcessError>) -> bool {
+        matches!(
+            result,
+            Err(UserCommandProcessError::EmailAlreadyInUseError(_))
+        )
+    }
+
+    #[tokio::test]
+    async fn test_register_user_with_email_differing_only_in_case() {
+        let mut processor = create_processor(InMemoryMailer::new());
+        processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+
+        let result = processor
+            .register_user(UserName::new("Alice").unwrap(), email("Alice@Example.COM"))
+            .await;
+        assert!(is_email_already_in_use_error(&result));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_releases_email() {
+        let mut processor = create_processor(InMemoryMailer::new());
+        let user_id = processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+        processor.delete_user(user_id.clone(), user_id).await.unwrap();
+
+        processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_email_change_moves_reservation() {
+        let mailer = InMemoryMailer::new();
+        let mut processor = create_processor(mailer.clone());
+        let user_id = processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+        let other_user_id = processor
+            .register_user(UserName::new("Bob").unwrap(), email("bob@example.com"))
+            .await
+            .unwrap();
+
+        // 他のユーザが使用しているメールアドレスへの変更は要求できない
+        let result = processor
+            .request_email_change(user_id.clone(), email("BOB@example.com"), user_id.clone())
+            .await;
+        assert!(is_email_already_in_use_error(&result));
+        assert!(mailer.sent_email_verifications().is_empty());
+
+        processor
+            .request_email_change(user_id.clone(), email("alice@example.org"), user_id.clone())
+            .await
+            .unwrap();
+        let token = mailer.sent_email_verifications()[0].token.clone();
+        processor
+            .confirm_email_change(user_id.clone(), token, user_id.clone())
+            .await
+            .unwrap();
+
+        // 変更後のメールアドレスは予約され、変更前のメールアドレスは解放される
+        let result = processor
+            .register_user(UserName::new("Carol").unwrap(), email("alice@example.org"))
+            .await;
+        assert!(is_email_already_in_use_error(&result));
+        let result = processor
+            .request_email_change(
+                other_user_id.clone(),
+                email("alice@example.org"),
+                other_user_id,
+            )
+            .await;
+        assert!(is_email_already_in_use_error(&result));
+        processor
+            .register_user(UserName::new("Carol").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_and_confirm_email_change_succeed_when_release_fails() {
+        let mailer = InMemoryMailer::new();
+        let repository = InMemoryUserRepository::new();
+        let reservation = InMemoryUserEmailReservation::new();
+        let mut processor = UserCommandProcessor::new(repository.clone(), reservation.clone(), mailer.clone());
+        let user_id = processor
+            .register_user(UserName::new("Alice").unwrap(), email("alice@example.com"))
+            .await
+            .unwrap();
+        processor
+            .request_email_change(user_id.clone(), email("alice@example.org"), user_id.clone())
+            .await
+            .unwrap();
+        reservation.fail_releases();
+
+        let token = mailer.sent_email_verifications()[0].token.clone();
+        processor
+            .confirm_email_change(user_id.clone(), token, user_id.clone())
+            .await
+            .unwrap();
+        processor.delete_user(user_id.clone(), user_id.clone()).await.unwrap();
+
+        let user = repository.find_by_id(&user_id).await.unwrap().unwrap();
+        assert_eq!(user.email(), &email("alice@example.org"));
+        assert!(user.is_deleted());
+    }
+}